@@ -0,0 +1,160 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use bearish_dot_fun::{
+    Bet, GlobalRoundInfo, PlatformConfig, Round, RoundOutcome, UserInfo, WinningsPreference,
+};
+use bearish_dot_fun_client::math;
+
+fn field(name: &str, value: impl std::fmt::Display) {
+    println!("  {name:<32} {value}");
+}
+
+pub fn print_platform_config(platform_config: &PlatformConfig) {
+    let global_round_info = &platform_config.global_round_info;
+    let allocation = &global_round_info.allocation;
+    let jackpot_allocation = &global_round_info.jackpot_allocation;
+
+    println!("Platform config");
+    field("owner", platform_config.owner);
+    field("stablecoin", platform_config.stablecoin);
+    field("is gated", platform_config.is_gated);
+    field("gatekeeper", platform_config.gatekeeper);
+    field(
+        "bucket boundaries",
+        format!("{:?}", platform_config.bucket_boundaries),
+    );
+    field("strike offset", platform_config.strike_offset);
+    field("strike authority", platform_config.strike_authority);
+    field(
+        "strike override pending",
+        platform_config.is_strike_override_pending,
+    );
+    field("house pool", platform_config.has_house_pool);
+    println!("Global round info");
+    field("round", global_round_info.round);
+    field("duration", global_round_info.duration);
+    field(
+        "allocation",
+        format!(
+            "winners {}, affiliate {}, jackpot {}, platform {}",
+            allocation.winners_share,
+            allocation.affiliate_share,
+            allocation.jackpot_share,
+            allocation.platform_share
+        ),
+    );
+    field(
+        "jackpot allocation",
+        format!(
+            "{}, {}, {}, {}, {}, {}",
+            jackpot_allocation.streak_5,
+            jackpot_allocation.streak_6,
+            jackpot_allocation.streak_7,
+            jackpot_allocation.streak_8,
+            jackpot_allocation.streak_9,
+            jackpot_allocation.streak_10
+        ),
+    );
+    field("min bet amount", global_round_info.min_bet_amount);
+    field("price account", global_round_info.price_account);
+    field("staleness threshold", global_round_info.staleness_threshold);
+    field("jackpot pool amount", global_round_info.jackpot_pool_amount);
+    field(
+        "accumulated platform fees",
+        global_round_info.accumulated_platform_fees,
+    );
+    field("cancellation window", global_round_info.cancellation_window);
+    field("cancellation fee", global_round_info.cancellation_fee);
+    field("max bet amount", global_round_info.max_bet_amount);
+    field(
+        "max user bet amount per round",
+        global_round_info.max_user_bet_amount_per_round,
+    );
+    field("max round imbalance", global_round_info.max_round_imbalance);
+    field(
+        "max one-sided amount",
+        global_round_info.max_one_sided_amount,
+    );
+}
+
+pub fn print_round(round_number: u64, round: &Round) {
+    println!("Round {round_number}");
+    field("start time", round.start_time);
+    field("starting price", round.starting_price);
+    field("strike price", round.strike_price);
+    field("ending price", round.ending_price);
+    field("long positions", round.long_positions);
+    field("short positions", round.short_positions);
+    field(
+        "affiliates for long positions",
+        round.affiliates_for_long_positions,
+    );
+    field(
+        "affiliates for short positions",
+        round.affiliates_for_short_positions,
+    );
+    field("total bet amount long", round.total_bet_amount_long);
+    field("total bet amount short", round.total_bet_amount_short);
+    field("house bet amount", round.house_bet_amount);
+    field("house is long", round.house_is_long);
+    field("fixed odds payout long", round.fixed_odds_payout_long);
+    field("fixed odds payout short", round.fixed_odds_payout_short);
+    if round.has_buckets() {
+        field(
+            "bucket boundaries",
+            format!("{:?}", round.bucket_boundaries),
+        );
+        field("bucket totals", format!("{:?}", round.bucket_totals));
+        field("bucket positions", format!("{:?}", round.bucket_positions));
+        field("winning bucket", round.winning_bucket);
+    }
+    if round.ending_price != 0 {
+        let outcome = match round.outcome() {
+            RoundOutcome::LongsWon => "longs won",
+            RoundOutcome::ShortsWon => "shorts won",
+            RoundOutcome::Tie => "tie",
+            RoundOutcome::OneSided => "nobody bet on the winning side",
+        };
+        field("outcome", outcome);
+    }
+}
+
+pub fn print_user_info(user: &Pubkey, user_info: &UserInfo) {
+    println!("User {user}");
+    field("amount", user_info.amount);
+    field("affiliate", user_info.affiliate);
+    field("last won round", user_info.last_won_round);
+    field("times won", user_info.times_won);
+    let winnings_preference = match user_info.winnings_preference {
+        WinningsPreference::KeepInBalance => "keep in balance".to_string(),
+        WinningsPreference::WithdrawToWallet { token_account } => {
+            format!("withdraw to {token_account}")
+        }
+        WinningsPreference::RepeatBet { amount, is_long } => format!(
+            "repeat bet of {amount} {}",
+            if is_long { "long" } else { "short" }
+        ),
+    };
+    field("winnings preference", winnings_preference);
+}
+
+pub fn print_bet(
+    round_number: u64,
+    bet: &Bet,
+    round: &Round,
+    user_info: &UserInfo,
+    global_round_info: &GlobalRoundInfo,
+) {
+    println!("Bet in round {round_number}");
+    field("amount", bet.amount);
+    field("side", if bet.is_long { "long" } else { "short" });
+    field("affiliate", bet.affiliate);
+    field("has claimed winnings", bet.has_claimed_winnings);
+    field(
+        "has affiliate claimed winnings",
+        bet.has_affiliate_claimed_winnings,
+    );
+    if let Some(payout) = math::user_payout(round, round_number, bet, user_info, global_round_info)
+    {
+        field("claimable", payout.total());
+    }
+}
@@ -0,0 +1,635 @@
+//! `bearish`, a command-line tool to administer and play on the bearish-dot-fun platform.
+//!
+//! Amounts are given in the base units of the token they are denominated in.
+
+mod display;
+
+use std::str::FromStr;
+
+use anchor_client::{
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair, Signer},
+    },
+    Client, Cluster, Program,
+};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anyhow::{anyhow, Context, Result};
+use bearish_dot_fun::{
+    Allocation, AllowedMintExtensions, Bet, GlobalRoundInfo, JackPotAllocation, PlatformConfig,
+    Round, UserInfo, WinningsPreference,
+};
+use bearish_dot_fun_client::{
+    instructions::{self, WalletPayout},
+    pda,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "bearish", version, about)]
+struct Cli {
+    /// RPC URL of the cluster to connect to.
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        env = "BEARISH_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Keypair that signs and pays for transactions.
+    #[arg(
+        long,
+        short = 'k',
+        global = true,
+        env = "BEARISH_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the platform. The signer becomes the owner.
+    Initialize {
+        /// Mint of the platform stablecoin.
+        #[arg(long)]
+        stablecoin: Pubkey,
+        /// Pyth price account rounds are settled against.
+        #[arg(long)]
+        price_account: Pubkey,
+        /// Minimum round duration in seconds.
+        #[arg(long, default_value_t = 300)]
+        duration: u64,
+        /// Winners, affiliate, jackpot and platform shares in bips.
+        #[arg(long, value_parser = parse_allocation, default_value = "4500,500,4000,1000")]
+        allocation: Allocation,
+        /// Jackpot shares in bips for win streaks of 5 to 10 rounds.
+        #[arg(
+            long,
+            value_parser = parse_jackpot_allocation,
+            default_value = "1000,1500,2000,2500,3000,10000"
+        )]
+        jackpot_allocation: JackPotAllocation,
+        #[arg(long, default_value_t = 0)]
+        min_bet_amount: u64,
+        /// Maximum age of a price in seconds.
+        #[arg(long, default_value_t = 60)]
+        staleness_threshold: u64,
+    },
+    /// Update a platform setting. Only the owner can do this.
+    #[command(subcommand)]
+    Set(SetCommand),
+    /// Withdraw the accumulated platform fees to the owner.
+    WithdrawPlatformFees {
+        /// Token account to withdraw to. Defaults to the owner's associated token account.
+        #[arg(long)]
+        to: Option<Pubkey>,
+    },
+    /// Transfer ownership of the platform. Both the current and the new owner must sign.
+    TransferOwnership {
+        /// Path to the new owner's keypair.
+        new_owner_keypair: String,
+    },
+    /// Deposit stablecoin from the signer's associated token account.
+    Deposit { amount: u64 },
+    /// Withdraw stablecoin to the signer's associated token account.
+    Withdraw { amount: u64 },
+    /// Bet from the signer's balance in the current round.
+    Bet { amount: u64, side: Side },
+    /// Claim the signer's winnings from a round.
+    Claim { round: u64 },
+    /// Grow a user info account created before its latest fields to the current layout, paying
+    /// the extra rent.
+    MigrateUserInfo {
+        /// User whose account to migrate. Defaults to the signer.
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+    /// Print on-chain accounts.
+    #[command(subcommand)]
+    Show(ShowCommand),
+}
+
+#[derive(Subcommand)]
+enum SetCommand {
+    Duration {
+        duration: u64,
+    },
+    Allocation {
+        #[arg(value_parser = parse_allocation)]
+        allocation: Allocation,
+    },
+    JackpotAllocation {
+        #[arg(value_parser = parse_jackpot_allocation)]
+        jackpot_allocation: JackPotAllocation,
+    },
+    MinBetAmount {
+        min_bet_amount: u64,
+    },
+    PriceAccount {
+        price_account: Pubkey,
+    },
+    StalenessThreshold {
+        staleness_threshold: u64,
+    },
+    CancellationWindow {
+        cancellation_window: u64,
+    },
+    CancellationFee {
+        cancellation_fee: u16,
+    },
+    Gating {
+        #[arg(action = clap::ArgAction::Set)]
+        is_gated: bool,
+        #[arg(default_value_t = Pubkey::default())]
+        gatekeeper: Pubkey,
+    },
+    MaxBetAmount {
+        max_bet_amount: u64,
+    },
+    MaxUserBetAmountPerRound {
+        max_user_bet_amount_per_round: u64,
+    },
+    MaxRoundImbalance {
+        max_round_imbalance: u64,
+        #[arg(default_value_t = 0)]
+        max_one_sided_amount: u64,
+    },
+    BucketBoundaries {
+        #[arg(allow_negative_numbers = true)]
+        bucket_boundaries: Vec<i32>,
+    },
+    StrikeOffset {
+        #[arg(allow_negative_numbers = true)]
+        strike_offset: i32,
+    },
+    /// Let a keeper start rounds with an explicit strike price. The default pubkey revokes it.
+    StrikeAuthority {
+        #[arg(default_value_t = Pubkey::default())]
+        strike_authority: Pubkey,
+    },
+    HousePoolConfig {
+        max_exposure: u16,
+        platform_fee_share: u16,
+    },
+    FixedOddsConfig {
+        base_multiplier: u64,
+        skew: u16,
+    },
+    MintMarketEnabled {
+        mint: Pubkey,
+        #[arg(action = clap::ArgAction::Set)]
+        is_enabled: bool,
+    },
+    MintMarketBetLimits {
+        mint: Pubkey,
+        min_bet_amount: u64,
+        max_bet_amount: u64,
+        max_user_bet_amount_per_round: u64,
+        #[arg(default_value_t = 0)]
+        max_one_sided_amount: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum ShowCommand {
+    /// Print the platform config.
+    Config,
+    /// Print a round.
+    Round { round: u64 },
+    /// Print a user's info, and their bet in a round if given.
+    User {
+        user: Pubkey,
+        #[arg(long)]
+        round: Option<u64>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Side {
+    Long,
+    Short,
+}
+
+fn parse_shares<const N: usize>(value: &str) -> Result<[u16; N], String> {
+    let shares = value
+        .split(',')
+        .map(|share| u16::from_str(share.trim()).map_err(|error| error.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    shares
+        .try_into()
+        .map_err(|_| format!("expected {N} comma separated values in bips"))
+}
+
+fn parse_allocation(value: &str) -> Result<Allocation, String> {
+    let [winners_share, affiliate_share, jackpot_share, platform_share] = parse_shares(value)?;
+
+    Ok(Allocation {
+        winners_share,
+        affiliate_share,
+        jackpot_share,
+        platform_share,
+    })
+}
+
+fn parse_jackpot_allocation(value: &str) -> Result<JackPotAllocation, String> {
+    let [streak_5, streak_6, streak_7, streak_8, streak_9, streak_10] = parse_shares(value)?;
+
+    Ok(JackPotAllocation {
+        streak_5,
+        streak_6,
+        streak_7,
+        streak_8,
+        streak_9,
+        streak_10,
+    })
+}
+
+fn read_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{rest}", std::env::var("HOME")?),
+        None => path.to_string(),
+    };
+
+    read_keypair_file(&path).map_err(|error| anyhow!("failed to read keypair {path}: {error}"))
+}
+
+/// Returns the stablecoin, its token program and the signer's associated token account for it.
+fn stablecoin_accounts(program: &Program<&Keypair>) -> Result<(Pubkey, Pubkey, Pubkey)> {
+    let platform_config: PlatformConfig = program.account(pda::platform_config())?;
+    let stablecoin = platform_config.stablecoin;
+    let token_program = program.rpc().get_account(&stablecoin)?.owner;
+    let token_account =
+        get_associated_token_address_with_program_id(&program.payer(), &stablecoin, &token_program);
+
+    Ok((stablecoin, token_program, token_account))
+}
+
+fn set_instruction(
+    owner: Pubkey,
+    command: SetCommand,
+) -> anchor_client::solana_sdk::instruction::Instruction {
+    match command {
+        SetCommand::Duration { duration } => instructions::set_duration(owner, duration),
+        SetCommand::Allocation { allocation } => instructions::set_allocation(owner, allocation),
+        SetCommand::JackpotAllocation { jackpot_allocation } => {
+            instructions::set_jackpot_allocation(owner, jackpot_allocation)
+        }
+        SetCommand::MinBetAmount { min_bet_amount } => {
+            instructions::set_min_bet_amount(owner, min_bet_amount)
+        }
+        SetCommand::PriceAccount { price_account } => {
+            instructions::set_price_account(owner, price_account)
+        }
+        SetCommand::StalenessThreshold {
+            staleness_threshold,
+        } => instructions::set_staleness_threshold(owner, staleness_threshold),
+        SetCommand::CancellationWindow {
+            cancellation_window,
+        } => instructions::set_cancellation_window(owner, cancellation_window),
+        SetCommand::CancellationFee { cancellation_fee } => {
+            instructions::set_cancellation_fee(owner, cancellation_fee)
+        }
+        SetCommand::Gating {
+            is_gated,
+            gatekeeper,
+        } => instructions::set_gating(owner, is_gated, gatekeeper),
+        SetCommand::MaxBetAmount { max_bet_amount } => {
+            instructions::set_max_bet_amount(owner, max_bet_amount)
+        }
+        SetCommand::MaxUserBetAmountPerRound {
+            max_user_bet_amount_per_round,
+        } => instructions::set_max_user_bet_amount_per_round(owner, max_user_bet_amount_per_round),
+        SetCommand::MaxRoundImbalance {
+            max_round_imbalance,
+            max_one_sided_amount,
+        } => {
+            instructions::set_max_round_imbalance(owner, max_round_imbalance, max_one_sided_amount)
+        }
+        SetCommand::BucketBoundaries { bucket_boundaries } => {
+            instructions::set_bucket_boundaries(owner, bucket_boundaries)
+        }
+        SetCommand::StrikeOffset { strike_offset } => {
+            instructions::set_strike_offset(owner, strike_offset)
+        }
+        SetCommand::StrikeAuthority { strike_authority } => {
+            instructions::set_strike_authority(owner, strike_authority)
+        }
+        SetCommand::HousePoolConfig {
+            max_exposure,
+            platform_fee_share,
+        } => instructions::set_house_pool_config(owner, max_exposure, platform_fee_share),
+        SetCommand::FixedOddsConfig {
+            base_multiplier,
+            skew,
+        } => instructions::set_fixed_odds_config(owner, base_multiplier, skew),
+        SetCommand::MintMarketEnabled { mint, is_enabled } => {
+            instructions::set_mint_market_enabled(owner, mint, is_enabled)
+        }
+        SetCommand::MintMarketBetLimits {
+            mint,
+            min_bet_amount,
+            max_bet_amount,
+            max_user_bet_amount_per_round,
+            max_one_sided_amount,
+        } => instructions::set_mint_market_bet_limits(
+            owner,
+            mint,
+            min_bet_amount,
+            max_bet_amount,
+            max_user_bet_amount_per_round,
+            max_one_sided_amount,
+        ),
+    }
+}
+
+fn show(program: &Program<&Keypair>, command: ShowCommand) -> Result<()> {
+    match command {
+        ShowCommand::Config => {
+            let platform_config: PlatformConfig = program
+                .account(pda::platform_config())
+                .context("platform is not initialized")?;
+            display::print_platform_config(&platform_config);
+        }
+        ShowCommand::Round { round } => {
+            let round_account: Round = program
+                .account(pda::round(round))
+                .with_context(|| format!("round {round} has not been started"))?;
+            display::print_round(round, &round_account);
+        }
+        ShowCommand::User { user, round } => {
+            let user_info: UserInfo = program
+                .account(pda::user_info(&user))
+                .with_context(|| format!("{user} has no user info"))?;
+            display::print_user_info(&user, &user_info);
+
+            if let Some(round) = round {
+                let platform_config: PlatformConfig = program.account(pda::platform_config())?;
+                let round_account: Round = program.account(pda::round(round))?;
+                let bet: Bet = program
+                    .account(pda::user_bet(&user, round))
+                    .with_context(|| format!("{user} has no bet in round {round}"))?;
+                println!();
+                display::print_bet(
+                    round,
+                    &bet,
+                    &round_account,
+                    &user_info,
+                    &platform_config.global_round_info,
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let payer = read_keypair(&cli.keypair)?;
+    let cluster = Cluster::from_str(&cli.url)?;
+    let client = Client::new_with_options(cluster, &payer, CommitmentConfig::confirmed());
+    let program = client.program(bearish_dot_fun::ID)?;
+    let signer = payer.pubkey();
+
+    let request = program.request();
+    let request = match cli.command {
+        Command::Show(command) => return show(&program, command),
+        Command::Initialize {
+            stablecoin,
+            price_account,
+            duration,
+            allocation,
+            jackpot_allocation,
+            min_bet_amount,
+            staleness_threshold,
+        } => {
+            let token_program = program.rpc().get_account(&stablecoin)?.owner;
+            let global_round_info = GlobalRoundInfo {
+                round: 0,
+                duration,
+                allocation,
+                jackpot_allocation,
+                min_bet_amount,
+                price_account,
+                staleness_threshold,
+                jackpot_pool_amount: 0,
+                accumulated_platform_fees: 0,
+                cancellation_window: 0,
+                cancellation_fee: 0,
+                max_bet_amount: 0,
+                max_user_bet_amount_per_round: 0,
+                max_round_imbalance: 0,
+                max_one_sided_amount: 0,
+            };
+
+            request.instruction(instructions::initialize(
+                signer,
+                stablecoin,
+                token_program,
+                global_round_info,
+                AllowedMintExtensions::default(),
+            ))
+        }
+        Command::Set(command) => request.instruction(set_instruction(signer, command)),
+        Command::WithdrawPlatformFees { to } => {
+            let (stablecoin, token_program, token_account) = stablecoin_accounts(&program)?;
+
+            request.instruction(instructions::withdraw_platform_fees(
+                signer,
+                stablecoin,
+                to.unwrap_or(token_account),
+                token_program,
+            ))
+        }
+        Command::TransferOwnership { new_owner_keypair } => {
+            let new_owner = read_keypair(&new_owner_keypair)?;
+
+            request
+                .instruction(instructions::transfer_ownership(signer, new_owner.pubkey()))
+                .signer(new_owner)
+        }
+        Command::Deposit { amount } => {
+            let (stablecoin, token_program, token_account) = stablecoin_accounts(&program)?;
+
+            request.instruction(instructions::deposit(
+                signer,
+                stablecoin,
+                token_account,
+                token_program,
+                amount,
+            ))
+        }
+        Command::Withdraw { amount } => {
+            let (stablecoin, token_program, token_account) = stablecoin_accounts(&program)?;
+
+            request.instruction(instructions::withdraw(
+                signer,
+                stablecoin,
+                token_account,
+                token_program,
+                amount,
+            ))
+        }
+        Command::Bet { amount, side } => {
+            let platform_config: PlatformConfig = program.account(pda::platform_config())?;
+            let round = pda::current_round(&platform_config.global_round_info);
+
+            request.instruction(instructions::place_bet(
+                signer,
+                signer,
+                round,
+                platform_config.has_house_pool,
+                amount,
+                matches!(side, Side::Long),
+            ))
+        }
+        Command::Claim { round } => {
+            let user_info: UserInfo = program.account(pda::user_info(&signer))?;
+            let wallet_payout = match user_info.winnings_preference {
+                WinningsPreference::WithdrawToWallet { token_account } => {
+                    let (stablecoin, token_program, _) = stablecoin_accounts(&program)?;
+
+                    Some(WalletPayout {
+                        stablecoin,
+                        token_account,
+                        token_program,
+                    })
+                }
+                _ => None,
+            };
+
+            request.instruction(instructions::claim_user_winnings(
+                signer,
+                signer,
+                round,
+                wallet_payout,
+            ))
+        }
+        Command::MigrateUserInfo { user } => request.instruction(instructions::migrate_user_info(
+            signer,
+            user.unwrap_or(signer),
+        )),
+    };
+
+    let signature = request.send()?;
+    println!("{signature}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("bearish").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn the_command_line_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn allocations_take_one_share_per_pool() {
+        let allocation = parse_allocation("4500, 500,4000,1000").unwrap();
+        assert_eq!(allocation.winners_share, 4_500);
+        assert_eq!(allocation.affiliate_share, 500);
+        assert_eq!(allocation.jackpot_share, 4_000);
+        assert_eq!(allocation.platform_share, 1_000);
+
+        assert!(parse_allocation("4500,500,4000").is_err());
+        assert!(parse_allocation("4500,500,4000,1000,0").is_err());
+        assert!(parse_allocation("4500,500,4000,-1").is_err());
+        assert_eq!(
+            parse_jackpot_allocation("1,2,3,4,5,6").unwrap().streak_10,
+            6
+        );
+    }
+
+    #[test]
+    fn initialize_has_defaults_for_everything_but_the_accounts() {
+        let stablecoin = Pubkey::new_unique();
+        let price_account = Pubkey::new_unique();
+        let cli = parse(&[
+            "initialize",
+            "--stablecoin",
+            &stablecoin.to_string(),
+            "--price-account",
+            &price_account.to_string(),
+        ])
+        .unwrap();
+
+        let Command::Initialize {
+            stablecoin: parsed_stablecoin,
+            duration,
+            allocation,
+            jackpot_allocation,
+            ..
+        } = cli.command
+        else {
+            panic!("expected initialize");
+        };
+        assert_eq!(parsed_stablecoin, stablecoin);
+        assert_eq!(duration, 300);
+        assert_eq!(allocation.winners_share, 4_500);
+        assert_eq!(jackpot_allocation.streak_10, 10_000);
+
+        assert!(parse(&["initialize", "--stablecoin", &stablecoin.to_string()]).is_err());
+    }
+
+    #[test]
+    fn bets_take_an_amount_and_a_side() {
+        let cli = parse(&["bet", "100", "short", "-u", "http://localhost:8899"]).unwrap();
+        assert_eq!(cli.url, "http://localhost:8899");
+        assert!(matches!(
+            cli.command,
+            Command::Bet {
+                amount: 100,
+                side: Side::Short
+            }
+        ));
+
+        assert!(parse(&["bet", "100", "sideways"]).is_err());
+        assert!(parse(&["bet", "-100", "long"]).is_err());
+    }
+
+    #[test]
+    fn settings_map_to_their_instructions() {
+        let owner = Pubkey::new_unique();
+        let set = |args: &[&str]| {
+            let Command::Set(command) = parse(&[&["set"], args].concat()).unwrap().command else {
+                panic!("expected set");
+            };
+            set_instruction(owner, command)
+        };
+
+        assert_eq!(
+            set(&["strike-offset", "-200"]),
+            instructions::set_strike_offset(owner, -200)
+        );
+        assert_eq!(
+            set(&["bucket-boundaries", "-100", "0", "100"]),
+            instructions::set_bucket_boundaries(owner, vec![-100, 0, 100])
+        );
+        assert_eq!(
+            set(&["gating", "false"]),
+            instructions::set_gating(owner, false, Pubkey::default())
+        );
+        assert_eq!(
+            set(&["max-round-imbalance", "20000", "300"]),
+            instructions::set_max_round_imbalance(owner, 20_000, 300)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+pub use bearish_dot_fun::{
+    Attestation, Bet, BlockedUser, BucketBet, HousePool, MintMarket, Order, PlatformConfig, Round,
+    Session, UserInfo,
+};
+
+/// Deserializes a program account from its raw data, checking its discriminator.
+pub fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn platform_config(data: &[u8]) -> Result<PlatformConfig> {
+    deserialize(data)
+}
+
+pub fn round(data: &[u8]) -> Result<Round> {
+    deserialize(data)
+}
+
+pub fn user_info(data: &[u8]) -> Result<UserInfo> {
+    deserialize(data)
+}
+
+pub fn bet(data: &[u8]) -> Result<Bet> {
+    deserialize(data)
+}
+
+pub fn bucket_bet(data: &[u8]) -> Result<BucketBet> {
+    deserialize(data)
+}
+
+pub fn house_pool(data: &[u8]) -> Result<HousePool> {
+    deserialize(data)
+}
+
+pub fn mint_market(data: &[u8]) -> Result<MintMarket> {
+    deserialize(data)
+}
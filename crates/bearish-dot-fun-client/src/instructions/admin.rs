@@ -0,0 +1,398 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, system_program};
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{
+    accounts, instruction, Allocation, AllowedMintExtensions, GlobalRoundInfo, JackPotAllocation,
+};
+
+use super::build;
+use crate::pda;
+
+pub fn initialize(
+    owner: Pubkey,
+    stablecoin: Pubkey,
+    token_program: Pubkey,
+    global_round_info: GlobalRoundInfo,
+    allowed_mint_extensions: AllowedMintExtensions,
+) -> Instruction {
+    build(
+        accounts::Initialize {
+            owner,
+            stablecoin,
+            platform_vault: pda::platform_vault(),
+            platform_config: pda::platform_config(),
+            system_program: system_program::ID,
+            token_program,
+        },
+        instruction::Initialize {
+            global_round_info,
+            allowed_mint_extensions,
+        },
+    )
+}
+
+/// Both the current and the new owner must sign.
+pub fn transfer_ownership(owner: Pubkey, new_owner: Pubkey) -> Instruction {
+    build(
+        accounts::TransferOwnership {
+            owner,
+            new_owner,
+            platform_config: pda::platform_config(),
+        },
+        instruction::TransferOwnership {},
+    )
+}
+
+fn set_platform_config_accounts(owner: Pubkey) -> accounts::SetPlatformConfig {
+    accounts::SetPlatformConfig {
+        owner,
+        platform_config: pda::platform_config(),
+    }
+}
+
+pub fn set_duration(owner: Pubkey, duration: u64) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetDuration { duration },
+    )
+}
+
+pub fn set_allocation(owner: Pubkey, allocation: Allocation) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetAllocation { allocation },
+    )
+}
+
+pub fn set_jackpot_allocation(owner: Pubkey, jackpot_allocation: JackPotAllocation) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetJackpotAllocation { jackpot_allocation },
+    )
+}
+
+pub fn set_min_bet_amount(owner: Pubkey, min_bet_amount: u64) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetMinBetAmount { min_bet_amount },
+    )
+}
+
+pub fn set_price_account(owner: Pubkey, price_account: Pubkey) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetPriceAccount { price_account },
+    )
+}
+
+pub fn set_staleness_threshold(owner: Pubkey, staleness_threshold: u64) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetStalenessThreshold {
+            staleness_threshold,
+        },
+    )
+}
+
+pub fn set_cancellation_window(owner: Pubkey, cancellation_window: u64) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetCancellationWindow {
+            cancellation_window,
+        },
+    )
+}
+
+pub fn set_cancellation_fee(owner: Pubkey, cancellation_fee: u16) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetCancellationFee { cancellation_fee },
+    )
+}
+
+pub fn set_gating(owner: Pubkey, is_gated: bool, gatekeeper: Pubkey) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetGating {
+            is_gated,
+            gatekeeper,
+        },
+    )
+}
+
+pub fn set_max_bet_amount(owner: Pubkey, max_bet_amount: u64) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetMaxBetAmount { max_bet_amount },
+    )
+}
+
+pub fn set_max_user_bet_amount_per_round(
+    owner: Pubkey,
+    max_user_bet_amount_per_round: u64,
+) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetMaxUserBetAmountPerRound {
+            max_user_bet_amount_per_round,
+        },
+    )
+}
+
+pub fn set_max_round_imbalance(
+    owner: Pubkey,
+    max_round_imbalance: u64,
+    max_one_sided_amount: u64,
+) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetMaxRoundImbalance {
+            max_round_imbalance,
+            max_one_sided_amount,
+        },
+    )
+}
+
+pub fn set_bucket_boundaries(owner: Pubkey, bucket_boundaries: Vec<i32>) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetBucketBoundaries { bucket_boundaries },
+    )
+}
+
+pub fn set_strike_offset(owner: Pubkey, strike_offset: i32) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetStrikeOffset { strike_offset },
+    )
+}
+
+pub fn set_strike_authority(owner: Pubkey, strike_authority: Pubkey) -> Instruction {
+    build(
+        set_platform_config_accounts(owner),
+        instruction::SetStrikeAuthority { strike_authority },
+    )
+}
+
+pub fn initialize_house_pool(
+    owner: Pubkey,
+    stablecoin: Pubkey,
+    token_program: Pubkey,
+    max_exposure: u16,
+    platform_fee_share: u16,
+) -> Instruction {
+    build(
+        accounts::InitializeHousePool {
+            owner,
+            platform_config: pda::platform_config(),
+            stablecoin,
+            house_pool: pda::house_pool(),
+            lp_mint: pda::lp_mint(),
+            system_program: system_program::ID,
+            token_program,
+        },
+        instruction::InitializeHousePool {
+            max_exposure,
+            platform_fee_share,
+        },
+    )
+}
+
+fn set_house_pool_config_accounts(owner: Pubkey) -> accounts::SetHousePoolConfig {
+    accounts::SetHousePoolConfig {
+        owner,
+        platform_config: pda::platform_config(),
+        house_pool: pda::house_pool(),
+    }
+}
+
+pub fn set_house_pool_config(
+    owner: Pubkey,
+    max_exposure: u16,
+    platform_fee_share: u16,
+) -> Instruction {
+    build(
+        set_house_pool_config_accounts(owner),
+        instruction::SetHousePoolConfig {
+            max_exposure,
+            platform_fee_share,
+        },
+    )
+}
+
+pub fn set_fixed_odds_config(owner: Pubkey, base_multiplier: u64, skew: u16) -> Instruction {
+    build(
+        set_house_pool_config_accounts(owner),
+        instruction::SetFixedOddsConfig {
+            base_multiplier,
+            skew,
+        },
+    )
+}
+
+pub fn block_user(owner: Pubkey, user: Pubkey) -> Instruction {
+    build(
+        accounts::BlockUser {
+            owner,
+            platform_config: pda::platform_config(),
+            blocked_user: pda::blocked_user(&user),
+            system_program: system_program::ID,
+        },
+        instruction::BlockUser { user },
+    )
+}
+
+pub fn unblock_user(owner: Pubkey, user: Pubkey) -> Instruction {
+    build(
+        accounts::UnblockUser {
+            owner,
+            platform_config: pda::platform_config(),
+            blocked_user: pda::blocked_user(&user),
+            system_program: system_program::ID,
+        },
+        instruction::UnblockUser {},
+    )
+}
+
+pub fn issue_attestation(gatekeeper: Pubkey, user: Pubkey, expires_at: u64) -> Instruction {
+    build(
+        accounts::IssueAttestation {
+            gatekeeper,
+            platform_config: pda::platform_config(),
+            attestation: pda::attestation(&user),
+            system_program: system_program::ID,
+        },
+        instruction::IssueAttestation { user, expires_at },
+    )
+}
+
+pub fn revoke_attestation(gatekeeper: Pubkey, user: Pubkey) -> Instruction {
+    build(
+        accounts::RevokeAttestation {
+            gatekeeper,
+            platform_config: pda::platform_config(),
+            attestation: pda::attestation(&user),
+        },
+        instruction::RevokeAttestation {},
+    )
+}
+
+pub fn withdraw_platform_fees(
+    owner: Pubkey,
+    stablecoin: Pubkey,
+    owner_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawPlatformFees {
+            owner,
+            platform_config: pda::platform_config(),
+            stablecoin,
+            platform_vault: pda::platform_vault(),
+            owner_token_account,
+            token_program,
+        },
+        instruction::WithdrawPlatformFees {},
+    )
+}
+
+pub fn whitelist_mint(
+    owner: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    allowed_mint_extensions: AllowedMintExtensions,
+) -> Instruction {
+    build(
+        accounts::WhitelistMint {
+            owner,
+            platform_config: pda::platform_config(),
+            mint,
+            mint_market: pda::mint_market(&mint),
+            mint_vault: pda::mint_vault(&mint),
+            system_program: system_program::ID,
+            token_program,
+        },
+        instruction::WhitelistMint {
+            allowed_mint_extensions,
+        },
+    )
+}
+
+pub fn set_mint_market_enabled(owner: Pubkey, mint: Pubkey, is_enabled: bool) -> Instruction {
+    build(
+        accounts::SetMintMarket {
+            owner,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&mint),
+        },
+        instruction::SetMintMarketEnabled { is_enabled },
+    )
+}
+
+pub fn set_mint_market_bet_limits(
+    owner: Pubkey,
+    mint: Pubkey,
+    min_bet_amount: u64,
+    max_bet_amount: u64,
+    max_user_bet_amount_per_round: u64,
+    max_one_sided_amount: u64,
+) -> Instruction {
+    build(
+        accounts::SetMintMarket {
+            owner,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&mint),
+        },
+        instruction::SetMintMarketBetLimits {
+            min_bet_amount,
+            max_bet_amount,
+            max_user_bet_amount_per_round,
+            max_one_sided_amount,
+        },
+    )
+}
+
+pub fn withdraw_mint_platform_fees(
+    owner: Pubkey,
+    mint: Pubkey,
+    owner_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawMintPlatformFees {
+            owner,
+            platform_config: pda::platform_config(),
+            mint,
+            mint_market: pda::mint_market(&mint),
+            mint_vault: pda::mint_vault(&mint),
+            owner_token_account,
+            token_program,
+        },
+        instruction::WithdrawMintPlatformFees {},
+    )
+}
+
+pub fn whitelist_native_sol(owner: Pubkey) -> Instruction {
+    build(
+        accounts::WhitelistNativeSol {
+            owner,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&spl_token::native_mint::ID),
+            native_vault: pda::native_vault(),
+            system_program: system_program::ID,
+        },
+        instruction::WhitelistNativeSol {},
+    )
+}
+
+pub fn withdraw_native_platform_fees(owner: Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawNativePlatformFees {
+            owner,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&spl_token::native_mint::ID),
+            native_vault: pda::native_vault(),
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawNativePlatformFees {},
+    )
+}
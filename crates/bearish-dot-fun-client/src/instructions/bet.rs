@@ -0,0 +1,186 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, system_program};
+use bearish_dot_fun::{accounts, instruction};
+
+use super::{access, build};
+use crate::pda;
+
+/// Places a bet for `user` in the current round number `round`. If `authority` is not the user,
+/// it bets as a delegate through its session. Pass `with_house_pool` if the house pool is enabled,
+/// here and for every other instruction that changes a round's pools.
+pub fn place_bet(
+    authority: Pubkey,
+    user: Pubkey,
+    round: u64,
+    with_house_pool: bool,
+    amount: u64,
+    is_long: bool,
+) -> Instruction {
+    build(
+        accounts::PlaceBet {
+            authority,
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            access: access(&user),
+            session: (authority != user).then(|| pda::session(&user, &authority)),
+            house_pool: with_house_pool.then(pda::house_pool),
+            system_program: system_program::ID,
+        },
+        instruction::PlaceBet { amount, is_long },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn place_bet_from_wallet(
+    user: Pubkey,
+    stablecoin: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+    round: u64,
+    with_house_pool: bool,
+    amount: u64,
+    is_long: bool,
+) -> Instruction {
+    build(
+        accounts::PlaceBetFromWallet {
+            user,
+            platform_config: pda::platform_config(),
+            stablecoin,
+            platform_vault: pda::platform_vault(),
+            user_token_account,
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            access: access(&user),
+            house_pool: with_house_pool.then(pda::house_pool),
+            system_program: system_program::ID,
+            token_program,
+        },
+        instruction::PlaceBetFromWallet { amount, is_long },
+    )
+}
+
+/// Places a bet in a whitelisted mint's pools. Pass `has_platform_user_info` if the user has a
+/// platform user info account, so that their affiliate carries over to the mint.
+pub fn place_mint_bet(
+    user: Pubkey,
+    mint: Pubkey,
+    round: u64,
+    has_platform_user_info: bool,
+    amount: u64,
+    is_long: bool,
+) -> Instruction {
+    build(
+        accounts::PlaceMintBet {
+            user,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&mint),
+            user_info: pda::mint_user_info(&user, &mint),
+            platform_user_info: has_platform_user_info.then(|| pda::user_info(&user)),
+            round: pda::round(round),
+            mint_round: pda::mint_round(round, &mint),
+            user_bet: pda::mint_user_bet(&user, round, &mint),
+            access: access(&user),
+            system_program: system_program::ID,
+        },
+        instruction::PlaceMintBet { amount, is_long },
+    )
+}
+
+pub fn place_bucket_bet(user: Pubkey, round: u64, amount: u64, bucket: u8) -> Instruction {
+    build(
+        accounts::PlaceBucketBet {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            bucket_bet: pda::bucket_bet(&user, round),
+            access: access(&user),
+            system_program: system_program::ID,
+        },
+        instruction::PlaceBucketBet { amount, bucket },
+    )
+}
+
+pub fn place_fixed_odds_bet(user: Pubkey, round: u64, amount: u64, is_long: bool) -> Instruction {
+    build(
+        accounts::PlaceFixedOddsBet {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            house_pool: pda::house_pool(),
+            fixed_odds_bet: pda::fixed_odds_bet(&user, round),
+            access: access(&user),
+            system_program: system_program::ID,
+        },
+        instruction::PlaceFixedOddsBet { amount, is_long },
+    )
+}
+
+pub fn execute_repeat_bet(
+    keeper: Pubkey,
+    user: Pubkey,
+    round: u64,
+    with_house_pool: bool,
+) -> Instruction {
+    build(
+        accounts::ExecuteRepeatBet {
+            keeper,
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            access: access(&user),
+            house_pool: with_house_pool.then(pda::house_pool),
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteRepeatBet {},
+    )
+}
+
+/// Executes `user`'s order `order_id` in the current round number `round`. The order's
+/// condition is checked against the previous round.
+pub fn execute_order(
+    executor: Pubkey,
+    user: Pubkey,
+    order_id: u64,
+    round: u64,
+    with_house_pool: bool,
+) -> Instruction {
+    build(
+        accounts::ExecuteOrder {
+            executor,
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            executor_info: pda::user_info(&executor),
+            order: pda::order(&user, order_id),
+            previous_round: pda::round(round - 1),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            access: access(&user),
+            house_pool: with_house_pool.then(pda::house_pool),
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteOrder {},
+    )
+}
+
+pub fn cancel_bet(user: Pubkey, round: u64, with_house_pool: bool, amount: u64) -> Instruction {
+    build(
+        accounts::CancelBet {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            house_pool: with_house_pool.then(pda::house_pool),
+            system_program: system_program::ID,
+        },
+        instruction::CancelBet { amount },
+    )
+}
@@ -0,0 +1,170 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, system_program};
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{accounts, instruction};
+
+use super::build;
+use crate::pda;
+
+/// Accounts needed to pay winnings straight to a wallet, for users whose winnings preference is
+/// `WithdrawToWallet`.
+#[derive(Clone, Copy, Debug)]
+pub struct WalletPayout {
+    pub stablecoin: Pubkey,
+    pub token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Claims `user`'s winnings in round number `round`. If `authority` is not the user, it claims
+/// as a delegate through its session.
+pub fn claim_user_winnings(
+    authority: Pubkey,
+    user: Pubkey,
+    round: u64,
+    wallet_payout: Option<WalletPayout>,
+) -> Instruction {
+    build(
+        accounts::ClaimUserWinnings {
+            authority,
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            session: (authority != user).then(|| pda::session(&user, &authority)),
+            stablecoin: wallet_payout.map(|payout| payout.stablecoin),
+            platform_vault: wallet_payout.map(|_| pda::platform_vault()),
+            payout_token_account: wallet_payout.map(|payout| payout.token_account),
+            system_program: system_program::ID,
+            token_program: wallet_payout.map(|payout| payout.token_program),
+        },
+        instruction::ClaimUserWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_affiliate_winnings(
+    affiliate: Pubkey,
+    user: Pubkey,
+    round: u64,
+    stablecoin: Pubkey,
+    affiliate_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimAffiliateWinnings {
+            user,
+            affiliate,
+            platform_config: pda::platform_config(),
+            platform_vault: pda::platform_vault(),
+            stablecoin,
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+            affiliate_token_account,
+            system_program: system_program::ID,
+            token_program,
+        },
+        instruction::ClaimAffiliateWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_mint_user_winnings(
+    authority: Pubkey,
+    user: Pubkey,
+    mint: Pubkey,
+    round: u64,
+) -> Instruction {
+    build(
+        accounts::ClaimMintUserWinnings {
+            authority,
+            user,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&mint),
+            user_info: pda::mint_user_info(&user, &mint),
+            mint_round: pda::mint_round(round, &mint),
+            user_bet: pda::mint_user_bet(&user, round, &mint),
+            session: (authority != user).then(|| pda::session(&user, &authority)),
+        },
+        instruction::ClaimMintUserWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_mint_affiliate_winnings(
+    affiliate: Pubkey,
+    user: Pubkey,
+    mint: Pubkey,
+    round: u64,
+    affiliate_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimMintAffiliateWinnings {
+            user,
+            affiliate,
+            platform_config: pda::platform_config(),
+            mint,
+            mint_market: pda::mint_market(&mint),
+            mint_vault: pda::mint_vault(&mint),
+            mint_round: pda::mint_round(round, &mint),
+            user_bet: pda::mint_user_bet(&user, round, &mint),
+            affiliate_token_account,
+            token_program,
+        },
+        instruction::ClaimMintAffiliateWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_native_affiliate_winnings(affiliate: Pubkey, user: Pubkey, round: u64) -> Instruction {
+    let native_mint = spl_token::native_mint::ID;
+
+    build(
+        accounts::ClaimNativeAffiliateWinnings {
+            user,
+            affiliate,
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&native_mint),
+            native_vault: pda::native_vault(),
+            mint_round: pda::mint_round(round, &native_mint),
+            user_bet: pda::mint_user_bet(&user, round, &native_mint),
+            system_program: system_program::ID,
+        },
+        instruction::ClaimNativeAffiliateWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_bucket_winnings(user: Pubkey, round: u64) -> Instruction {
+    build(
+        accounts::ClaimBucketWinnings {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            bucket_bet: pda::bucket_bet(&user, round),
+        },
+        instruction::ClaimBucketWinnings {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn claim_fixed_odds_winnings(user: Pubkey, round: u64) -> Instruction {
+    build(
+        accounts::ClaimFixedOddsWinnings {
+            user,
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            fixed_odds_bet: pda::fixed_odds_bet(&user, round),
+        },
+        instruction::ClaimFixedOddsWinnings {
+            round_index: round - 1,
+        },
+    )
+}
@@ -0,0 +1,65 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use bearish_dot_fun::{accounts, instruction};
+
+use super::build;
+use crate::pda;
+
+fn house_liquidity_accounts(
+    liquidity_provider: Pubkey,
+    stablecoin: Pubkey,
+    liquidity_provider_token_account: Pubkey,
+    liquidity_provider_lp_token_account: Pubkey,
+    token_program: Pubkey,
+) -> accounts::HouseLiquidity {
+    accounts::HouseLiquidity {
+        liquidity_provider,
+        platform_config: pda::platform_config(),
+        house_pool: pda::house_pool(),
+        lp_mint: pda::lp_mint(),
+        stablecoin,
+        platform_vault: pda::platform_vault(),
+        liquidity_provider_token_account,
+        liquidity_provider_lp_token_account,
+        token_program,
+    }
+}
+
+pub fn deposit_liquidity(
+    liquidity_provider: Pubkey,
+    stablecoin: Pubkey,
+    liquidity_provider_token_account: Pubkey,
+    liquidity_provider_lp_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        house_liquidity_accounts(
+            liquidity_provider,
+            stablecoin,
+            liquidity_provider_token_account,
+            liquidity_provider_lp_token_account,
+            token_program,
+        ),
+        instruction::DepositLiquidity { amount },
+    )
+}
+
+pub fn withdraw_liquidity(
+    liquidity_provider: Pubkey,
+    stablecoin: Pubkey,
+    liquidity_provider_token_account: Pubkey,
+    liquidity_provider_lp_token_account: Pubkey,
+    token_program: Pubkey,
+    shares: u64,
+) -> Instruction {
+    build(
+        house_liquidity_accounts(
+            liquidity_provider,
+            stablecoin,
+            liquidity_provider_token_account,
+            liquidity_provider_lp_token_account,
+            token_program,
+        ),
+        instruction::WithdrawLiquidity { shares },
+    )
+}
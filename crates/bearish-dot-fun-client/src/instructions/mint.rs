@@ -0,0 +1,80 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, system_program};
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{accounts, instruction};
+
+use super::{access, build};
+use crate::pda;
+
+fn mint_deposit_and_withdraw_accounts(
+    user: Pubkey,
+    mint: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+) -> accounts::MintDepositAndWithdraw {
+    accounts::MintDepositAndWithdraw {
+        user,
+        platform_config: pda::platform_config(),
+        mint,
+        mint_market: pda::mint_market(&mint),
+        mint_vault: pda::mint_vault(&mint),
+        user_token_account,
+        user_info: pda::mint_user_info(&user, &mint),
+        access: access(&user),
+        system_program: system_program::ID,
+        token_program,
+    }
+}
+
+pub fn deposit_mint(
+    user: Pubkey,
+    mint: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        mint_deposit_and_withdraw_accounts(user, mint, user_token_account, token_program),
+        instruction::DepositMint { amount },
+    )
+}
+
+pub fn withdraw_mint(
+    user: Pubkey,
+    mint: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        mint_deposit_and_withdraw_accounts(user, mint, user_token_account, token_program),
+        instruction::WithdrawMint { amount },
+    )
+}
+
+fn native_deposit_and_withdraw_accounts(user: Pubkey) -> accounts::NativeDepositAndWithdraw {
+    let native_mint = spl_token::native_mint::ID;
+
+    accounts::NativeDepositAndWithdraw {
+        user,
+        platform_config: pda::platform_config(),
+        mint_market: pda::mint_market(&native_mint),
+        native_vault: pda::native_vault(),
+        user_info: pda::mint_user_info(&user, &native_mint),
+        access: access(&user),
+        system_program: system_program::ID,
+    }
+}
+
+pub fn deposit_native_sol(user: Pubkey, amount: u64) -> Instruction {
+    build(
+        native_deposit_and_withdraw_accounts(user),
+        instruction::DepositNativeSol { amount },
+    )
+}
+
+pub fn withdraw_native_sol(user: Pubkey, amount: u64) -> Instruction {
+    build(
+        native_deposit_and_withdraw_accounts(user),
+        instruction::WithdrawNativeSol { amount },
+    )
+}
@@ -0,0 +1,42 @@
+//! Instruction builders for every instruction of the program. Accounts that are PDAs of the
+//! program are derived from the arguments, everything else has to be passed in.
+
+pub mod admin;
+pub mod bet;
+pub mod claim;
+pub mod house;
+pub mod mint;
+pub mod quote;
+pub mod round;
+pub mod user;
+
+pub use admin::*;
+pub use bet::*;
+pub use claim::*;
+pub use house::*;
+pub use mint::*;
+pub use quote::*;
+pub use round::*;
+pub use user::*;
+
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use bearish_dot_fun::accounts;
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: bearish_dot_fun::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// The user's attestation and blocklist PDAs, checked by every instruction that lets them move
+/// funds or bet.
+fn access(user: &Pubkey) -> accounts::UserAccess {
+    accounts::UserAccess {
+        attestation: pda::attestation(user),
+        blocked_user: pda::blocked_user(user),
+    }
+}
@@ -0,0 +1,49 @@
+//! Read-only instructions that return their result through the transaction's return data. Run
+//! them with `simulateTransaction` and decode the return data as `BetQuote`, `ClaimQuote` or
+//! `JackpotQuote`.
+
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use bearish_dot_fun::{accounts, instruction};
+
+use super::build;
+use crate::pda;
+
+/// Quotes a bet of `amount` on round number `round`, which must be the current round. Pass
+/// `with_house_pool` if the house pool is enabled.
+pub fn quote_bet(round: u64, with_house_pool: bool, amount: u64, is_long: bool) -> Instruction {
+    build(
+        accounts::QuoteBet {
+            platform_config: pda::platform_config(),
+            round: pda::round(round),
+            house_pool: with_house_pool.then(pda::house_pool),
+        },
+        instruction::QuoteBet { amount, is_long },
+    )
+}
+
+/// Quotes claiming `user`'s winnings in round number `round`.
+pub fn quote_claim(user: Pubkey, round: u64) -> Instruction {
+    build(
+        accounts::QuoteClaim {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            round: pda::round(round),
+            user_bet: pda::user_bet(&user, round),
+        },
+        instruction::QuoteClaim {
+            round_index: round - 1,
+        },
+    )
+}
+
+pub fn quote_jackpot(user: Pubkey) -> Instruction {
+    build(
+        accounts::QuoteJackpot {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+        },
+        instruction::QuoteJackpot {},
+    )
+}
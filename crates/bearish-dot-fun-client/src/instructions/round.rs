@@ -0,0 +1,87 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, system_program};
+use bearish_dot_fun::{accounts, instruction};
+
+use super::build;
+use crate::pda;
+
+fn run_round_accounts(
+    user: Pubkey,
+    round: u64,
+    price_account: Pubkey,
+    with_house_pool: bool,
+) -> accounts::RunRound {
+    accounts::RunRound {
+        user,
+        platform_config: pda::platform_config(),
+        round: pda::round(round),
+        price_account,
+        house_pool: with_house_pool.then(pda::house_pool),
+        system_program: system_program::ID,
+    }
+}
+
+/// Starts round number `round`, which must be the current round.
+pub fn start_round(user: Pubkey, round: u64, price_account: Pubkey) -> Instruction {
+    build(
+        run_round_accounts(user, round, price_account, false),
+        instruction::StartRound {},
+    )
+}
+
+pub fn start_round_with_strike(
+    authority: Pubkey,
+    round: u64,
+    price_account: Pubkey,
+    strike_price: u64,
+) -> Instruction {
+    build(
+        accounts::StartRoundWithStrike {
+            authority,
+            platform_config: pda::platform_config(),
+            round: pda::round(round),
+            price_account,
+            system_program: system_program::ID,
+        },
+        instruction::StartRoundWithStrike { strike_price },
+    )
+}
+
+/// Stops `start_round` from starting the next round until `start_round_with_strike` does, or
+/// lets it again.
+pub fn set_strike_override_pending(authority: Pubkey, is_pending: bool) -> Instruction {
+    build(
+        accounts::SetStrikeOverridePending {
+            authority,
+            platform_config: pda::platform_config(),
+        },
+        instruction::SetStrikeOverridePending { is_pending },
+    )
+}
+
+/// Ends round number `round`, which must be the current round. The house pool is required if it
+/// is enabled or the round has fixed-odds bets.
+pub fn end_round(
+    user: Pubkey,
+    round: u64,
+    price_account: Pubkey,
+    with_house_pool: bool,
+) -> Instruction {
+    build(
+        run_round_accounts(user, round, price_account, with_house_pool),
+        instruction::EndRound {},
+    )
+}
+
+pub fn settle_mint_round(mint: Pubkey, round: u64) -> Instruction {
+    build(
+        accounts::SettleMintRound {
+            platform_config: pda::platform_config(),
+            mint_market: pda::mint_market(&mint),
+            round: pda::round(round),
+            mint_round: pda::mint_round(round, &mint),
+        },
+        instruction::SettleMintRound {
+            round_index: round - 1,
+        },
+    )
+}
@@ -0,0 +1,178 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_instruction},
+    system_program,
+};
+use bearish_dot_fun::{
+    accounts, instruction, OrderCondition, SessionPermissions, WinningsPreference,
+};
+
+use super::{access, build};
+use crate::pda;
+
+fn deposit_and_withdraw_accounts(
+    user: Pubkey,
+    stablecoin: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+) -> accounts::DepositAndWithdraw {
+    accounts::DepositAndWithdraw {
+        user,
+        platform_config: pda::platform_config(),
+        stablecoin,
+        platform_vault: pda::platform_vault(),
+        user_token_account,
+        user_info: pda::user_info(&user),
+        access: access(&user),
+        system_program: system_program::ID,
+        token_program,
+    }
+}
+
+pub fn deposit(
+    user: Pubkey,
+    stablecoin: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        deposit_and_withdraw_accounts(user, stablecoin, user_token_account, token_program),
+        instruction::Deposit { amount },
+    )
+}
+
+pub fn withdraw(
+    user: Pubkey,
+    stablecoin: Pubkey,
+    user_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        deposit_and_withdraw_accounts(user, stablecoin, user_token_account, token_program),
+        instruction::Withdraw { amount },
+    )
+}
+
+pub fn set_affiliate(user: Pubkey, affiliate: Pubkey) -> Instruction {
+    build(
+        accounts::SetAffiliate {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            access: access(&user),
+            system_program: system_program::ID,
+        },
+        instruction::SetAffiliate { affiliate },
+    )
+}
+
+pub fn set_winnings_preference(
+    user: Pubkey,
+    winnings_preference: WinningsPreference,
+) -> Instruction {
+    build(
+        accounts::SetWinningsPreference {
+            user,
+            user_info: pda::user_info(&user),
+            system_program: system_program::ID,
+        },
+        instruction::SetWinningsPreference {
+            winnings_preference,
+        },
+    )
+}
+
+/// Grows a user info account created before the latest `UserInfo` fields to the current layout,
+/// with `payer` paying the extra rent.
+pub fn migrate_user_info(payer: Pubkey, user: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateUserInfo {
+            payer,
+            user,
+            user_info: pda::user_info(&user),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateUserInfo {},
+    )
+}
+
+/// Escrows `lamports` in the user's info account for the rent of their repeat bets, which keepers
+/// are paid back from when they place them. Setting another winnings preference returns them.
+pub fn escrow_repeat_bet_rent(user: Pubkey, lamports: u64) -> Instruction {
+    system_instruction::transfer(&user, &pda::user_info(&user), lamports)
+}
+
+pub fn create_session(
+    user: Pubkey,
+    delegate: Pubkey,
+    expires_at: u64,
+    max_spend: u64,
+    permissions: SessionPermissions,
+) -> Instruction {
+    build(
+        accounts::CreateSession {
+            user,
+            session: pda::session(&user, &delegate),
+            system_program: system_program::ID,
+        },
+        instruction::CreateSession {
+            delegate,
+            expires_at,
+            max_spend,
+            permissions,
+        },
+    )
+}
+
+pub fn revoke_session(user: Pubkey, delegate: Pubkey) -> Instruction {
+    build(
+        accounts::RevokeSession {
+            user,
+            session: pda::session(&user, &delegate),
+            system_program: system_program::ID,
+        },
+        instruction::RevokeSession {},
+    )
+}
+
+/// Creates an order that bets in the round after the current one, if `condition` is met by the
+/// current round.
+pub fn create_order(
+    user: Pubkey,
+    order_id: u64,
+    amount: u64,
+    is_long: bool,
+    condition: OrderCondition,
+    executor_fee: u64,
+) -> Instruction {
+    build(
+        accounts::CreateOrder {
+            user,
+            platform_config: pda::platform_config(),
+            user_info: pda::user_info(&user),
+            order: pda::order(&user, order_id),
+            system_program: system_program::ID,
+        },
+        instruction::CreateOrder {
+            order_id,
+            amount,
+            is_long,
+            condition,
+            executor_fee,
+        },
+    )
+}
+
+pub fn cancel_order(user: Pubkey, order_id: u64) -> Instruction {
+    build(
+        accounts::CancelOrder {
+            user,
+            user_info: pda::user_info(&user),
+            order: pda::order(&user, order_id),
+            system_program: system_program::ID,
+        },
+        instruction::CancelOrder {},
+    )
+}
@@ -0,0 +1,14 @@
+//! Client for the bearish-dot-fun program.
+//!
+//! Rounds are identified by their round number everywhere in this crate, which is the number
+//! emitted in `RoundStarted` and `RoundEnded` and used in the round's PDA seeds. The round that is
+//! currently running is `global_round_info.round + 1` (see [`pda::current_round`]). Instruction
+//! builders convert round numbers to the `round_index` argument expected by the claim
+//! instructions, which is the round number minus one.
+
+pub mod accounts;
+pub mod instructions;
+pub mod math;
+pub mod pda;
+
+pub use bearish_dot_fun::{constants, events, ID};
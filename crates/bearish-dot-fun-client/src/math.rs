@@ -0,0 +1,394 @@
+//! Payout calculators mirroring the program's settlement and claim instructions. These reuse the
+//! program's own `Round` and `UserInfo` methods, so they always match what happens on-chain.
+
+use anchor_lang::prelude::*;
+use bearish_dot_fun::{
+    constants, utils, Allocation, Bet, BucketBet, GlobalRoundInfo, HousePool, Round, UserInfo,
+};
+
+/// The amounts `end_round` moves out of a round's pools, without a house pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundSettlement {
+    pub jackpot_amount: u64,
+    pub platform_fee: u64,
+    /// The winners' cut of the losing side, split pro-rata between winning bets.
+    pub winners_pool: u64,
+    /// The affiliates' cut of the losing side, split equally between affiliates of winning bets.
+    pub affiliate_pool: u64,
+}
+
+/// What `claim_user_winnings` credits for a winning bet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UserPayout {
+    /// The bet amount, which is returned to the user.
+    pub amount: u64,
+    /// The user's share of the winners' cut of the losing side.
+    pub winnings: u64,
+    /// The streak jackpot the claim pays out, if any.
+    pub jackpot_amount: u64,
+}
+
+impl UserPayout {
+    pub fn total(&self) -> u64 {
+        self.amount + self.winnings + self.jackpot_amount
+    }
+}
+
+/// Returns how the pools of `round` are split once it has ended.
+pub fn round_settlement(round: &Round, allocation: &Allocation) -> RoundSettlement {
+    let (jackpot_amount, platform_fee) = round.settle(allocation);
+    let (winners_pool, affiliate_pool) = round.distributable_pools(allocation);
+
+    RoundSettlement {
+        jackpot_amount,
+        platform_fee,
+        winners_pool,
+        affiliate_pool,
+    }
+}
+
+/// Returns what claiming `bet` in round number `round_number` pays out, or `None` if the bet is
+/// not eligible for a claim or `round_number` is 0. `global_round_info` must be read after the
+/// round ended, since the streak jackpot comes out of the current jackpot pool.
+pub fn user_payout(
+    round: &Round,
+    round_number: u64,
+    bet: &Bet,
+    user_info: &UserInfo,
+    global_round_info: &GlobalRoundInfo,
+) -> Option<UserPayout> {
+    if round.validate_claimable(bet).is_err() {
+        return None;
+    }
+    let round_index = round_number.checked_sub(1)?;
+
+    let mut user_info = user_info.clone();
+    let mut jackpot_pool_amount = global_round_info.jackpot_pool_amount;
+
+    Some(UserPayout {
+        amount: bet.amount,
+        winnings: round.winnings(bet.amount, round.allocation.winners_share),
+        jackpot_amount: user_info.record_win(
+            round_index,
+            &global_round_info.jackpot_allocation,
+            &mut jackpot_pool_amount,
+        ),
+    })
+}
+
+/// Returns what the affiliate of `bet` receives from `claim_affiliate_winnings`, or `None` if
+/// there is nothing to claim.
+pub fn affiliate_payout(round: &Round, bet: &Bet) -> Option<u64> {
+    if round.ending_price == 0
+        || bet.affiliate == Pubkey::default()
+        || bet.has_affiliate_claimed_winnings
+        || round.validate_claim(bet).is_err()
+    {
+        return None;
+    }
+
+    Some(round.affiliate_winnings(round.allocation.affiliate_share)).filter(|amount| *amount > 0)
+}
+
+/// Returns what `claim_fixed_odds_winnings` credits for a winning fixed-odds bet.
+pub fn fixed_odds_payout(round: &Round, fixed_odds_bet: &Bet) -> Option<u64> {
+    if round.ending_price == 0
+        || fixed_odds_bet.has_claimed_winnings
+        || round.validate_claim(fixed_odds_bet).is_err()
+    {
+        return None;
+    }
+
+    Some(
+        u64::try_from(utils::math::mul_div_down(
+            &(fixed_odds_bet.amount as u128),
+            &(fixed_odds_bet.multiplier as u128),
+            &(constants::general::BPS as u128),
+        ))
+        .unwrap(),
+    )
+}
+
+/// Returns the multiplier (in bips) a fixed-odds bet would lock in if placed now.
+pub fn fixed_odds_quote(
+    house_pool: &HousePool,
+    round: &Round,
+    amount: u64,
+    is_long: bool,
+) -> Option<u64> {
+    house_pool
+        .fixed_odds_multiplier(round, amount, is_long)
+        .ok()
+}
+
+/// Returns what `claim_bucket_winnings` credits for a bet on the winning bucket, including the
+/// bet amount.
+pub fn bucket_payout(round: &Round, bucket_bet: &BucketBet) -> Option<u64> {
+    if round.ending_price == 0
+        || bucket_bet.has_claimed_winnings
+        || round.validate_bucket_claim(bucket_bet).is_err()
+    {
+        return None;
+    }
+
+    Some(
+        bucket_bet.amount
+            + round.bucket_winnings(
+                bucket_bet.amount,
+                round.allocation.winners_share + round.allocation.affiliate_share,
+            ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bearish_dot_fun::{JackPotAllocation, WinningsPreference};
+
+    const STARTING_PRICE: u64 = 10_000;
+
+    fn allocation() -> Allocation {
+        Allocation {
+            winners_share: 4_500,
+            affiliate_share: 500,
+            jackpot_share: 4_000,
+            platform_share: 1_000,
+        }
+    }
+
+    fn global_round_info(jackpot_pool_amount: u64) -> GlobalRoundInfo {
+        GlobalRoundInfo {
+            round: 1,
+            duration: 60,
+            allocation: allocation(),
+            jackpot_allocation: JackPotAllocation {
+                streak_5: 1_000,
+                streak_6: 1_500,
+                streak_7: 2_000,
+                streak_8: 2_500,
+                streak_9: 3_000,
+                streak_10: 10_000,
+            },
+            min_bet_amount: 0,
+            price_account: Pubkey::default(),
+            staleness_threshold: 30,
+            jackpot_pool_amount,
+            accumulated_platform_fees: 0,
+            cancellation_window: 0,
+            cancellation_fee: 0,
+            max_bet_amount: 0,
+            max_user_bet_amount_per_round: 0,
+            max_round_imbalance: 0,
+            max_one_sided_amount: 0,
+        }
+    }
+
+    /// An ended round with the given pools, one position and affiliate per bet.
+    fn round(long: &[u64], short: &[u64], ending_price: u64) -> Round {
+        Round {
+            start_time: 1,
+            starting_price: STARTING_PRICE,
+            ending_price,
+            strike_price: STARTING_PRICE,
+            long_positions: long.len() as u64,
+            short_positions: short.len() as u64,
+            affiliates_for_long_positions: long.len() as u64,
+            affiliates_for_short_positions: short.len() as u64,
+            total_bet_amount_long: long.iter().sum(),
+            total_bet_amount_short: short.iter().sum(),
+            house_bet_amount: 0,
+            house_is_long: false,
+            fixed_odds_payout_long: 0,
+            fixed_odds_payout_short: 0,
+            bucket_boundaries: Vec::new(),
+            bucket_totals: [0; constants::general::MAX_BUCKETS],
+            bucket_positions: [0; constants::general::MAX_BUCKETS],
+            winning_bucket: 0,
+            allocation: allocation(),
+            bump: 0,
+        }
+    }
+
+    fn bet(amount: u64, is_long: bool) -> Bet {
+        Bet {
+            amount,
+            is_long,
+            affiliate: Pubkey::new_unique(),
+            has_claimed_winnings: false,
+            has_affiliate_claimed_winnings: false,
+            multiplier: 0,
+            bump: 0,
+        }
+    }
+
+    fn user_info(last_won_round: u64, times_won: u64) -> UserInfo {
+        UserInfo {
+            user: Pubkey::new_unique(),
+            amount: 0,
+            affiliate: Pubkey::default(),
+            last_won_round,
+            times_won,
+            winnings_preference: WinningsPreference::KeepInBalance,
+            bump: 0,
+        }
+    }
+
+    // The numbers below are the ones the program's `longs_win_and_claim` and
+    // `ties_go_to_the_jackpot_and_platform` tests observe on-chain.
+
+    #[test]
+    fn settlement_splits_the_losing_side() {
+        let round = round(&[300, 100], &[1_000], STARTING_PRICE + 1);
+
+        assert_eq!(
+            round_settlement(&round, &allocation()),
+            RoundSettlement {
+                jackpot_amount: 400,
+                platform_fee: 100,
+                winners_pool: 450,
+                affiliate_pool: 50,
+            }
+        );
+    }
+
+    #[test]
+    fn ties_settle_into_the_jackpot_and_platform() {
+        let round = round(&[1_000], &[1_000], STARTING_PRICE);
+        let settlement = round_settlement(&round, &allocation());
+
+        assert_eq!(settlement.jackpot_amount, 1_800);
+        assert_eq!(settlement.platform_fee, 200);
+        assert_eq!(
+            user_payout(
+                &round,
+                1,
+                &bet(1_000, true),
+                &user_info(0, 0),
+                &global_round_info(0)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn winners_are_paid_pro_rata() {
+        let round = round(&[300, 100], &[1_000], STARTING_PRICE + 1);
+        let global_round_info = global_round_info(400);
+        let user_info = user_info(0, 0);
+
+        let alice = user_payout(&round, 1, &bet(300, true), &user_info, &global_round_info);
+        let bob = user_payout(&round, 1, &bet(100, true), &user_info, &global_round_info);
+        assert_eq!(alice.map(|payout| payout.total()), Some(300 + 337));
+        assert_eq!(bob.map(|payout| payout.total()), Some(100 + 112));
+
+        let carol = user_payout(
+            &round,
+            1,
+            &bet(1_000, false),
+            &user_info,
+            &global_round_info,
+        );
+        assert_eq!(carol, None);
+
+        let claimed_bet = Bet {
+            has_claimed_winnings: true,
+            ..bet(300, true)
+        };
+        assert_eq!(
+            user_payout(&round, 1, &claimed_bet, &user_info, &global_round_info),
+            None
+        );
+        // Rounds are numbered from 1.
+        assert_eq!(
+            user_payout(&round, 0, &bet(300, true), &user_info, &global_round_info),
+            None
+        );
+    }
+
+    #[test]
+    fn a_fifth_win_in_a_row_pays_the_streak_jackpot() {
+        let round = round(&[1_000], &[1_000], STARTING_PRICE + 1);
+
+        let payout = user_payout(
+            &round,
+            5,
+            &bet(1_000, true),
+            &user_info(4, 4),
+            &global_round_info(1_000),
+        );
+        assert_eq!(
+            payout,
+            Some(UserPayout {
+                amount: 1_000,
+                winnings: 450,
+                jackpot_amount: 100,
+            })
+        );
+    }
+
+    #[test]
+    fn affiliates_split_their_cut_equally() {
+        let round = round(&[300, 100], &[1_000], STARTING_PRICE + 1);
+
+        assert_eq!(affiliate_payout(&round, &bet(300, true)), Some(25));
+        assert_eq!(affiliate_payout(&round, &bet(1_000, false)), None);
+
+        let bet_without_affiliate = Bet {
+            affiliate: Pubkey::default(),
+            ..bet(300, true)
+        };
+        assert_eq!(affiliate_payout(&round, &bet_without_affiliate), None);
+    }
+
+    #[test]
+    fn fixed_odds_quotes_shrink_on_the_heavy_side() {
+        let mut house_pool = HousePool {
+            lp_mint: Pubkey::default(),
+            total_liquidity: 1_000_000,
+            total_shares: 1_000_000,
+            max_exposure: 10_000,
+            platform_fee_share: 0,
+            fixed_odds_base_multiplier: 20_000,
+            fixed_odds_skew: 5_000,
+            reserved_liability: 0,
+            bump: 0,
+            lp_mint_bump: 0,
+        };
+        let mut round = round(&[], &[], 0);
+
+        // The first bet makes its side as heavy as it gets.
+        assert_eq!(
+            fixed_odds_quote(&house_pool, &round, 100, true),
+            Some(10_000)
+        );
+        round.fixed_odds_payout_short = 200;
+        assert_eq!(
+            fixed_odds_quote(&house_pool, &round, 100, true),
+            Some(20_000)
+        );
+
+        house_pool.fixed_odds_base_multiplier = 0;
+        assert_eq!(fixed_odds_quote(&house_pool, &round, 100, true), None);
+    }
+
+    #[test]
+    fn fixed_odds_bets_pay_their_locked_multiplier() {
+        let round = round(&[], &[], STARTING_PRICE + 1);
+        let fixed_odds_bet = Bet {
+            multiplier: 18_500,
+            ..bet(1_000, true)
+        };
+
+        assert_eq!(fixed_odds_payout(&round, &fixed_odds_bet), Some(1_850));
+        assert_eq!(
+            fixed_odds_payout(
+                &round,
+                &Bet {
+                    is_long: false,
+                    ..fixed_odds_bet
+                }
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{constants::seeds, GlobalRoundInfo, ID};
+
+/// Returns the number of the round that is currently running, or about to be started.
+pub fn current_round(global_round_info: &GlobalRoundInfo) -> u64 {
+    global_round_info.round + 1
+}
+
+pub fn platform_config() -> Pubkey {
+    Pubkey::find_program_address(&[seeds::PLATFORM_CONFIG], &ID).0
+}
+
+pub fn platform_vault() -> Pubkey {
+    Pubkey::find_program_address(&[seeds::PLATFORM_VAULT], &ID).0
+}
+
+pub fn user_info(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::USER, user.as_ref()], &ID).0
+}
+
+pub fn round(round: u64) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::ROUND, round.to_be_bytes().as_ref()], &ID).0
+}
+
+pub fn user_bet(user: &Pubkey, round: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[seeds::USER_BET, user.as_ref(), round.to_be_bytes().as_ref()],
+        &ID,
+    )
+    .0
+}
+
+pub fn fixed_odds_bet(user: &Pubkey, round: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            seeds::FIXED_ODDS_BET,
+            user.as_ref(),
+            round.to_be_bytes().as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn bucket_bet(user: &Pubkey, round: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            seeds::BUCKET_BET,
+            user.as_ref(),
+            round.to_be_bytes().as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn session(user: &Pubkey, delegate: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::SESSION, user.as_ref(), delegate.as_ref()], &ID).0
+}
+
+pub fn order(user: &Pubkey, order_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[seeds::ORDER, user.as_ref(), order_id.to_be_bytes().as_ref()],
+        &ID,
+    )
+    .0
+}
+
+pub fn attestation(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::ATTESTATION, user.as_ref()], &ID).0
+}
+
+pub fn blocked_user(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::BLOCKED_USER, user.as_ref()], &ID).0
+}
+
+pub fn house_pool() -> Pubkey {
+    Pubkey::find_program_address(&[seeds::HOUSE_POOL], &ID).0
+}
+
+pub fn lp_mint() -> Pubkey {
+    Pubkey::find_program_address(&[seeds::LP_MINT], &ID).0
+}
+
+pub fn mint_market(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::MINT_MARKET, mint.as_ref()], &ID).0
+}
+
+/// The vault of a whitelisted mint. For the native SOL market, this is a system account holding
+/// lamports.
+pub fn mint_vault(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::PLATFORM_VAULT, mint.as_ref()], &ID).0
+}
+
+pub fn native_vault() -> Pubkey {
+    mint_vault(&spl_token::native_mint::ID)
+}
+
+pub fn mint_user_info(user: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::USER, user.as_ref(), mint.as_ref()], &ID).0
+}
+
+pub fn mint_round(round: u64, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[seeds::ROUND, round.to_be_bytes().as_ref(), mint.as_ref()],
+        &ID,
+    )
+    .0
+}
+
+pub fn mint_user_bet(user: &Pubkey, round: u64, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            seeds::USER_BET,
+            user.as_ref(),
+            round.to_be_bytes().as_ref(),
+            mint.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
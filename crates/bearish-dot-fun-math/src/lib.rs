@@ -0,0 +1,307 @@
+//! Settlement and payout math of the bearish-dot-fun program. It has no Solana dependencies, so
+//! that the program and off-chain tools like the simulator run exactly the same arithmetic.
+//!
+//! Amounts are in base units of the token bets are placed in, and shares are in bips.
+
+pub const BPS: u16 = 10_000;
+
+/// How the losing side of a round is split.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocation {
+    pub winners_share: u16,
+    pub affiliate_share: u16,
+    pub jackpot_share: u16,
+    pub platform_share: u16,
+}
+
+/// The shares of the jackpot pool paid out for win streaks of 5 to 10 rounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JackPotAllocation {
+    pub streak_5: u16,
+    pub streak_6: u16,
+    pub streak_7: u16,
+    pub streak_8: u16,
+    pub streak_9: u16,
+    pub streak_10: u16,
+}
+
+impl JackPotAllocation {
+    /// Returns the jackpot share paid out on the `times_won`-th win in a row.
+    pub fn streak_share(&self, times_won: u64) -> u16 {
+        match times_won {
+            10 => self.streak_10,
+            9 => self.streak_9,
+            8 => self.streak_8,
+            7 => self.streak_7,
+            6 => self.streak_6,
+            5 => self.streak_5,
+            _ => 0,
+        }
+    }
+}
+
+pub fn is_greater_than(x: &u64, y: &u64) -> bool {
+    *x > *y
+}
+
+pub fn mul_div_down(x: &u128, y: &u128, d: &u128) -> u128 {
+    x.checked_mul(*y).unwrap().checked_div(*d).unwrap()
+}
+
+/// Returns `share` bips of `amount`, rounded down.
+pub fn mul_bps(amount: u64, share: u16) -> u64 {
+    u64::try_from(mul_div_down(
+        &(amount as u128),
+        &(share as u128),
+        &(BPS as u128),
+    ))
+    .unwrap()
+}
+
+/// Returns the price rounds starting at `starting_price` are settled against, moved by
+/// `strike_offset` bips. Saturates at 0 and `u64::MAX` rather than failing.
+pub fn strike_price(starting_price: u64, strike_offset: i32) -> u64 {
+    let bps = BPS as i128;
+    let strike_price = starting_price as i128 * (bps + strike_offset as i128) / bps;
+
+    strike_price.clamp(0, u64::MAX as i128) as u64
+}
+
+/// Returns the amounts of a round's pools that go to the jackpot and to the platform once it has
+/// ended. On a tie, everything except the platform share of both sides rolls over into the
+/// jackpot. Otherwise the jackpot and platform shares are taken from the losing side, and the
+/// winners and affiliate shares roll over into the jackpot too if nobody bet on the winning side.
+pub fn settle(
+    winning_side_amount: u64,
+    losing_side_amount: u64,
+    is_tie: bool,
+    allocation: &Allocation,
+) -> (u64, u64) {
+    if is_tie {
+        let total_bet_amount = winning_side_amount + losing_side_amount;
+
+        return (
+            mul_bps(
+                total_bet_amount,
+                allocation.jackpot_share + allocation.winners_share + allocation.affiliate_share,
+            ),
+            mul_bps(total_bet_amount, allocation.platform_share),
+        );
+    }
+
+    let mut jackpot_amount = mul_bps(losing_side_amount, allocation.jackpot_share);
+    let platform_fee = mul_bps(losing_side_amount, allocation.platform_share);
+    if winning_side_amount == 0 {
+        jackpot_amount += mul_bps(
+            losing_side_amount,
+            allocation.winners_share + allocation.affiliate_share,
+        );
+    }
+
+    (jackpot_amount, platform_fee)
+}
+
+/// Returns the winners and affiliate shares of the losing side that winning bets and their
+/// affiliates claim from.
+pub fn distributable_pools(losing_side_amount: u64, allocation: &Allocation) -> (u64, u64) {
+    (
+        mul_bps(losing_side_amount, allocation.winners_share),
+        mul_bps(losing_side_amount, allocation.affiliate_share),
+    )
+}
+
+/// Returns the pro-rata share of the winners' cut of the losing side for a winning position of
+/// `amount`. The amount itself is not included.
+pub fn winnings(
+    amount: u64,
+    winning_side_amount: u64,
+    losing_side_amount: u64,
+    winners_share: u16,
+) -> u64 {
+    let pool_amount_to_claim_winnings_from = mul_bps(losing_side_amount, winners_share);
+
+    u64::try_from(mul_div_down(
+        &(amount as u128),
+        &(pool_amount_to_claim_winnings_from as u128),
+        &(winning_side_amount as u128),
+    ))
+    .unwrap()
+}
+
+/// Returns an equal share of the affiliates' cut of the losing side, split between the
+/// `affiliates` of winning positions.
+pub fn affiliate_winnings(affiliates: u64, losing_side_amount: u64, affiliate_share: u16) -> u64 {
+    mul_bps(losing_side_amount, affiliate_share)
+        .checked_div(affiliates)
+        .unwrap()
+}
+
+/// A user's run of consecutive wins, as tracked on their `UserInfo`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Streak {
+    /// One past the index of the last round the user claimed a win in.
+    pub last_won_round: u64,
+    pub times_won: u64,
+}
+
+impl Streak {
+    /// Records a win in round `round_index` and returns the streak jackpot the user is entitled
+    /// to, if any, deducting it from `jackpot_pool_amount`. The streak resets after a 10 win
+    /// streak or whenever the user hasn't won the previous round.
+    pub fn record_win(
+        &mut self,
+        round_index: u64,
+        jackpot_allocation: &JackPotAllocation,
+        jackpot_pool_amount: &mut u64,
+    ) -> u64 {
+        let mut jackpot_amount = 0;
+
+        self.times_won += 1;
+        let streak_winnings_share = jackpot_allocation.streak_share(self.times_won);
+        if streak_winnings_share > 0
+            && round_index == self.last_won_round
+            && *jackpot_pool_amount > 0
+        {
+            jackpot_amount = mul_bps(*jackpot_pool_amount, streak_winnings_share);
+
+            *jackpot_pool_amount -= jackpot_amount;
+
+            if self.times_won == 10 {
+                self.times_won = 0;
+            }
+        }
+
+        if round_index != self.last_won_round {
+            self.times_won = 1;
+        }
+        self.last_won_round = round_index + 1;
+
+        jackpot_amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALLOCATION: Allocation = Allocation {
+        winners_share: 4_500,
+        affiliate_share: 500,
+        jackpot_share: 4_000,
+        platform_share: 1_000,
+    };
+
+    const JACKPOT_ALLOCATION: JackPotAllocation = JackPotAllocation {
+        streak_5: 1_000,
+        streak_6: 1_500,
+        streak_7: 2_000,
+        streak_8: 2_500,
+        streak_9: 3_000,
+        streak_10: 10_000,
+    };
+
+    #[test]
+    fn settlement_takes_the_jackpot_and_platform_shares_of_the_losing_side() {
+        assert_eq!(settle(400, 1_000, false, &ALLOCATION), (400, 100));
+        assert_eq!(distributable_pools(1_000, &ALLOCATION), (450, 50));
+    }
+
+    #[test]
+    fn ties_roll_everything_but_the_platform_share_into_the_jackpot() {
+        assert_eq!(settle(1_000, 1_000, true, &ALLOCATION), (1_800, 200));
+        assert_eq!(settle(0, 1_000, true, &ALLOCATION), (900, 100));
+    }
+
+    #[test]
+    fn one_sided_rounds_roll_the_winners_and_affiliate_shares_into_the_jackpot() {
+        assert_eq!(settle(0, 1_000, false, &ALLOCATION), (900, 100));
+        assert_eq!(settle(1_000, 0, false, &ALLOCATION), (0, 0));
+        assert_eq!(settle(0, 0, false, &ALLOCATION), (0, 0));
+    }
+
+    #[test]
+    fn settlement_does_not_overflow_on_the_largest_pools() {
+        let (jackpot_amount, platform_fee) = settle(u64::MAX / 2, u64::MAX / 2, true, &ALLOCATION);
+
+        assert_eq!(jackpot_amount, mul_bps(u64::MAX - 1, 9_000));
+        assert_eq!(platform_fee, mul_bps(u64::MAX - 1, 1_000));
+        assert_eq!(mul_bps(u64::MAX, BPS), u64::MAX);
+    }
+
+    #[test]
+    fn winnings_are_pro_rata_and_rounded_down() {
+        assert_eq!(winnings(300, 400, 1_000, 4_500), 337);
+        assert_eq!(winnings(100, 400, 1_000, 4_500), 112);
+        assert_eq!(winnings(400, 400, 1_000, 4_500), 450);
+        assert_eq!(winnings(1, 3, 1, BPS), 0);
+        assert_eq!(winnings(400, 400, 0, 4_500), 0);
+        assert_eq!(winnings(u64::MAX, u64::MAX, u64::MAX, BPS), u64::MAX);
+    }
+
+    #[test]
+    fn affiliates_split_their_share_equally() {
+        assert_eq!(affiliate_winnings(1, 1_000, 500), 50);
+        assert_eq!(affiliate_winnings(3, 1_000, 500), 16);
+        assert_eq!(affiliate_winnings(1, 0, 500), 0);
+    }
+
+    #[test]
+    fn streaks_pay_out_from_the_fifth_win_in_a_row() {
+        let mut streak = Streak::default();
+        let mut jackpot_pool_amount = 10_000;
+
+        for round_index in 0..4 {
+            assert_eq!(
+                streak.record_win(round_index, &JACKPOT_ALLOCATION, &mut jackpot_pool_amount),
+                0
+            );
+        }
+        assert_eq!(
+            streak.record_win(4, &JACKPOT_ALLOCATION, &mut jackpot_pool_amount),
+            1_000
+        );
+        assert_eq!(jackpot_pool_amount, 9_000);
+        assert_eq!(streak.times_won, 5);
+
+        // Skipping a round starts the streak over.
+        assert_eq!(
+            streak.record_win(6, &JACKPOT_ALLOCATION, &mut jackpot_pool_amount),
+            0
+        );
+        assert_eq!(streak.times_won, 1);
+        assert_eq!(streak.last_won_round, 7);
+    }
+
+    #[test]
+    fn a_ten_win_streak_takes_the_whole_jackpot_and_starts_over() {
+        let mut streak = Streak {
+            last_won_round: 9,
+            times_won: 9,
+        };
+        let mut jackpot_pool_amount = 10_000;
+
+        assert_eq!(
+            streak.record_win(9, &JACKPOT_ALLOCATION, &mut jackpot_pool_amount),
+            10_000
+        );
+        assert_eq!(jackpot_pool_amount, 0);
+        assert_eq!(streak.times_won, 0);
+    }
+
+    #[test]
+    fn strike_prices_are_offset_from_the_starting_price() {
+        assert_eq!(strike_price(10_000, 0), 10_000);
+        assert_eq!(strike_price(10_000, 200), 10_200);
+        assert_eq!(strike_price(10_000, -200), 9_800);
+        assert_eq!(strike_price(10_000, -9_999), 1);
+        assert_eq!(strike_price(10_000, BPS as i32), 20_000);
+    }
+
+    #[test]
+    fn strike_prices_saturate_instead_of_overflowing() {
+        assert_eq!(strike_price(u64::MAX, 1), u64::MAX);
+        assert_eq!(strike_price(u64::MAX, i32::MAX), u64::MAX);
+        assert_eq!(strike_price(u64::MAX, -(BPS as i32)), 0);
+        assert_eq!(strike_price(1, i32::MIN), 0);
+    }
+}
@@ -0,0 +1,802 @@
+use anchor_client::anchor_lang::{prelude::Pubkey, Event};
+use bearish_dot_fun::{events, RoundOutcome};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS rounds (
+    round INTEGER PRIMARY KEY,
+    starting_price INTEGER,
+    strike_price INTEGER,
+    start_slot INTEGER,
+    ending_price INTEGER,
+    end_slot INTEGER,
+    outcome TEXT,
+    total_bet_amount_long INTEGER,
+    total_bet_amount_short INTEGER,
+    long_positions INTEGER,
+    short_positions INTEGER,
+    jackpot_amount INTEGER,
+    platform_fee INTEGER,
+    winners_pool INTEGER,
+    affiliate_pool INTEGER,
+    jackpot_pool_amount INTEGER,
+    winning_bucket INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS bets (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    mint TEXT,
+    kind TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    is_long INTEGER,
+    bucket INTEGER,
+    multiplier INTEGER,
+    affiliate TEXT,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS bet_cancellations (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    is_long INTEGER NOT NULL,
+    amount INTEGER NOT NULL,
+    fee INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS claims (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    mint TEXT,
+    kind TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS affiliate_earnings (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    affiliate TEXT NOT NULL,
+    mint TEXT,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS deposits (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    stablecoin TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS platform_fees (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    owner TEXT NOT NULL,
+    mint TEXT,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS config_changes (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    setting TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+";
+
+/// Where an event was emitted. Together with the signature, the index of the event within its
+/// transaction makes up the primary key of every event table.
+struct EventKey<'a> {
+    signature: &'a str,
+    event_index: usize,
+    slot: u64,
+}
+
+/// Bets without an affiliate carry the default pubkey, which is stored as NULL.
+fn affiliate(affiliate: Pubkey) -> Option<String> {
+    (affiliate != Pubkey::default()).then(|| affiliate.to_string())
+}
+
+fn decode<E: Event>(data: &[u8]) -> Option<E> {
+    let mut data = data.strip_prefix(E::DISCRIMINATOR)?;
+
+    E::deserialize(&mut data).ok()
+}
+
+/// Describes the setting changed by an admin event, and its new value.
+fn config_change(data: &[u8]) -> Option<(&'static str, String)> {
+    if let Some(event) = decode::<events::Initialized>(data) {
+        return Some((
+            "initialized",
+            format!(
+                "owner {}, stablecoin {}, price account {}",
+                event.owner, event.stablecoin, event.global_round_info.price_account
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::OwnershipTransferred>(data) {
+        return Some(("owner", event.new_owner.to_string()));
+    }
+    if let Some(event) = decode::<events::DurationSet>(data) {
+        return Some(("duration", event.duration.to_string()));
+    }
+    if let Some(event) = decode::<events::AllocationSet>(data) {
+        let allocation = event.allocation;
+        return Some((
+            "allocation",
+            format!(
+                "winners {}, affiliate {}, jackpot {}, platform {}",
+                allocation.winners_share,
+                allocation.affiliate_share,
+                allocation.jackpot_share,
+                allocation.platform_share
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::JackPotAllocationSet>(data) {
+        let jackpot_allocation = event.jackpot_allocation;
+        return Some((
+            "jackpot_allocation",
+            format!(
+                "{}, {}, {}, {}, {}, {}",
+                jackpot_allocation.streak_5,
+                jackpot_allocation.streak_6,
+                jackpot_allocation.streak_7,
+                jackpot_allocation.streak_8,
+                jackpot_allocation.streak_9,
+                jackpot_allocation.streak_10
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::MinBetAmountSet>(data) {
+        return Some(("min_bet_amount", event.min_bet_amount.to_string()));
+    }
+    if let Some(event) = decode::<events::PriceAccountSet>(data) {
+        return Some(("price_account", event.price_account.to_string()));
+    }
+    if let Some(event) = decode::<events::StalenessThresholdSet>(data) {
+        return Some(("staleness_threshold", event.staleness_threshold.to_string()));
+    }
+    if let Some(event) = decode::<events::CancellationWindowSet>(data) {
+        return Some(("cancellation_window", event.cancellation_window.to_string()));
+    }
+    if let Some(event) = decode::<events::CancellationFeeSet>(data) {
+        return Some(("cancellation_fee", event.cancellation_fee.to_string()));
+    }
+    if let Some(event) = decode::<events::MaxBetAmountSet>(data) {
+        return Some(("max_bet_amount", event.max_bet_amount.to_string()));
+    }
+    if let Some(event) = decode::<events::MaxUserBetAmountPerRoundSet>(data) {
+        return Some((
+            "max_user_bet_amount_per_round",
+            event.max_user_bet_amount_per_round.to_string(),
+        ));
+    }
+    if let Some(event) = decode::<events::MaxRoundImbalanceSet>(data) {
+        return Some((
+            "max_round_imbalance",
+            format!(
+                "{}, max one-sided amount {}",
+                event.max_round_imbalance, event.max_one_sided_amount
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::BucketBoundariesSet>(data) {
+        return Some((
+            "bucket_boundaries",
+            format!("{:?}", event.bucket_boundaries),
+        ));
+    }
+    if let Some(event) = decode::<events::StrikeOffsetSet>(data) {
+        return Some(("strike_offset", event.strike_offset.to_string()));
+    }
+    if let Some(event) = decode::<events::StrikeAuthoritySet>(data) {
+        return Some(("strike_authority", event.strike_authority.to_string()));
+    }
+    if let Some(event) = decode::<events::StrikeOverridePendingSet>(data) {
+        return Some(("strike_override_pending", event.is_pending.to_string()));
+    }
+    if let Some(event) = decode::<events::GatingSet>(data) {
+        return Some((
+            "gating",
+            format!("gated {}, gatekeeper {}", event.is_gated, event.gatekeeper),
+        ));
+    }
+    if let Some(event) = decode::<events::HousePoolInitialized>(data) {
+        return Some((
+            "house_pool",
+            format!(
+                "lp mint {}, max exposure {}, platform fee share {}",
+                event.lp_mint, event.max_exposure, event.platform_fee_share
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::HousePoolConfigSet>(data) {
+        return Some((
+            "house_pool_config",
+            format!(
+                "max exposure {}, platform fee share {}",
+                event.max_exposure, event.platform_fee_share
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::FixedOddsConfigSet>(data) {
+        return Some((
+            "fixed_odds_config",
+            format!(
+                "base multiplier {}, skew {}",
+                event.base_multiplier, event.skew
+            ),
+        ));
+    }
+    if let Some(event) = decode::<events::MintWhitelisted>(data) {
+        return Some(("mint_whitelisted", event.mint.to_string()));
+    }
+    if let Some(event) = decode::<events::MintMarketEnabledSet>(data) {
+        return Some((
+            "mint_market_enabled",
+            format!("{} {}", event.mint, event.is_enabled),
+        ));
+    }
+    if let Some(event) = decode::<events::MintMarketBetLimitsSet>(data) {
+        return Some((
+            "mint_market_bet_limits",
+            format!(
+                "{} min {}, max {}, max per user and round {}, max one-sided {}",
+                event.mint,
+                event.min_bet_amount,
+                event.max_bet_amount,
+                event.max_user_bet_amount_per_round,
+                event.max_one_sided_amount
+            ),
+        ));
+    }
+
+    None
+}
+
+/// A row of the bets table. Columns that don't apply to a kind of bet are left empty.
+#[derive(Default)]
+struct BetRow {
+    round: u64,
+    user: String,
+    mint: Option<String>,
+    kind: &'static str,
+    amount: u64,
+    is_long: Option<bool>,
+    bucket: Option<u8>,
+    multiplier: Option<u64>,
+    affiliate: Option<String>,
+}
+
+fn insert_bet(transaction: &Transaction, key: &EventKey, bet: BetRow) -> rusqlite::Result<usize> {
+    transaction.execute(
+        "INSERT OR IGNORE INTO bets
+            (signature, event_index, slot, round, user, mint, kind, amount, is_long, bucket,
+            multiplier, affiliate)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            key.signature,
+            key.event_index,
+            key.slot,
+            bet.round,
+            bet.user,
+            bet.mint,
+            bet.kind,
+            bet.amount,
+            bet.is_long,
+            bet.bucket,
+            bet.multiplier,
+            bet.affiliate
+        ],
+    )
+}
+
+fn insert_claim(
+    transaction: &Transaction,
+    key: &EventKey,
+    round_index: u64,
+    user: String,
+    mint: Option<String>,
+    kind: &str,
+    amount: u64,
+) -> rusqlite::Result<usize> {
+    transaction.execute(
+        "INSERT OR IGNORE INTO claims (signature, event_index, slot, round, user, mint, kind, amount)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            key.signature,
+            key.event_index,
+            key.slot,
+            round_index + 1,
+            user,
+            mint,
+            kind,
+            amount
+        ],
+    )
+}
+
+fn insert_affiliate_earning(
+    transaction: &Transaction,
+    key: &EventKey,
+    round_index: u64,
+    affiliate: String,
+    mint: Option<String>,
+    amount: u64,
+) -> rusqlite::Result<usize> {
+    transaction.execute(
+        "INSERT OR IGNORE INTO affiliate_earnings
+            (signature, event_index, slot, round, affiliate, mint, amount)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            key.signature,
+            key.event_index,
+            key.slot,
+            round_index + 1,
+            affiliate,
+            mint,
+            amount
+        ],
+    )
+}
+
+fn insert_deposit(
+    transaction: &Transaction,
+    key: &EventKey,
+    user: String,
+    stablecoin: String,
+    kind: &str,
+    amount: u64,
+) -> rusqlite::Result<usize> {
+    transaction.execute(
+        "INSERT OR IGNORE INTO deposits (signature, event_index, slot, user, stablecoin, kind, amount)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            key.signature,
+            key.event_index,
+            key.slot,
+            user,
+            stablecoin,
+            kind,
+            amount
+        ],
+    )
+}
+
+fn insert_platform_fee(
+    transaction: &Transaction,
+    key: &EventKey,
+    owner: String,
+    mint: Option<String>,
+    amount: u64,
+) -> rusqlite::Result<usize> {
+    transaction.execute(
+        "INSERT OR IGNORE INTO platform_fees (signature, event_index, slot, owner, mint, amount)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            key.signature,
+            key.event_index,
+            key.slot,
+            owner,
+            mint,
+            amount
+        ],
+    )
+}
+
+/// Writes a single event to the table it belongs to. Events that have no table, such as session
+/// and order bookkeeping, are skipped.
+fn insert_event(transaction: &Transaction, key: &EventKey, data: &[u8]) -> rusqlite::Result<()> {
+    if let Some(event) = decode::<events::RoundStarted>(data) {
+        transaction.execute(
+            "INSERT INTO rounds (round, starting_price, strike_price, start_slot)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT (round) DO UPDATE SET
+                starting_price = excluded.starting_price,
+                strike_price = excluded.strike_price,
+                start_slot = excluded.start_slot",
+            params![
+                event.round,
+                event.starting_price,
+                event.strike_price,
+                key.slot
+            ],
+        )?;
+    } else if let Some(event) = decode::<events::RoundEnded>(data) {
+        let outcome = match event.outcome {
+            RoundOutcome::LongsWon => "longs_won",
+            RoundOutcome::ShortsWon => "shorts_won",
+            RoundOutcome::Tie => "tie",
+            RoundOutcome::OneSided => "one_sided",
+        };
+
+        transaction.execute(
+            "INSERT INTO rounds
+                (round, starting_price, strike_price, ending_price, end_slot, outcome,
+                total_bet_amount_long, total_bet_amount_short, long_positions, short_positions,
+                jackpot_amount, platform_fee, winners_pool, affiliate_pool, jackpot_pool_amount)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            ON CONFLICT (round) DO UPDATE SET
+                starting_price = excluded.starting_price,
+                strike_price = excluded.strike_price,
+                ending_price = excluded.ending_price,
+                end_slot = excluded.end_slot,
+                outcome = excluded.outcome,
+                total_bet_amount_long = excluded.total_bet_amount_long,
+                total_bet_amount_short = excluded.total_bet_amount_short,
+                long_positions = excluded.long_positions,
+                short_positions = excluded.short_positions,
+                jackpot_amount = excluded.jackpot_amount,
+                platform_fee = excluded.platform_fee,
+                winners_pool = excluded.winners_pool,
+                affiliate_pool = excluded.affiliate_pool,
+                jackpot_pool_amount = excluded.jackpot_pool_amount",
+            params![
+                event.round,
+                event.starting_price,
+                event.strike_price,
+                event.ending_price,
+                key.slot,
+                outcome,
+                event.total_bet_amount_long,
+                event.total_bet_amount_short,
+                event.long_positions,
+                event.short_positions,
+                event.jackpot_amount,
+                event.platform_fee,
+                event.winners_pool,
+                event.affiliate_pool,
+                event.jackpot_pool_amount
+            ],
+        )?;
+    } else if let Some(event) = decode::<events::BucketsSettled>(data) {
+        transaction.execute(
+            "INSERT INTO rounds (round, winning_bucket) VALUES (?1, ?2)
+            ON CONFLICT (round) DO UPDATE SET winning_bucket = excluded.winning_bucket",
+            params![event.round, event.winning_bucket],
+        )?;
+    } else if let Some(event) = decode::<events::BetPlaced>(data) {
+        insert_bet(
+            transaction,
+            key,
+            BetRow {
+                round: event.round,
+                user: event.user.to_string(),
+                kind: "parimutuel",
+                amount: event.amount,
+                is_long: Some(event.is_long),
+                affiliate: affiliate(event.affiliate),
+                ..Default::default()
+            },
+        )?;
+    } else if let Some(event) = decode::<events::MintBetPlaced>(data) {
+        insert_bet(
+            transaction,
+            key,
+            BetRow {
+                round: event.round,
+                user: event.user.to_string(),
+                mint: Some(event.mint.to_string()),
+                kind: "parimutuel",
+                amount: event.amount,
+                is_long: Some(event.is_long),
+                affiliate: affiliate(event.affiliate),
+                ..Default::default()
+            },
+        )?;
+    } else if let Some(event) = decode::<events::BucketBetPlaced>(data) {
+        insert_bet(
+            transaction,
+            key,
+            BetRow {
+                round: event.round,
+                user: event.user.to_string(),
+                kind: "bucket",
+                amount: event.amount,
+                bucket: Some(event.bucket),
+                ..Default::default()
+            },
+        )?;
+    } else if let Some(event) = decode::<events::FixedOddsBetPlaced>(data) {
+        insert_bet(
+            transaction,
+            key,
+            BetRow {
+                round: event.round,
+                user: event.user.to_string(),
+                kind: "fixed_odds",
+                amount: event.amount,
+                is_long: Some(event.is_long),
+                multiplier: Some(event.multiplier),
+                ..Default::default()
+            },
+        )?;
+    } else if let Some(event) = decode::<events::BetCancelled>(data) {
+        transaction.execute(
+            "INSERT OR IGNORE INTO bet_cancellations
+                (signature, event_index, slot, round, user, is_long, amount, fee)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                key.signature,
+                key.event_index,
+                key.slot,
+                event.round,
+                event.user.to_string(),
+                event.is_long,
+                event.amount,
+                event.fee
+            ],
+        )?;
+    } else if let Some(event) = decode::<events::WinningsClaimed>(data) {
+        insert_claim(
+            transaction,
+            key,
+            event.round_index,
+            event.user.to_string(),
+            None,
+            "parimutuel",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::MintWinningsClaimed>(data) {
+        insert_claim(
+            transaction,
+            key,
+            event.round_index,
+            event.user.to_string(),
+            Some(event.mint.to_string()),
+            "parimutuel",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::BucketWinningsClaimed>(data) {
+        insert_claim(
+            transaction,
+            key,
+            event.round_index,
+            event.user.to_string(),
+            None,
+            "bucket",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::FixedOddsWinningsClaimed>(data) {
+        insert_claim(
+            transaction,
+            key,
+            event.round_index,
+            event.user.to_string(),
+            None,
+            "fixed_odds",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::AffiliateWinningsClaimed>(data) {
+        insert_affiliate_earning(
+            transaction,
+            key,
+            event.round_index,
+            event.affiliate.to_string(),
+            None,
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::MintAffiliateWinningsClaimed>(data) {
+        insert_affiliate_earning(
+            transaction,
+            key,
+            event.round_index,
+            event.affiliate.to_string(),
+            Some(event.mint.to_string()),
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::Deposited>(data) {
+        insert_deposit(
+            transaction,
+            key,
+            event.user.to_string(),
+            event.stablecoin.to_string(),
+            "deposit",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::Withdrawn>(data) {
+        insert_deposit(
+            transaction,
+            key,
+            event.user.to_string(),
+            event.stablecoin.to_string(),
+            "withdrawal",
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::CollectedPlatformFees>(data) {
+        insert_platform_fee(
+            transaction,
+            key,
+            event.owner.to_string(),
+            None,
+            event.amount,
+        )?;
+    } else if let Some(event) = decode::<events::CollectedMintPlatformFees>(data) {
+        insert_platform_fee(
+            transaction,
+            key,
+            event.owner.to_string(),
+            Some(event.mint.to_string()),
+            event.amount,
+        )?;
+    } else if let Some((setting, value)) = config_change(data) {
+        transaction.execute(
+            "INSERT OR IGNORE INTO config_changes (signature, event_index, slot, setting, value)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![key.signature, key.event_index, key.slot, setting, value],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub struct Database {
+    connection: Connection,
+}
+
+impl Database {
+    pub fn open(path: &str) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self { connection })
+    }
+
+    /// Returns the highest slot of any indexed transaction.
+    pub fn last_slot(&self) -> rusqlite::Result<Option<u64>> {
+        self.connection
+            .query_row("SELECT MAX(slot) FROM transactions", [], |row| row.get(0))
+    }
+
+    pub fn contains(&self, signature: &str) -> rusqlite::Result<bool> {
+        self.connection
+            .query_row(
+                "SELECT 1 FROM transactions WHERE signature = ?1",
+                [signature],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|row| row.is_some())
+    }
+
+    /// Writes the events of a transaction atomically. Transactions that have already been indexed
+    /// are skipped, so re-ingesting an overlapping slot range is harmless. Returns whether the
+    /// transaction was new.
+    pub fn ingest(
+        &mut self,
+        signature: &str,
+        slot: u64,
+        block_time: Option<i64>,
+        events: &[Vec<u8>],
+    ) -> rusqlite::Result<bool> {
+        let transaction = self.connection.transaction()?;
+
+        let is_new = transaction.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot, block_time) VALUES (?1, ?2, ?3)",
+            params![signature, slot, block_time],
+        )? == 1;
+        if is_new {
+            for (event_index, data) in events.iter().enumerate() {
+                let key = EventKey {
+                    signature,
+                    event_index,
+                    slot,
+                };
+                insert_event(&transaction, &key, data)?;
+            }
+        }
+
+        transaction.commit()?;
+
+        Ok(is_new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(database: &Database, table: &str) -> u64 {
+        database
+            .connection
+            .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+    }
+
+    fn events(user: Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            events::RoundStarted {
+                round: 1,
+                starting_price: 100,
+                strike_price: 102,
+            }
+            .data(),
+            events::Deposited {
+                user,
+                stablecoin: Pubkey::new_unique(),
+                amount: 1_000,
+            }
+            .data(),
+            events::BetPlaced {
+                user,
+                round: 1,
+                amount: 300,
+                is_long: true,
+                affiliate: Pubkey::default(),
+                total_amount: 300,
+            }
+            .data(),
+        ]
+    }
+
+    #[test]
+    fn transactions_are_only_ingested_once() {
+        let mut database = Database::open(":memory:").unwrap();
+        let events = events(Pubkey::new_unique());
+
+        assert!(!database.contains("signature").unwrap());
+        assert!(database
+            .ingest("signature", 10, Some(1_000), &events)
+            .unwrap());
+        assert!(!database
+            .ingest("signature", 10, Some(1_000), &events)
+            .unwrap());
+
+        assert!(database.contains("signature").unwrap());
+        assert_eq!(database.last_slot().unwrap(), Some(10));
+        for table in ["transactions", "rounds", "deposits", "bets"] {
+            assert_eq!(count(&database, table), 1, "{table}");
+        }
+        let affiliate: Option<String> = database
+            .connection
+            .query_row("SELECT affiliate FROM bets", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(affiliate, None);
+    }
+
+    #[test]
+    fn the_same_events_in_another_transaction_are_ingested() {
+        let mut database = Database::open(":memory:").unwrap();
+        let events = events(Pubkey::new_unique());
+
+        database.ingest("first", 10, None, &events).unwrap();
+        assert!(database.ingest("second", 11, None, &events).unwrap());
+
+        assert_eq!(database.last_slot().unwrap(), Some(11));
+        assert_eq!(count(&database, "transactions"), 2);
+        assert_eq!(count(&database, "deposits"), 2);
+        assert_eq!(count(&database, "bets"), 2);
+        // Both start the same round, which is upserted.
+        assert_eq!(count(&database, "rounds"), 1);
+    }
+}
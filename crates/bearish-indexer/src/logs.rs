@@ -0,0 +1,58 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Returns the data of every event emitted by `program_id` in a transaction's logs, in order.
+///
+/// Anchor logs events as `Program data: <base64>`. The invocation stack is tracked so that data
+/// logged by other programs the transaction calls into is left out.
+pub fn program_data(logs: &[String], program_id: &Pubkey) -> Vec<Vec<u8>> {
+    let program_id = program_id.to_string();
+    let mut invocations = vec![];
+    let mut data = vec![];
+
+    for log in logs {
+        let Some(log) = log.strip_prefix("Program ") else {
+            continue;
+        };
+
+        if let Some(encoded) = log.strip_prefix("data: ") {
+            if invocations.last() == Some(&program_id.as_str()) {
+                if let Ok(bytes) = STANDARD.decode(encoded) {
+                    data.push(bytes);
+                }
+            }
+        } else if let Some((id, message)) = log.split_once(' ') {
+            if message.starts_with("invoke [") {
+                invocations.push(id);
+            } else if message == "success" || message.starts_with("failed") {
+                invocations.pop();
+            }
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_data_logged_by_the_program_is_kept() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let logs = [
+            format!("Program {program_id} invoke [1]"),
+            format!("Program data: {}", STANDARD.encode([1])),
+            format!("Program {other_program_id} invoke [2]"),
+            format!("Program data: {}", STANDARD.encode([2])),
+            format!("Program {other_program_id} success"),
+            "Program data: not base64!".to_string(),
+            format!("Program data: {}", STANDARD.encode([3])),
+            format!("Program {program_id} success"),
+            format!("Program data: {}", STANDARD.encode([4])),
+        ];
+
+        assert_eq!(program_data(&logs, &program_id), vec![vec![1], vec![3]]);
+    }
+}
@@ -0,0 +1,208 @@
+//! `bearish-indexer`, which decodes the events bearish-dot-fun emits into SQLite tables of
+//! rounds, bets, claims, deposits, affiliate earnings and config changes.
+//!
+//! `backfill` indexes the transactions of a slot range, and `watch` catches up from the last
+//! indexed slot before following new transactions live. Every transaction is written at most
+//! once, so ranges can overlap and either command can be re-run safely.
+
+mod db;
+mod logs;
+
+use std::str::FromStr;
+
+use anchor_client::{
+    solana_client::{
+        pubsub_client::PubsubClient,
+        rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+        rpc_config::{RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+    },
+    solana_sdk::{commitment_config::CommitmentConfig, signature::Signature},
+    Cluster,
+};
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+use db::Database;
+
+/// Signatures fetched per page while backfilling. This is the most the RPC allows.
+const SIGNATURES_PER_PAGE: usize = 1000;
+
+#[derive(Parser)]
+#[command(name = "bearish-indexer", version, about)]
+struct Args {
+    /// RPC URL of the cluster to index.
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        env = "BEARISH_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Path to the SQLite database, created if it does not exist.
+    #[arg(
+        long,
+        short = 'd',
+        global = true,
+        env = "BEARISH_DATABASE",
+        default_value = "bearish.db"
+    )]
+    database: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index the transactions of a slot range.
+    Backfill {
+        #[arg(long, default_value_t = 0)]
+        from_slot: u64,
+        /// Defaults to the latest confirmed slot.
+        #[arg(long)]
+        to_slot: Option<u64>,
+    },
+    /// Catch up from the last indexed slot, then index new transactions as they land.
+    Watch {
+        /// Websocket URL to subscribe on. Derived from the RPC URL by default.
+        #[arg(long)]
+        ws_url: Option<String>,
+    },
+}
+
+struct Indexer {
+    rpc: RpcClient,
+    database: Database,
+}
+
+impl Indexer {
+    fn backfill(&mut self, from_slot: u64, to_slot: Option<u64>) -> Result<()> {
+        let program_id = bearish_dot_fun::ID;
+        let mut signatures = vec![];
+        let mut before = None;
+
+        // Signatures come newest first, page back until the start of the range.
+        loop {
+            let page = self.rpc.get_signatures_for_address_with_config(
+                &program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(SIGNATURES_PER_PAGE),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+            let is_done = last.slot < from_slot || page.len() < SIGNATURES_PER_PAGE;
+
+            signatures.extend(page.into_iter().filter(|status| {
+                status.err.is_none()
+                    && status.slot >= from_slot
+                    && to_slot.is_none_or(|to_slot| status.slot <= to_slot)
+            }));
+
+            if is_done {
+                break;
+            }
+        }
+
+        let mut indexed = 0;
+        for status in signatures.iter().rev() {
+            if self.database.contains(&status.signature)? {
+                continue;
+            }
+
+            let transaction = self.rpc.get_transaction_with_config(
+                &Signature::from_str(&status.signature)?,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Json),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )?;
+            let log_messages = transaction
+                .transaction
+                .meta
+                .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+                .unwrap_or_default();
+
+            let is_new = self.database.ingest(
+                &status.signature,
+                transaction.slot,
+                transaction.block_time,
+                &logs::program_data(&log_messages, &program_id),
+            )?;
+            indexed += usize::from(is_new);
+        }
+
+        println!(
+            "indexed {indexed} of {} transactions from slot {from_slot}",
+            signatures.len()
+        );
+
+        Ok(())
+    }
+
+    fn watch(&mut self, ws_url: &str) -> Result<()> {
+        // Subscribe before catching up so that nothing landing in between is missed. Anything
+        // seen twice is skipped by the database.
+        let (_subscription, receiver) = PubsubClient::logs_subscribe(
+            ws_url,
+            RpcTransactionLogsFilter::Mentions(vec![bearish_dot_fun::ID.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )?;
+
+        let from_slot = self.database.last_slot()?.unwrap_or(0);
+        self.backfill(from_slot, None)?;
+
+        for response in receiver {
+            let logs = response.value;
+            if logs.err.is_some() {
+                continue;
+            }
+
+            if self.database.ingest(
+                &logs.signature,
+                response.context.slot,
+                None,
+                &logs::program_data(&logs.logs, &bearish_dot_fun::ID),
+            )? {
+                println!(
+                    "indexed {} at slot {}",
+                    logs.signature, response.context.slot
+                );
+            }
+        }
+
+        Err(anyhow!("log subscription closed"))
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let mut indexer = Indexer {
+        rpc: RpcClient::new_with_commitment(args.url.clone(), CommitmentConfig::confirmed()),
+        database: Database::open(&args.database)?,
+    };
+
+    match args.command {
+        Command::Backfill { from_slot, to_slot } => indexer.backfill(from_slot, to_slot),
+        Command::Watch { ws_url } => {
+            let ws_url = match ws_url {
+                Some(ws_url) => ws_url,
+                None => Cluster::from_str(&args.url)?.ws_url().to_string(),
+            };
+
+            indexer.watch(&ws_url)
+        }
+    }
+}
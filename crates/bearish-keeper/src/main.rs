@@ -0,0 +1,521 @@
+//! `bearish-keeper`, a daemon that ends the current round as soon as its duration has elapsed
+//! and starts the next one, so that rounds progress without anyone having to call
+//! `end_round`/`start_round` by hand.
+//!
+//! It also runs the cranks that follow each round transition. Once a round has ended, it settles
+//! the pools of every mint that was bet in and claims the winnings of users who gave the keeper a
+//! session allowed to claim. Once the next round has started, it places users' repeat bets and
+//! executes the standing orders that target it and whose condition is met.
+//!
+//! Rounds are only sent for when the oracle price is fresh enough for the program to accept it.
+//! Stale prices and failed transactions are retried with exponential backoff. A failed crank for
+//! one user is logged and skipped.
+//!
+//! Logging goes through `env_logger` at `info` by default, see `RUST_LOG`.
+
+mod metrics;
+mod schedule;
+
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+    sync::{atomic::Ordering, Arc},
+    thread,
+    time::Duration,
+};
+
+use anchor_client::{
+    anchor_lang::Space,
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        instruction::Instruction,
+        signature::{read_keypair_file, Keypair, Signer},
+        sysvar::{self, clock::Clock},
+    },
+    Client, Cluster, Program,
+};
+use anyhow::{anyhow, Result};
+use bearish_dot_fun::{
+    constants, Bet, GlobalRoundInfo, MintMarket, Order, PlatformConfig, Round, Session, UserInfo,
+    WinningsPreference,
+};
+use bearish_dot_fun_client::{
+    instructions::{self, WalletPayout},
+    pda,
+};
+use clap::Parser;
+use log::{info, warn};
+use pyth_sdk_solana::state::load_price_account;
+
+use metrics::Metrics;
+use schedule::{Backoff, Crank, Progress, RoundAction};
+
+/// Offset of `Session::delegate`, after the discriminator and `user`.
+const SESSION_DELEGATE_OFFSET: usize = 8 + 32;
+/// Offset of `UserInfo::winnings_preference`, the last field of the account.
+const WINNINGS_PREFERENCE_OFFSET: usize = constants::general::ANCHOR_DISCRIMINATOR_SIZE
+    + UserInfo::INIT_SPACE
+    - WinningsPreference::INIT_SPACE;
+/// Borsh variant index of `WinningsPreference::RepeatBet`.
+const REPEAT_BET_VARIANT: u8 = 2;
+
+#[derive(Parser)]
+#[command(name = "bearish-keeper", version, about)]
+struct Args {
+    /// RPC URL of the cluster to connect to.
+    #[arg(
+        long,
+        short = 'u',
+        env = "BEARISH_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Keypair that signs and pays for transactions.
+    #[arg(
+        long,
+        short = 'k',
+        env = "BEARISH_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+
+    /// Address to serve Prometheus metrics on.
+    #[arg(
+        long,
+        env = "BEARISH_METRICS_ADDRESS",
+        default_value = "127.0.0.1:9464"
+    )]
+    metrics_address: SocketAddr,
+
+    /// Seconds between checks of the current round.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    poll_interval: u64,
+
+    /// Upper bound in seconds for the backoff between retries.
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    max_backoff: u64,
+}
+
+/// What the keeper did, or could not do, about the current round in one pass.
+enum Step {
+    /// The current round is running and its duration has not elapsed yet, or is waiting for a
+    /// strike override.
+    Idle,
+    Started,
+    Ended,
+    /// The oracle price is older than the staleness threshold.
+    StalePrice,
+}
+
+struct Keeper<'a> {
+    program: Program<&'a Keypair>,
+    metrics: Arc<Metrics>,
+    progress: Progress,
+}
+
+impl Keeper<'_> {
+    fn clock(&self) -> Result<Clock> {
+        let account = self.program.rpc().get_account(&sysvar::clock::ID)?;
+
+        Ok(bincode::deserialize(&account.data)?)
+    }
+
+    /// Mirrors the price check `start_round` and `end_round` do on-chain, so that no transaction
+    /// is sent that would fail on a stale price.
+    fn is_price_fresh(&self, global_round_info: &GlobalRoundInfo, clock: &Clock) -> Result<bool> {
+        let price_account = global_round_info.price_account;
+        let data = self.program.rpc().get_account_data(&price_account)?;
+        let price_feed = load_price_account::<32, ()>(&data)
+            .map_err(|error| anyhow!("invalid price account {price_account}: {error}"))?
+            .to_price_feed(&price_account);
+
+        Ok(price_feed
+            .get_price_no_older_than(clock.unix_timestamp, global_round_info.staleness_threshold)
+            .is_some())
+    }
+
+    /// Sends a crank transaction on behalf of a single user or mint. A failure is logged and
+    /// counted but doesn't hold up the others.
+    fn send_crank(&self, instruction: Instruction, description: &str) -> bool {
+        match self.program.request().instruction(instruction).send() {
+            Ok(signature) => {
+                info!("{description}: {signature}");
+                true
+            }
+            Err(error) => {
+                self.metrics
+                    .transaction_errors
+                    .fetch_add(1, Ordering::Relaxed);
+                warn!("failed to {description}: {error}");
+                false
+            }
+        }
+    }
+
+    /// Settles the pools of every mint that was bet in during `round`, which must have ended.
+    fn settle_mint_rounds(&self, round: u64) -> Result<()> {
+        for (_, mint_market) in self.program.accounts::<MintMarket>(vec![])? {
+            let mint = mint_market.mint;
+            // No mint round is created until someone bets in the mint.
+            let Ok(mint_round) = self.program.account::<Round>(pda::mint_round(round, &mint))
+            else {
+                continue;
+            };
+            if mint_round.ending_price != 0 {
+                continue;
+            }
+
+            if self.send_crank(
+                instructions::settle_mint_round(mint, round),
+                &format!("settle round {round} for mint {mint}"),
+            ) {
+                self.metrics
+                    .mint_rounds_settled
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Claims the winnings in `round_number`, which must have ended, of every user with a live
+    /// session that lets the keeper claim for them. Winnings follow the user's preference.
+    fn claim_winnings(
+        &self,
+        round_number: u64,
+        platform_config: &PlatformConfig,
+        clock: &Clock,
+    ) -> Result<()> {
+        let keeper = self.program.payer();
+        let round: Round = self.program.account(pda::round(round_number))?;
+        let sessions = self
+            .program
+            .accounts::<Session>(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                SESSION_DELEGATE_OFFSET,
+                keeper.as_ref(),
+            ))])?;
+
+        for (_, session) in sessions {
+            if !session.permissions.claim_winnings
+                || session.expires_at <= clock.unix_timestamp as u64
+            {
+                continue;
+            }
+            let user = session.user;
+            let Ok(bet) = self
+                .program
+                .account::<Bet>(pda::user_bet(&user, round_number))
+            else {
+                continue;
+            };
+            if round.validate_claimable(&bet).is_err() {
+                continue;
+            }
+
+            let user_info: UserInfo = self.program.account(pda::user_info(&user))?;
+            let wallet_payout = match user_info.winnings_preference {
+                WinningsPreference::WithdrawToWallet { token_account } => Some(WalletPayout {
+                    stablecoin: platform_config.stablecoin,
+                    token_account,
+                    token_program: self
+                        .program
+                        .rpc()
+                        .get_account(&platform_config.stablecoin)?
+                        .owner,
+                }),
+                _ => None,
+            };
+
+            if self.send_crank(
+                instructions::claim_user_winnings(keeper, user, round_number, wallet_payout),
+                &format!("claim round {round_number} winnings for {user}"),
+            ) {
+                self.metrics
+                    .winnings_claimed
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Places the repeat bet of every user who has one and can afford it in `round_number`, which
+    /// must have started. Users who already bet in the round keep their bet.
+    fn execute_repeat_bets(
+        &self,
+        round_number: u64,
+        platform_config: &PlatformConfig,
+    ) -> Result<()> {
+        let keeper = self.program.payer();
+        let rpc = self.program.rpc();
+        // Users escrow the rent of their repeat bets in their info account, on top of its own.
+        let escrow_needed = rpc.get_minimum_balance_for_rent_exemption(
+            constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        )? + rpc.get_minimum_balance_for_rent_exemption(
+            constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        )?;
+        let user_infos = self
+            .program
+            .accounts::<UserInfo>(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                WINNINGS_PREFERENCE_OFFSET,
+                &[REPEAT_BET_VARIANT],
+            ))])?;
+
+        for (address, user_info) in user_infos {
+            let WinningsPreference::RepeatBet { amount, .. } = user_info.winnings_preference else {
+                continue;
+            };
+            let user = user_info.user;
+            if user_info.amount < amount
+                || rpc.get_balance(&address)? < escrow_needed
+                || self
+                    .program
+                    .account::<Bet>(pda::user_bet(&user, round_number))
+                    .is_ok()
+            {
+                continue;
+            }
+
+            if self.send_crank(
+                instructions::execute_repeat_bet(
+                    keeper,
+                    user,
+                    round_number,
+                    platform_config.has_house_pool,
+                ),
+                &format!("place round {round_number} repeat bet for {user}"),
+            ) {
+                self.metrics
+                    .repeat_bets_executed
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Executes every standing order that bets in `round_number`, which must have started, and
+    /// whose condition is met by the round before it.
+    fn execute_orders(&self, round_number: u64, platform_config: &PlatformConfig) -> Result<()> {
+        // Orders are conditioned on the previous round, which the first round doesn't have.
+        if round_number == 1 {
+            return Ok(());
+        }
+
+        let executor = self.program.payer();
+        let previous_round: Round = self.program.account(pda::round(round_number - 1))?;
+
+        for (_, order) in self.program.accounts::<Order>(vec![])? {
+            if order.validate_target_round(round_number).is_err()
+                || order.validate_condition_met(&previous_round).is_err()
+            {
+                continue;
+            }
+
+            if self.send_crank(
+                instructions::execute_order(
+                    executor,
+                    order.user,
+                    order.order_id,
+                    round_number,
+                    platform_config.has_house_pool,
+                ),
+                &format!("execute order {} of {}", order.order_id, order.user),
+            ) {
+                self.metrics.orders_executed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_crank(
+        &self,
+        crank: &Crank,
+        platform_config: &PlatformConfig,
+        clock: &Clock,
+    ) -> Result<()> {
+        match *crank {
+            Crank::AfterEnd(round) => {
+                self.settle_mint_rounds(round)?;
+                self.claim_winnings(round, platform_config, clock)
+            }
+            Crank::AfterStart(round) => {
+                self.execute_repeat_bets(round, platform_config)?;
+                self.execute_orders(round, platform_config)
+            }
+        }
+    }
+
+    fn step(&mut self) -> Result<Step> {
+        let platform_config: PlatformConfig = self.program.account(pda::platform_config())?;
+        let global_round_info = &platform_config.global_round_info;
+        let round_number = pda::current_round(global_round_info);
+        self.metrics
+            .current_round
+            .store(round_number, Ordering::Relaxed);
+
+        let start_time = self
+            .program
+            .account::<Round>(pda::round(round_number))
+            .map(|round| round.start_time)
+            .unwrap_or(0);
+        let clock = self.clock()?;
+
+        for crank in self.progress.due(global_round_info.round, start_time != 0) {
+            self.run_crank(&crank, &platform_config, &clock)?;
+            self.progress.record(&crank);
+        }
+
+        let action = schedule::round_action(
+            start_time,
+            global_round_info.duration,
+            platform_config.is_strike_override_pending,
+            clock.unix_timestamp as u64,
+        );
+        if action == RoundAction::Wait {
+            return Ok(Step::Idle);
+        }
+        if !self.is_price_fresh(global_round_info, &clock)? {
+            return Ok(Step::StalePrice);
+        }
+
+        let payer = self.program.payer();
+        let price_account = global_round_info.price_account;
+        let instruction = if action == RoundAction::End {
+            instructions::end_round(
+                payer,
+                round_number,
+                price_account,
+                platform_config.has_house_pool,
+            )
+        } else {
+            instructions::start_round(payer, round_number, price_account)
+        };
+        let signature = self.program.request().instruction(instruction).send()?;
+
+        if action == RoundAction::End {
+            self.metrics.rounds_ended.fetch_add(1, Ordering::Relaxed);
+            info!("ended round {round_number}: {signature}");
+            Ok(Step::Ended)
+        } else {
+            self.metrics.rounds_started.fetch_add(1, Ordering::Relaxed);
+            info!("started round {round_number}: {signature}");
+            Ok(Step::Started)
+        }
+    }
+
+    fn run(&mut self, poll_interval: Duration, max_backoff: Duration) {
+        let mut backoff = Backoff::new(poll_interval, max_backoff);
+
+        loop {
+            let failure = match self.step() {
+                Ok(Step::Idle) => {
+                    backoff.reset();
+                    thread::sleep(poll_interval);
+                    continue;
+                }
+                // Move on to the next round straight away.
+                Ok(Step::Started | Step::Ended) => {
+                    self.record_success();
+                    backoff.reset();
+                    continue;
+                }
+                Ok(Step::StalePrice) => {
+                    self.metrics
+                        .stale_price_retries
+                        .fetch_add(1, Ordering::Relaxed);
+                    "oracle price is stale".to_string()
+                }
+                Err(error) => {
+                    self.metrics
+                        .transaction_errors
+                        .fetch_add(1, Ordering::Relaxed);
+                    error.to_string()
+                }
+            };
+
+            let delay = backoff.next();
+            warn!("{failure}, retrying in {delay:?}");
+            thread::sleep(delay);
+        }
+    }
+
+    fn record_success(&self) {
+        if let Ok(clock) = self.clock() {
+            self.metrics
+                .last_success_timestamp
+                .store(clock.unix_timestamp as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+fn read_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{rest}", std::env::var("HOME")?),
+        None => path.to_string(),
+    };
+
+    read_keypair_file(&path).map_err(|error| anyhow!("failed to read keypair {path}: {error}"))
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
+
+    let payer = read_keypair(&args.keypair)?;
+    let client = Client::new_with_options(
+        Cluster::from_str(&args.url)?,
+        &payer,
+        CommitmentConfig::confirmed(),
+    );
+    let mut keeper = Keeper {
+        program: client.program(bearish_dot_fun::ID)?,
+        metrics: Arc::new(Metrics::default()),
+        progress: Progress::default(),
+    };
+
+    keeper.metrics.serve(args.metrics_address)?;
+    info!(
+        "keeping rounds as {} on {}, metrics on {}",
+        payer.pubkey(),
+        args.url,
+        args.metrics_address
+    );
+
+    keeper.run(
+        Duration::from_secs(args.poll_interval),
+        Duration::from_secs(args.max_backoff),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_client::anchor_lang::{prelude::Pubkey, AccountSerialize};
+
+    use super::*;
+
+    #[test]
+    fn repeat_bets_are_matched_at_the_winnings_preference_offset() {
+        let user_info = UserInfo {
+            amount: 1_000,
+            affiliate: Pubkey::default(),
+            last_won_round: 0,
+            times_won: 0,
+            bump: 255,
+            user: Pubkey::new_unique(),
+            winnings_preference: WinningsPreference::RepeatBet {
+                amount: 100,
+                is_long: true,
+            },
+        };
+        let mut data = Vec::new();
+        user_info.try_serialize(&mut data).unwrap();
+
+        assert_eq!(data[WINNINGS_PREFERENCE_OFFSET], REPEAT_BET_VARIANT);
+    }
+}
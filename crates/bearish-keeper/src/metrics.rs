@@ -0,0 +1,133 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+/// Counters and gauges exported in the Prometheus text format.
+#[derive(Default)]
+pub struct Metrics {
+    pub current_round: AtomicU64,
+    pub rounds_started: AtomicU64,
+    pub rounds_ended: AtomicU64,
+    pub mint_rounds_settled: AtomicU64,
+    pub winnings_claimed: AtomicU64,
+    pub repeat_bets_executed: AtomicU64,
+    pub orders_executed: AtomicU64,
+    pub stale_price_retries: AtomicU64,
+    pub transaction_errors: AtomicU64,
+    pub last_success_timestamp: AtomicU64,
+}
+
+impl Metrics {
+    fn render(&self) -> String {
+        let metrics: [(&str, &str, &str, &AtomicU64); 10] = [
+            (
+                "bearish_keeper_current_round",
+                "gauge",
+                "Number of the round that is currently running or about to be started.",
+                &self.current_round,
+            ),
+            (
+                "bearish_keeper_rounds_started_total",
+                "counter",
+                "Rounds started by this keeper.",
+                &self.rounds_started,
+            ),
+            (
+                "bearish_keeper_rounds_ended_total",
+                "counter",
+                "Rounds ended by this keeper.",
+                &self.rounds_ended,
+            ),
+            (
+                "bearish_keeper_mint_rounds_settled_total",
+                "counter",
+                "Mint rounds settled by this keeper.",
+                &self.mint_rounds_settled,
+            ),
+            (
+                "bearish_keeper_winnings_claimed_total",
+                "counter",
+                "Winnings claimed by this keeper on behalf of users.",
+                &self.winnings_claimed,
+            ),
+            (
+                "bearish_keeper_repeat_bets_executed_total",
+                "counter",
+                "Repeat bets placed by this keeper.",
+                &self.repeat_bets_executed,
+            ),
+            (
+                "bearish_keeper_orders_executed_total",
+                "counter",
+                "Standing orders executed by this keeper.",
+                &self.orders_executed,
+            ),
+            (
+                "bearish_keeper_stale_price_retries_total",
+                "counter",
+                "Times a round could not be started or ended because the oracle price was stale.",
+                &self.stale_price_retries,
+            ),
+            (
+                "bearish_keeper_transaction_errors_total",
+                "counter",
+                "Failed RPC calls and transactions.",
+                &self.transaction_errors,
+            ),
+            (
+                "bearish_keeper_last_success_timestamp_seconds",
+                "gauge",
+                "Unix timestamp of the last round started or ended by this keeper.",
+                &self.last_success_timestamp,
+            ),
+        ];
+
+        metrics
+            .iter()
+            .map(|(name, kind, help, value)| {
+                format!(
+                    "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {}\n",
+                    value.load(Ordering::Relaxed)
+                )
+            })
+            .collect()
+    }
+
+    /// Serves the metrics to every HTTP request on `address` from a background thread.
+    pub fn serve(self: &Arc<Self>, address: SocketAddr) -> std::io::Result<()> {
+        let listener = TcpListener::bind(address)?;
+        let metrics = Arc::clone(self);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Err(error) = metrics.respond(stream) {
+                    log::warn!("failed to serve metrics: {error}");
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn respond(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        // Drain the request headers, the response is the same for every path.
+        let mut reader = BufReader::new(&stream);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let body = self.render();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+}
@@ -0,0 +1,180 @@
+//! Decides what the keeper does next from the state it read on-chain. Nothing here talks to the
+//! cluster, so the scheduling can be tested on its own.
+
+use std::time::Duration;
+
+/// What to do about the current round.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RoundAction {
+    /// The current round is running and its duration has not elapsed yet, or it is waiting to be
+    /// started with an explicit strike price.
+    Wait,
+    Start,
+    End,
+}
+
+/// `start_time` is 0 while the current round has not been started. While a strike override is
+/// pending, the round is left for the strike authority to start.
+pub fn round_action(
+    start_time: u64,
+    duration: u64,
+    is_strike_override_pending: bool,
+    now: u64,
+) -> RoundAction {
+    if start_time == 0 && is_strike_override_pending {
+        RoundAction::Wait
+    } else if start_time == 0 {
+        RoundAction::Start
+    } else if now < start_time + duration {
+        RoundAction::Wait
+    } else {
+        RoundAction::End
+    }
+}
+
+/// Work that follows a round transition.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Crank {
+    /// Settle the mint rounds of the round, which has ended, and claim the winnings of the users
+    /// who let the keeper claim for them.
+    AfterEnd(u64),
+    /// Place the repeat bets and execute the standing orders for the round, which has started.
+    AfterStart(u64),
+}
+
+/// The rounds whose cranks have run since the keeper started.
+#[derive(Default)]
+pub struct Progress {
+    last_ended_round: u64,
+    last_started_round: u64,
+}
+
+impl Progress {
+    /// Returns the cranks that are due, in the order they must run, given the number of the last
+    /// round that has ended (0 if none has) and whether the round after it has started. Claims
+    /// run before repeat bets so that the winnings they credit can fund them.
+    pub fn due(&self, last_ended_round: u64, is_current_round_started: bool) -> Vec<Crank> {
+        let mut cranks = Vec::new();
+
+        if last_ended_round > self.last_ended_round {
+            cranks.push(Crank::AfterEnd(last_ended_round));
+        }
+        let current_round = last_ended_round + 1;
+        if is_current_round_started && current_round > self.last_started_round {
+            cranks.push(Crank::AfterStart(current_round));
+        }
+
+        cranks
+    }
+
+    pub fn record(&mut self, crank: &Crank) {
+        match *crank {
+            Crank::AfterEnd(round) => self.last_ended_round = round,
+            Crank::AfterStart(round) => self.last_started_round = round,
+        }
+    }
+}
+
+/// Exponential backoff between retries, reset on success.
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            current: initial,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+    }
+
+    /// Returns how long to wait before the next retry and doubles it, up to the max.
+    pub fn next(&mut self) -> Duration {
+        let backoff = self.current;
+        self.current = (self.current * 2).min(self.max);
+
+        backoff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_are_started_then_ended_once_their_duration_has_elapsed() {
+        assert_eq!(round_action(0, 60, false, 1_000), RoundAction::Start);
+        assert_eq!(round_action(1_000, 60, false, 1_000), RoundAction::Wait);
+        assert_eq!(round_action(1_000, 60, false, 1_059), RoundAction::Wait);
+        assert_eq!(round_action(1_000, 60, false, 1_060), RoundAction::End);
+        assert_eq!(round_action(1_000, 60, false, 5_000), RoundAction::End);
+    }
+
+    #[test]
+    fn rounds_with_a_pending_strike_override_are_not_started() {
+        assert_eq!(round_action(0, 60, true, 1_000), RoundAction::Wait);
+        // The override only holds off the start, not the end of a running round.
+        assert_eq!(round_action(1_000, 60, true, 1_060), RoundAction::End);
+    }
+
+    #[test]
+    fn nothing_is_due_before_the_first_round_has_started() {
+        assert_eq!(Progress::default().due(0, false), vec![]);
+    }
+
+    #[test]
+    fn cranks_follow_each_round_transition_once() {
+        let mut progress = Progress::default();
+
+        assert_eq!(progress.due(0, true), vec![Crank::AfterStart(1)]);
+        progress.record(&Crank::AfterStart(1));
+        assert_eq!(progress.due(0, true), vec![]);
+
+        assert_eq!(progress.due(1, false), vec![Crank::AfterEnd(1)]);
+        progress.record(&Crank::AfterEnd(1));
+        assert_eq!(progress.due(1, false), vec![]);
+
+        assert_eq!(progress.due(1, true), vec![Crank::AfterStart(2)]);
+        progress.record(&Crank::AfterStart(2));
+        assert_eq!(progress.due(1, true), vec![]);
+    }
+
+    #[test]
+    fn claims_run_before_the_next_rounds_repeat_bets() {
+        // A keeper that comes up after the next round has already started.
+        assert_eq!(
+            Progress::default().due(7, true),
+            vec![Crank::AfterEnd(7), Crank::AfterStart(8)]
+        );
+    }
+
+    #[test]
+    fn unrecorded_cranks_stay_due() {
+        let mut progress = Progress::default();
+        progress.record(&Crank::AfterEnd(3));
+
+        assert_eq!(progress.due(3, true), vec![Crank::AfterStart(4)]);
+        assert_eq!(progress.due(3, true), vec![Crank::AfterStart(4)]);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_max_and_resets() {
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+
+        assert_eq!(backoff.next(), Duration::from_secs(1));
+        assert_eq!(backoff.next(), Duration::from_secs(2));
+        assert_eq!(backoff.next(), Duration::from_secs(4));
+        assert_eq!(backoff.next(), Duration::from_secs(5));
+        assert_eq!(backoff.next(), Duration::from_secs(5));
+
+        backoff.reset();
+        assert_eq!(backoff.next(), Duration::from_secs(1));
+    }
+}
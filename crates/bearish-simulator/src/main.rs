@@ -0,0 +1,229 @@
+//! `bearish-simulator`, a backtester for the platform's `Allocation` and `JackPotAllocation`.
+//!
+//! It replays a historical price series from a CSV file as back-to-back rounds, each ending at the
+//! price the next one starts at, with a synthetic population of bettors. Rounds are settled and
+//! claimed with the program's own payout math, and the report shows what the config earns the
+//! platform, how the jackpot grows, how often streaks pay out and what players get back.
+//!
+//! Amounts are given in the base units of the stablecoin.
+
+mod prices;
+mod simulation;
+
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{bail, Result};
+use bearish_dot_fun_math::{Allocation, JackPotAllocation, BPS};
+use clap::Parser;
+
+use simulation::{Config, Population, Report, Simulation, STREAKS};
+
+#[derive(Parser)]
+#[command(name = "bearish-simulator", version, about)]
+struct Args {
+    /// CSV file of historical prices, oldest first.
+    prices: PathBuf,
+
+    /// Name of the CSV column holding the prices.
+    #[arg(long, default_value = "close")]
+    column: String,
+
+    /// Decimals the prices are kept with, like a Pyth exponent of minus this many.
+    #[arg(long, default_value_t = 8)]
+    decimals: u32,
+
+    /// Winners, affiliate, jackpot and platform shares in bips.
+    #[arg(long, value_parser = parse_allocation, default_value = "4500,500,4000,1000")]
+    allocation: Allocation,
+
+    /// Jackpot shares in bips for win streaks of 5 to 10 rounds.
+    #[arg(
+        long,
+        value_parser = parse_jackpot_allocation,
+        default_value = "1000,1500,2000,2500,3000,10000"
+    )]
+    jackpot_allocation: JackPotAllocation,
+
+    /// Offset of the strike price from the starting price in bips, above -100% and at most 100%.
+    #[arg(
+        long,
+        default_value_t = 0,
+        allow_negative_numbers = true,
+        value_parser = clap::value_parser!(i32).range(-9_999..=10_000)
+    )]
+    strike_offset: i32,
+
+    /// Jackpot pool amount before the first round.
+    #[arg(long, default_value_t = 0)]
+    initial_jackpot: u64,
+
+    /// Number of bettors.
+    #[arg(long, default_value_t = 100)]
+    bettors: usize,
+
+    /// Chance that a bettor bets in any given round.
+    #[arg(long, default_value_t = 0.5, value_parser = parse_probability)]
+    participation: f64,
+
+    #[arg(long, default_value_t = 1_000_000)]
+    min_bet: u64,
+
+    #[arg(long, default_value_t = 100_000_000)]
+    max_bet: u64,
+
+    /// Share of the bettors who bet on the previous round's price move.
+    #[arg(long, default_value_t = 0.25, value_parser = parse_probability)]
+    momentum: f64,
+
+    /// Share of the bettors who bet against the previous round's price move.
+    #[arg(long, default_value_t = 0.25, value_parser = parse_probability)]
+    contrarian: f64,
+
+    /// Share of the bettors who were referred by an affiliate.
+    #[arg(long, default_value_t = 0.5, value_parser = parse_probability)]
+    affiliated: f64,
+
+    /// Seed of the bettors' random choices, so that runs can be reproduced.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+fn parse_shares<const N: usize>(value: &str) -> Result<[u16; N], String> {
+    let shares = value
+        .split(',')
+        .map(|share| u16::from_str(share.trim()).map_err(|error| error.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    shares
+        .try_into()
+        .map_err(|_| format!("expected {N} comma separated values in bips"))
+}
+
+/// Parses an allocation, which must add up to 100% like the program requires.
+fn parse_allocation(value: &str) -> Result<Allocation, String> {
+    let shares = parse_shares::<4>(value)?;
+    if shares.iter().map(|share| *share as u32).sum::<u32>() != BPS as u32 {
+        return Err(format!("shares must add up to {BPS} bips"));
+    }
+    let [winners_share, affiliate_share, jackpot_share, platform_share] = shares;
+
+    Ok(Allocation {
+        winners_share,
+        affiliate_share,
+        jackpot_share,
+        platform_share,
+    })
+}
+
+/// Parses a jackpot allocation, which must be strictly increasing and at most 100% like the
+/// program requires.
+fn parse_jackpot_allocation(value: &str) -> Result<JackPotAllocation, String> {
+    let shares = parse_shares::<6>(value)?;
+    if shares.iter().any(|share| *share > BPS) {
+        return Err(format!("shares must be at most {BPS} bips"));
+    }
+    if shares.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err("shares must be strictly increasing".to_string());
+    }
+    let [streak_5, streak_6, streak_7, streak_8, streak_9, streak_10] = shares;
+
+    Ok(JackPotAllocation {
+        streak_5,
+        streak_6,
+        streak_7,
+        streak_8,
+        streak_9,
+        streak_10,
+    })
+}
+
+fn parse_probability(value: &str) -> Result<f64, String> {
+    let probability = f64::from_str(value).map_err(|error| error.to_string())?;
+    if !(0.0..=1.0).contains(&probability) {
+        return Err("expected a value between 0 and 1".to_string());
+    }
+
+    Ok(probability)
+}
+
+fn field(name: &str, value: impl std::fmt::Display) {
+    println!("  {name:<32} {value}");
+}
+
+fn percentage(value: f64) -> String {
+    format!("{:.2}%", value * 100.0)
+}
+
+fn print_report(report: &Report) {
+    println!("Rounds");
+    field("rounds", report.rounds);
+    field("ties", report.ties);
+    field("one-sided rounds", report.one_sided_rounds);
+    field("bets", report.bets);
+    field("wagered", report.wagered);
+    println!("Players");
+    field("returned to players", report.returned_to_players);
+    field("rtp", percentage(report.rtp()));
+    println!("Platform");
+    field("platform revenue", report.platform_revenue);
+    field("platform margin", percentage(report.platform_margin()));
+    field("affiliate payouts", report.affiliate_payouts);
+    field("left in the vault", report.unclaimed);
+    println!("Jackpot");
+    field("initial jackpot", report.initial_jackpot);
+    field("final jackpot", report.final_jackpot);
+    field("peak jackpot", report.peak_jackpot);
+    field("inflows", report.jackpot_inflows);
+    field(
+        "streak payouts",
+        report.streak_payout_amounts.iter().sum::<u128>(),
+    );
+    println!("Streak payouts");
+    for (streak, (payouts, amount)) in STREAKS.zip(
+        report
+            .streak_payouts
+            .iter()
+            .zip(&report.streak_payout_amounts),
+    ) {
+        let frequency = match payouts {
+            0 => "never".to_string(),
+            _ => format!("every {:.1} rounds", report.rounds as f64 / *payouts as f64),
+        };
+        field(
+            &format!("{streak} wins"),
+            format!("{payouts} paying {amount} in total, {frequency}"),
+        );
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.min_bet == 0 || args.min_bet > args.max_bet {
+        bail!("--min-bet must be positive and at most --max-bet");
+    }
+    if args.momentum + args.contrarian > 1.0 {
+        bail!("--momentum and --contrarian must add up to at most 1");
+    }
+
+    let prices = prices::load(&args.prices, &args.column, args.decimals)?;
+    let config = Config {
+        allocation: args.allocation,
+        jackpot_allocation: args.jackpot_allocation,
+        strike_offset: args.strike_offset,
+        initial_jackpot: args.initial_jackpot,
+    };
+    let population = Population {
+        bettors: args.bettors,
+        participation: args.participation,
+        min_bet: args.min_bet,
+        max_bet: args.max_bet,
+        momentum: args.momentum,
+        contrarian: args.contrarian,
+        affiliated: args.affiliated,
+    };
+
+    let report = Simulation::new(&config, &population, args.seed).run(&prices);
+    print_report(&report);
+
+    Ok(())
+}
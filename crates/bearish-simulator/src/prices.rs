@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// Reads the `column` column of the CSV at `path` as prices with `decimals` decimals, the way
+/// they'd be published at an exponent of `-decimals`. Rows are expected oldest first.
+pub fn load(path: &Path, column: &str, decimals: u32) -> Result<Vec<u64>> {
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    let index = reader
+        .headers()?
+        .iter()
+        .position(|header| header.trim().eq_ignore_ascii_case(column))
+        .ok_or_else(|| anyhow!("{} has no {column} column", path.display()))?;
+
+    let mut prices = Vec::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let value = record
+            .get(index)
+            .ok_or_else(|| anyhow!("row {} has no {column} value", row + 1))?;
+        let price = parse_price(value.trim(), decimals)
+            .with_context(|| format!("invalid price {value:?} on row {}", row + 1))?;
+        prices.push(price);
+    }
+
+    if prices.len() < 2 {
+        bail!(
+            "{} needs at least two prices to play a round",
+            path.display()
+        );
+    }
+
+    Ok(prices)
+}
+
+/// Parses a decimal price like `97123.45` into an integer with `decimals` decimals, without going
+/// through floating point so that equal prices stay equal. Extra decimals are truncated.
+fn parse_price(value: &str, decimals: u32) -> Result<u64> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let fraction = format!("{fraction:0<width$}", width = decimals as usize);
+
+    let price = format!("{integer}{}", &fraction[..decimals as usize])
+        .parse::<u64>()
+        .map_err(|error| anyhow!("{error}"))?;
+    if price == 0 {
+        bail!("prices must be positive");
+    }
+
+    Ok(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_are_parsed_without_rounding() {
+        assert_eq!(parse_price("97123.45", 2).unwrap(), 9_712_345);
+        assert_eq!(parse_price("97123.4", 2).unwrap(), 9_712_340);
+        assert_eq!(parse_price("97123", 2).unwrap(), 9_712_300);
+        assert_eq!(parse_price("97123.456", 2).unwrap(), 9_712_345);
+        assert_eq!(parse_price("0.1", 8).unwrap(), 10_000_000);
+
+        assert!(parse_price("0.00", 2).is_err());
+        assert!(parse_price("-1", 2).is_err());
+        assert!(parse_price("abc", 2).is_err());
+    }
+}
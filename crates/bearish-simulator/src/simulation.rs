@@ -0,0 +1,396 @@
+//! Replays a price series as back-to-back rounds, with synthetic bettors betting in each round and
+//! claiming their winnings as soon as it ends. Rounds are settled and paid out with the program's
+//! own math, without a house pool.
+
+use bearish_dot_fun_math::{self as math, Allocation, JackPotAllocation, Streak};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Shortest and longest streaks that can pay out a jackpot.
+pub const STREAKS: std::ops::RangeInclusive<u64> = 5..=10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Bets on either side with equal odds.
+    Random,
+    /// Bets on the direction the price moved in the previous round.
+    Momentum,
+    /// Bets against the direction the price moved in the previous round.
+    Contrarian,
+}
+
+/// The platform settings being tested.
+pub struct Config {
+    pub allocation: Allocation,
+    pub jackpot_allocation: JackPotAllocation,
+    pub strike_offset: i32,
+    pub initial_jackpot: u64,
+}
+
+/// How many bettors there are and how they bet.
+pub struct Population {
+    pub bettors: usize,
+    /// Chance that a bettor bets in any given round.
+    pub participation: f64,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    /// Shares of the bettors following the momentum and contrarian strategies. The others bet at
+    /// random.
+    pub momentum: f64,
+    pub contrarian: f64,
+    /// Share of the bettors who were referred by an affiliate.
+    pub affiliated: f64,
+}
+
+struct Bettor {
+    strategy: Strategy,
+    has_affiliate: bool,
+    streak: Streak,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub rounds: u64,
+    pub ties: u64,
+    /// Rounds where nobody bet on the winning side.
+    pub one_sided_rounds: u64,
+    pub bets: u64,
+    pub wagered: u128,
+    /// Stakes, winnings and streak jackpots paid back to bettors.
+    pub returned_to_players: u128,
+    pub platform_revenue: u128,
+    pub affiliate_payouts: u128,
+    /// What rounding and affiliate shares of rounds without affiliates leave in the vault.
+    pub unclaimed: u128,
+    pub initial_jackpot: u64,
+    pub final_jackpot: u64,
+    pub peak_jackpot: u64,
+    pub jackpot_inflows: u128,
+    /// Number of streak jackpots paid out and their total, by streak length from 5 to 10.
+    pub streak_payouts: [u64; 6],
+    pub streak_payout_amounts: [u128; 6],
+}
+
+impl Report {
+    /// Returned to players over wagered.
+    pub fn rtp(&self) -> f64 {
+        ratio(self.returned_to_players, self.wagered)
+    }
+
+    /// Platform revenue over wagered.
+    pub fn platform_margin(&self) -> f64 {
+        ratio(self.platform_revenue, self.wagered)
+    }
+}
+
+fn ratio(numerator: u128, denominator: u128) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+
+    numerator as f64 / denominator as f64
+}
+
+pub struct Simulation<'a> {
+    config: &'a Config,
+    population: &'a Population,
+    rng: StdRng,
+    bettors: Vec<Bettor>,
+    jackpot_pool_amount: u64,
+    /// Whether the price rose in the previous round, if it moved.
+    last_move: Option<bool>,
+    report: Report,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(config: &'a Config, population: &'a Population, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let bettors = (0..population.bettors)
+            .map(|_| {
+                let draw = rng.gen::<f64>();
+                let strategy = if draw < population.momentum {
+                    Strategy::Momentum
+                } else if draw < population.momentum + population.contrarian {
+                    Strategy::Contrarian
+                } else {
+                    Strategy::Random
+                };
+
+                Bettor {
+                    strategy,
+                    has_affiliate: rng.gen_bool(population.affiliated),
+                    streak: Streak::default(),
+                }
+            })
+            .collect();
+
+        Self {
+            config,
+            population,
+            rng,
+            bettors,
+            jackpot_pool_amount: config.initial_jackpot,
+            last_move: None,
+            report: Report {
+                initial_jackpot: config.initial_jackpot,
+                final_jackpot: config.initial_jackpot,
+                peak_jackpot: config.initial_jackpot,
+                ..Report::default()
+            },
+        }
+    }
+
+    /// Plays a round between each pair of consecutive prices.
+    pub fn run(mut self, prices: &[u64]) -> Report {
+        for (round_index, window) in prices.windows(2).enumerate() {
+            self.play_round(round_index as u64, window[0], window[1]);
+        }
+
+        self.report.final_jackpot = self.jackpot_pool_amount;
+        self.report
+    }
+
+    fn side(&mut self, strategy: Strategy) -> bool {
+        match (strategy, self.last_move) {
+            (Strategy::Momentum, Some(has_risen)) => has_risen,
+            (Strategy::Contrarian, Some(has_risen)) => !has_risen,
+            _ => self.rng.gen_bool(0.5),
+        }
+    }
+
+    fn play_round(&mut self, round_index: u64, starting_price: u64, ending_price: u64) {
+        let config = self.config;
+        let allocation = &config.allocation;
+        let population = self.population;
+
+        let mut bets = Vec::new();
+        let (mut total_bet_amount_long, mut total_bet_amount_short) = (0u64, 0u64);
+        let (mut affiliates_for_long_positions, mut affiliates_for_short_positions) = (0, 0);
+        for bettor in 0..self.bettors.len() {
+            if !self.rng.gen_bool(population.participation) {
+                continue;
+            }
+            let amount = self.rng.gen_range(population.min_bet..=population.max_bet);
+            let is_long = self.side(self.bettors[bettor].strategy);
+            let has_affiliate = self.bettors[bettor].has_affiliate as u64;
+
+            if is_long {
+                total_bet_amount_long += amount;
+                affiliates_for_long_positions += has_affiliate;
+            } else {
+                total_bet_amount_short += amount;
+                affiliates_for_short_positions += has_affiliate;
+            }
+            bets.push((bettor, amount, is_long));
+        }
+
+        let strike_price = math::strike_price(starting_price, config.strike_offset);
+        let is_tie = ending_price == strike_price;
+        let have_longs_won = math::is_greater_than(&ending_price, &strike_price);
+        let (winning_side_amount, losing_side_amount, affiliates) = if have_longs_won {
+            (
+                total_bet_amount_long,
+                total_bet_amount_short,
+                affiliates_for_long_positions,
+            )
+        } else {
+            (
+                total_bet_amount_short,
+                total_bet_amount_long,
+                affiliates_for_short_positions,
+            )
+        };
+
+        let (jackpot_amount, platform_fee) =
+            math::settle(winning_side_amount, losing_side_amount, is_tie, allocation);
+        self.jackpot_pool_amount += jackpot_amount;
+        self.report.peak_jackpot = self.report.peak_jackpot.max(self.jackpot_pool_amount);
+
+        let round_amount = (total_bet_amount_long + total_bet_amount_short) as u128;
+        let mut paid_out = (jackpot_amount + platform_fee) as u128;
+
+        if !is_tie && winning_side_amount > 0 {
+            for &(bettor, amount, is_long) in &bets {
+                if is_long != have_longs_won {
+                    continue;
+                }
+
+                let winnings = math::winnings(
+                    amount,
+                    winning_side_amount,
+                    losing_side_amount,
+                    allocation.winners_share,
+                );
+                let jackpot_amount = self.bettors[bettor].streak.record_win(
+                    round_index,
+                    &config.jackpot_allocation,
+                    &mut self.jackpot_pool_amount,
+                );
+                if jackpot_amount > 0 {
+                    let streak = self.bettors[bettor].streak.times_won;
+                    // A 10 win streak resets the count to 0 once it pays out.
+                    let streak = if streak == 0 { *STREAKS.end() } else { streak };
+                    let index = (streak - STREAKS.start()) as usize;
+                    self.report.streak_payouts[index] += 1;
+                    self.report.streak_payout_amounts[index] += jackpot_amount as u128;
+                }
+                self.report.returned_to_players += (amount + winnings + jackpot_amount) as u128;
+                paid_out += (amount + winnings) as u128;
+
+                if self.bettors[bettor].has_affiliate {
+                    let affiliate_winnings = math::affiliate_winnings(
+                        affiliates,
+                        losing_side_amount,
+                        allocation.affiliate_share,
+                    ) as u128;
+                    self.report.affiliate_payouts += affiliate_winnings;
+                    paid_out += affiliate_winnings;
+                }
+            }
+        }
+
+        if ending_price != starting_price {
+            self.last_move = Some(ending_price > starting_price);
+        }
+
+        let report = &mut self.report;
+        report.rounds += 1;
+        report.ties += is_tie as u64;
+        report.one_sided_rounds += (!is_tie && winning_side_amount == 0 && !bets.is_empty()) as u64;
+        report.bets += bets.len() as u64;
+        report.wagered += round_amount;
+        report.platform_revenue += platform_fee as u128;
+        report.jackpot_inflows += jackpot_amount as u128;
+        report.unclaimed += round_amount - paid_out;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            allocation: Allocation {
+                winners_share: 4_500,
+                affiliate_share: 500,
+                jackpot_share: 4_000,
+                platform_share: 1_000,
+            },
+            jackpot_allocation: JackPotAllocation {
+                streak_5: 1_000,
+                streak_6: 1_500,
+                streak_7: 2_000,
+                streak_8: 2_500,
+                streak_9: 3_000,
+                streak_10: 10_000,
+            },
+            strike_offset: 0,
+            initial_jackpot: 1_000,
+        }
+    }
+
+    fn population() -> Population {
+        Population {
+            bettors: 20,
+            participation: 0.9,
+            min_bet: 10,
+            max_bet: 1_000,
+            momentum: 0.25,
+            contrarian: 0.25,
+            affiliated: 0.5,
+        }
+    }
+
+    /// A price series that rises and falls, with a flat stretch.
+    fn prices() -> Vec<u64> {
+        (0..200u64)
+            .map(|round| 1_000 + (round * 37 % 23) * (round % 5))
+            .collect()
+    }
+
+    #[test]
+    fn flat_prices_tie_every_round() {
+        let population = Population {
+            bettors: 4,
+            participation: 1.0,
+            min_bet: 100,
+            max_bet: 100,
+            ..population()
+        };
+
+        let report = Simulation::new(&config(), &population, 7).run(&[1_000; 3]);
+
+        assert_eq!(
+            report,
+            Report {
+                rounds: 2,
+                ties: 2,
+                bets: 8,
+                wagered: 800,
+                platform_revenue: 80,
+                initial_jackpot: 1_000,
+                final_jackpot: 1_720,
+                peak_jackpot: 1_720,
+                jackpot_inflows: 720,
+                ..Report::default()
+            }
+        );
+    }
+
+    #[test]
+    fn runs_with_a_fixed_seed_are_reproducible() {
+        let config = config();
+        let population = population();
+        let prices = prices();
+
+        let report = Simulation::new(&config, &population, 42).run(&prices);
+
+        assert_eq!(
+            report,
+            Simulation::new(&config, &population, 42).run(&prices)
+        );
+        assert_ne!(
+            report,
+            Simulation::new(&config, &population, 43).run(&prices)
+        );
+        assert_eq!(
+            report,
+            Report {
+                rounds: 199,
+                ties: 6,
+                bets: 3_563,
+                wagered: 1_795_057,
+                returned_to_players: 1_498_729,
+                platform_revenue: 93_194,
+                affiliate_payouts: 43_534,
+                unclaimed: 1_574,
+                initial_jackpot: 1_000,
+                final_jackpot: 159_026,
+                peak_jackpot: 220_994,
+                jackpot_inflows: 399_348,
+                streak_payouts: [13, 5, 2, 0, 0, 0],
+                streak_payout_amounts: [144_766, 64_062, 32_494, 0, 0, 0],
+                ..Report::default()
+            }
+        );
+    }
+
+    #[test]
+    fn every_unit_wagered_is_accounted_for() {
+        let report = Simulation::new(&config(), &population(), 42).run(&prices());
+        let streak_payouts = report.streak_payout_amounts.iter().sum::<u128>();
+
+        assert_eq!(
+            report.wagered,
+            report.returned_to_players - streak_payouts
+                + report.platform_revenue
+                + report.affiliate_payouts
+                + report.jackpot_inflows
+                + report.unclaimed
+        );
+        assert_eq!(
+            report.final_jackpot as u128,
+            report.initial_jackpot as u128 + report.jackpot_inflows - streak_payouts
+        );
+    }
+}
@@ -17,6 +17,33 @@ pub mod seeds {
 
     #[constant]
     pub const USER_BET: &[u8] = b"user_bet";
+
+    #[constant]
+    pub const SESSION: &[u8] = b"session";
+
+    #[constant]
+    pub const ORDER: &[u8] = b"order";
+
+    #[constant]
+    pub const ATTESTATION: &[u8] = b"attestation";
+
+    #[constant]
+    pub const BLOCKED_USER: &[u8] = b"blocked_user";
+
+    #[constant]
+    pub const MINT_MARKET: &[u8] = b"mint_market";
+
+    #[constant]
+    pub const HOUSE_POOL: &[u8] = b"house_pool";
+
+    #[constant]
+    pub const LP_MINT: &[u8] = b"lp_mint";
+
+    #[constant]
+    pub const FIXED_ODDS_BET: &[u8] = b"fixed_odds_bet";
+
+    #[constant]
+    pub const BUCKET_BET: &[u8] = b"bucket_bet";
 }
 
 pub mod general {
@@ -25,5 +52,7 @@ pub mod general {
     #[constant]
     pub const BPS: u16 = 10_000;
 
+    pub const MAX_BUCKETS: usize = 8;
+
     pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 }
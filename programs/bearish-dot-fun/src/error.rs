@@ -38,4 +38,98 @@ pub enum ErrorCodes {
     ClaimAmountZero,
     #[msg("Platform fee amount to collect is 0.")]
     PlatformFeeAmountZero,
+    #[msg("Bet amount cannot be 0.")]
+    BetAmountZero,
+    #[msg("Cannot bet on both sides of a round.")]
+    CannotBetOnBothSides,
+    #[msg("Cancellation window has elapsed.")]
+    CancellationWindowElapsed,
+    #[msg("Cancel amount cannot be 0.")]
+    CancelAmountZero,
+    #[msg("Cancel amount exceeds bet amount.")]
+    CancelAmountExceedsBetAmount,
+    #[msg("Invalid payout token account.")]
+    InvalidPayoutTokenAccount,
+    #[msg("Missing accounts required to pay out winnings.")]
+    MissingPayoutAccounts,
+    #[msg("User has no repeat bet standing order.")]
+    NoRepeatBet,
+    #[msg("Not enough lamports escrowed for the repeat bet's rent.")]
+    RepeatBetRentNotEscrowed,
+    #[msg("Insufficient balance.")]
+    InsufficientBalance,
+    #[msg("Invalid delegate address.")]
+    InvalidDelegate,
+    #[msg("Session has expired.")]
+    SessionExpired,
+    #[msg("Session does not permit this instruction.")]
+    SessionPermissionDenied,
+    #[msg("Session spend cap exceeded.")]
+    SessionSpendCapExceeded,
+    #[msg("A valid session is required for delegated calls.")]
+    SessionRequired,
+    #[msg("Invalid order condition.")]
+    InvalidOrderCondition,
+    #[msg("Order condition not met.")]
+    OrderConditionNotMet,
+    #[msg("Bet amount cannot be more than the maximum bet amount.")]
+    BetAmountAboveMaxBetAmount,
+    #[msg("Exceeds max bet amount per user per round.")]
+    UserRoundExposureExceeded,
+    #[msg("Exceeds max round imbalance.")]
+    RoundImbalanceExceeded,
+    #[msg("Max round imbalance must be 0 or at least 100%.")]
+    InvalidMaxRoundImbalance,
+    #[msg("Max one-sided amount must be set along with the max round imbalance.")]
+    InvalidMaxOneSidedAmount,
+    #[msg("User is blocked.")]
+    UserBlocked,
+    #[msg("Invalid attestation.")]
+    InvalidAttestation,
+    #[msg("Attestation has been revoked.")]
+    AttestationRevoked,
+    #[msg("Attestation has expired.")]
+    AttestationExpired,
+    #[msg("Gatekeeper cannot be default pubkey.")]
+    GatekeeperDefaultPubkey,
+    #[msg("Mint market is disabled.")]
+    MintMarketDisabled,
+    #[msg("Mint is already the platform stablecoin.")]
+    MintIsPlatformStablecoin,
+    #[msg("Mint has an unsupported extension.")]
+    UnsupportedMintExtension,
+    #[msg("Mint market does not hold native SOL.")]
+    MintMarketNotNative,
+    #[msg("Max exposure cannot exceed 100%.")]
+    InvalidMaxExposure,
+    #[msg("House pool has no liquidity left.")]
+    HousePoolDepleted,
+    #[msg("Liquidity amount cannot be 0.")]
+    LiquidityAmountZero,
+    #[msg("Share amount cannot be 0.")]
+    SharesAmountZero,
+    #[msg("Fixed-odds betting is disabled.")]
+    FixedOddsDisabled,
+    #[msg("Invalid fixed-odds configuration.")]
+    InvalidFixedOddsConfig,
+    #[msg("House liquidity cannot cover this.")]
+    InsufficientHouseLiquidity,
+    #[msg("House pool is required.")]
+    HousePoolRequired,
+    #[msg("Bucket boundaries must be increasing and at most 7.")]
+    InvalidBucketBoundaries,
+    #[msg("Invalid bucket.")]
+    InvalidBucket,
+    #[msg("Cannot bet on multiple buckets of a round.")]
+    CannotBetOnMultipleBuckets,
+    #[msg("Strike offset must be above -100% and at most 100%.")]
+    InvalidStrikeOffset,
+    #[msg("Signer is not the owner or strike authority.")]
+    NotStrikeAuthority,
+    #[msg("A strike price override is pending.")]
+    StrikeOverridePending,
+    #[msg("Invalid attestation or blocklist account.")]
+    InvalidAccessAccount,
+    #[msg("Orders can only be executed in their target round.")]
+    NotOrderTargetRound,
 }
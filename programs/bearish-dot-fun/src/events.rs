@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{Allocation, GlobalRoundInfo, JackPotAllocation};
+use crate::{
+    Allocation, GlobalRoundInfo, JackPotAllocation, OrderCondition, RoundOutcome,
+    SessionPermissions, WinningsPreference,
+};
 
 #[event]
 pub struct Initialized {
@@ -46,6 +49,120 @@ pub struct StalenessThresholdSet {
     pub staleness_threshold: u64,
 }
 
+#[event]
+pub struct CancellationWindowSet {
+    pub cancellation_window: u64,
+}
+
+#[event]
+pub struct CancellationFeeSet {
+    pub cancellation_fee: u16,
+}
+
+#[event]
+pub struct MaxBetAmountSet {
+    pub max_bet_amount: u64,
+}
+
+#[event]
+pub struct MaxUserBetAmountPerRoundSet {
+    pub max_user_bet_amount_per_round: u64,
+}
+
+#[event]
+pub struct MaxRoundImbalanceSet {
+    pub max_round_imbalance: u64,
+    pub max_one_sided_amount: u64,
+}
+
+#[event]
+pub struct BucketBoundariesSet {
+    pub bucket_boundaries: Vec<i32>,
+}
+
+#[event]
+pub struct StrikeOffsetSet {
+    pub strike_offset: i32,
+}
+
+#[event]
+pub struct StrikeAuthoritySet {
+    pub strike_authority: Pubkey,
+}
+
+#[event]
+pub struct StrikeOverridePendingSet {
+    pub is_pending: bool,
+}
+
+#[event]
+pub struct GatingSet {
+    pub is_gated: bool,
+    pub gatekeeper: Pubkey,
+}
+
+#[event]
+pub struct HousePoolInitialized {
+    pub lp_mint: Pubkey,
+    pub max_exposure: u16,
+    pub platform_fee_share: u16,
+}
+
+#[event]
+pub struct HousePoolConfigSet {
+    pub max_exposure: u16,
+    pub platform_fee_share: u16,
+}
+
+#[event]
+pub struct FixedOddsConfigSet {
+    pub base_multiplier: u64,
+    pub skew: u16,
+}
+
+#[event]
+pub struct UserBlocked {
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct UserUnblocked {
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct AttestationIssued {
+    pub user: Pubkey,
+    pub gatekeeper: Pubkey,
+    pub expires_at: u64,
+}
+
+#[event]
+pub struct AttestationRevoked {
+    pub user: Pubkey,
+    pub gatekeeper: Pubkey,
+}
+
+#[event]
+pub struct MintWhitelisted {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct MintMarketEnabledSet {
+    pub mint: Pubkey,
+    pub is_enabled: bool,
+}
+
+#[event]
+pub struct MintMarketBetLimitsSet {
+    pub mint: Pubkey,
+    pub min_bet_amount: u64,
+    pub max_bet_amount: u64,
+    pub max_user_bet_amount_per_round: u64,
+    pub max_one_sided_amount: u64,
+}
+
 #[event]
 pub struct Deposited {
     pub user: Pubkey,
@@ -60,20 +177,122 @@ pub struct Withdrawn {
     pub amount: u64,
 }
 
+#[event]
+pub struct LiquidityDeposited {
+    pub liquidity_provider: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
+#[event]
+pub struct LiquidityWithdrawn {
+    pub liquidity_provider: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
 #[event]
 pub struct AffiliateSet {
     pub user: Pubkey,
     pub affiliate: Pubkey,
 }
 
+#[event]
+pub struct WinningsPreferenceSet {
+    pub user: Pubkey,
+    pub winnings_preference: WinningsPreference,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: u64,
+    pub max_spend: u64,
+    pub permissions: SessionPermissions,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct OrderCreated {
+    pub user: Pubkey,
+    pub order_id: u64,
+    pub amount: u64,
+    pub is_long: bool,
+    pub condition: OrderCondition,
+    pub executor_fee: u64,
+    pub target_round: u64,
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub user: Pubkey,
+    pub order_id: u64,
+}
+
+#[event]
+pub struct OrderExecuted {
+    pub user: Pubkey,
+    pub order_id: u64,
+    pub round: u64,
+    pub executor: Pubkey,
+    pub executor_fee: u64,
+}
+
 #[event]
 pub struct RoundStarted {
     pub round: u64,
     pub starting_price: u64,
+    pub strike_price: u64,
 }
 
+/// Everything `end_round` settled, so that rounds can be tracked from events alone. The jackpot
+/// and platform amounts include the round's bucket pools, and the platform amount excludes the
+/// house pool's share.
 #[event]
 pub struct RoundEnded {
+    pub round: u64,
+    pub starting_price: u64,
+    pub strike_price: u64,
+    pub ending_price: u64,
+    pub outcome: RoundOutcome,
+    pub total_bet_amount_long: u64,
+    pub total_bet_amount_short: u64,
+    pub long_positions: u64,
+    pub short_positions: u64,
+    pub jackpot_amount: u64,
+    pub platform_fee: u64,
+    pub winners_pool: u64,
+    pub affiliate_pool: u64,
+    pub jackpot_pool_amount: u64,
+}
+
+#[event]
+pub struct BucketsSettled {
+    pub round: u64,
+    pub winning_bucket: u8,
+    pub jackpot_amount: u64,
+    pub platform_fee: u64,
+}
+
+#[event]
+pub struct HouseBetSettled {
+    pub round: u64,
+    pub amount: u64,
+    pub is_long: bool,
+    pub payout: u64,
+    pub lp_fee: u64,
+    pub fixed_odds_payout: u64,
+}
+
+#[event]
+pub struct MintRoundSettled {
+    pub mint: Pubkey,
     pub round: u64,
     pub ending_price: u64,
 }
@@ -85,6 +304,35 @@ pub struct BetPlaced {
     pub amount: u64,
     pub is_long: bool,
     pub affiliate: Pubkey,
+    pub total_amount: u64,
+}
+
+#[event]
+pub struct BucketBetPlaced {
+    pub user: Pubkey,
+    pub round: u64,
+    pub amount: u64,
+    pub bucket: u8,
+    pub total_amount: u64,
+}
+
+#[event]
+pub struct FixedOddsBetPlaced {
+    pub user: Pubkey,
+    pub round: u64,
+    pub amount: u64,
+    pub is_long: bool,
+    pub multiplier: u64,
+}
+
+#[event]
+pub struct BetCancelled {
+    pub user: Pubkey,
+    pub round: u64,
+    pub amount: u64,
+    pub fee: u64,
+    pub is_long: bool,
+    pub remaining_amount: u64,
 }
 
 #[event]
@@ -95,6 +343,21 @@ pub struct WinningsClaimed {
     pub amount: u64,
 }
 
+#[event]
+pub struct BucketWinningsClaimed {
+    pub user: Pubkey,
+    pub round_index: u64,
+    pub bucket: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FixedOddsWinningsClaimed {
+    pub user: Pubkey,
+    pub round_index: u64,
+    pub amount: u64,
+}
+
 #[event]
 pub struct AffiliateWinningsClaimed {
     pub affiliate: Pubkey,
@@ -107,3 +370,38 @@ pub struct CollectedPlatformFees {
     pub owner: Pubkey,
     pub amount: u64,
 }
+
+#[event]
+pub struct MintBetPlaced {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub round: u64,
+    pub amount: u64,
+    pub is_long: bool,
+    pub affiliate: Pubkey,
+    pub total_amount: u64,
+}
+
+#[event]
+pub struct MintWinningsClaimed {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub round_index: u64,
+    pub is_long: bool,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MintAffiliateWinningsClaimed {
+    pub affiliate: Pubkey,
+    pub mint: Pubkey,
+    pub round_index: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CollectedMintPlatformFees {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
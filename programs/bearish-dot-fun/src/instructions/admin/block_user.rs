@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, BlockedUser, PlatformConfig};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct BlockUser<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + BlockedUser::INIT_SPACE,
+        seeds = [
+            constants::seeds::BLOCKED_USER,
+            user.as_ref()
+        ],
+        bump,
+    )]
+    pub blocked_user: Account<'info, BlockedUser>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl BlockUser<'_> {
+    pub fn block_user(ctx: Context<BlockUser>, user: Pubkey) -> Result<()> {
+        let blocked_user = &mut ctx.accounts.blocked_user;
+
+        blocked_user.user = user;
+        blocked_user.bump = ctx.bumps.blocked_user;
+
+        emit!(events::UserBlocked { user });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{constants, events, HousePool, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct InitializeHousePool<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(address = platform_config.stablecoin)]
+    pub stablecoin: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + HousePool::INIT_SPACE,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump,
+    )]
+    pub house_pool: Account<'info, HousePool>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [constants::seeds::LP_MINT],
+        bump,
+        mint::decimals = stablecoin.decimals,
+        mint::authority = house_pool,
+        mint::token_program = token_program,
+    )]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SetHousePoolConfig<'info> {
+    #[account(address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Account<'info, HousePool>,
+}
+
+impl InitializeHousePool<'_> {
+    pub fn initialize_house_pool(
+        ctx: Context<InitializeHousePool>,
+        max_exposure: u16,
+        platform_fee_share: u16,
+    ) -> Result<()> {
+        let house_pool = &mut ctx.accounts.house_pool;
+
+        house_pool.lp_mint = ctx.accounts.lp_mint.key();
+        house_pool.max_exposure = max_exposure;
+        house_pool.platform_fee_share = platform_fee_share;
+        house_pool.bump = ctx.bumps.house_pool;
+        house_pool.lp_mint_bump = ctx.bumps.lp_mint;
+
+        house_pool.validate_max_exposure()?;
+        house_pool.validate_platform_fee_share()?;
+
+        ctx.accounts.platform_config.has_house_pool = true;
+
+        emit!(events::HousePoolInitialized {
+            lp_mint: house_pool.lp_mint,
+            max_exposure,
+            platform_fee_share
+        });
+
+        Ok(())
+    }
+}
+
+impl SetHousePoolConfig<'_> {
+    pub fn set_house_pool_config(
+        ctx: Context<SetHousePoolConfig>,
+        max_exposure: u16,
+        platform_fee_share: u16,
+    ) -> Result<()> {
+        let house_pool = &mut ctx.accounts.house_pool;
+
+        house_pool.max_exposure = max_exposure;
+        house_pool.platform_fee_share = platform_fee_share;
+
+        house_pool.validate_max_exposure()?;
+        house_pool.validate_platform_fee_share()?;
+
+        emit!(events::HousePoolConfigSet {
+            max_exposure,
+            platform_fee_share
+        });
+
+        Ok(())
+    }
+
+    pub fn set_fixed_odds_config(
+        ctx: Context<SetHousePoolConfig>,
+        base_multiplier: u64,
+        skew: u16,
+    ) -> Result<()> {
+        let house_pool = &mut ctx.accounts.house_pool;
+
+        house_pool.fixed_odds_base_multiplier = base_multiplier;
+        house_pool.fixed_odds_skew = skew;
+
+        house_pool.validate_fixed_odds_config()?;
+
+        emit!(events::FixedOddsConfigSet {
+            base_multiplier,
+            skew
+        });
+
+        Ok(())
+    }
+}
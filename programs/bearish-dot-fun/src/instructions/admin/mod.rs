@@ -1,7 +1,23 @@
+pub mod block_user;
+pub mod initialize_house_pool;
 pub mod set_platform_config;
+pub mod start_round_with_strike;
 pub mod transfer_ownership;
+pub mod unblock_user;
+pub mod whitelist_mint;
+pub mod whitelist_native_sol;
+pub mod withdraw_mint_platform_fees;
+pub mod withdraw_native_platform_fees;
 pub mod withdraw_platform_fees;
 
+pub use block_user::*;
+pub use initialize_house_pool::*;
 pub use set_platform_config::*;
+pub use start_round_with_strike::*;
 pub use transfer_ownership::*;
+pub use unblock_user::*;
+pub use whitelist_mint::*;
+pub use whitelist_native_sol::*;
+pub use withdraw_mint_platform_fees::*;
+pub use withdraw_native_platform_fees::*;
 pub use withdraw_platform_fees::*;
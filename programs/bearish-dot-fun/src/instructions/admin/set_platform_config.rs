@@ -16,6 +16,7 @@ pub struct SetPlatformConfig<'info> {
 }
 
 impl SetPlatformConfig<'_> {
+    #[allow(clippy::redundant_field_names)]
     pub fn set_duration(ctx: Context<SetPlatformConfig>, duration: u64) -> Result<()> {
         let platform_config = &mut ctx.accounts.platform_config;
 
@@ -28,6 +29,7 @@ impl SetPlatformConfig<'_> {
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn set_allocation(ctx: Context<SetPlatformConfig>, allocation: Allocation) -> Result<()> {
         let platform_config = &mut ctx.accounts.platform_config;
 
@@ -42,6 +44,7 @@ impl SetPlatformConfig<'_> {
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn set_jackpot_allocation(
         ctx: Context<SetPlatformConfig>,
         jackpot_allocation: JackPotAllocation,
@@ -59,6 +62,7 @@ impl SetPlatformConfig<'_> {
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn set_min_bet_amount(ctx: Context<SetPlatformConfig>, min_bet_amount: u64) -> Result<()> {
         ctx.accounts
             .platform_config
@@ -72,6 +76,7 @@ impl SetPlatformConfig<'_> {
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn set_price_account(ctx: Context<SetPlatformConfig>, price_account: Pubkey) -> Result<()> {
         let platform_config = &mut ctx.accounts.platform_config;
 
@@ -86,6 +91,7 @@ impl SetPlatformConfig<'_> {
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn set_staleness_threshold(
         ctx: Context<SetPlatformConfig>,
         staleness_threshold: u64,
@@ -101,4 +107,140 @@ impl SetPlatformConfig<'_> {
 
         Ok(())
     }
+
+    pub fn set_cancellation_window(
+        ctx: Context<SetPlatformConfig>,
+        cancellation_window: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .platform_config
+            .global_round_info
+            .cancellation_window = cancellation_window;
+
+        emit!(events::CancellationWindowSet {
+            cancellation_window
+        });
+
+        Ok(())
+    }
+
+    pub fn set_cancellation_fee(
+        ctx: Context<SetPlatformConfig>,
+        cancellation_fee: u16,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.global_round_info.cancellation_fee = cancellation_fee;
+
+        platform_config.validate_cancellation_fee()?;
+
+        emit!(events::CancellationFeeSet { cancellation_fee });
+
+        Ok(())
+    }
+
+    pub fn set_gating(
+        ctx: Context<SetPlatformConfig>,
+        is_gated: bool,
+        gatekeeper: Pubkey,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.is_gated = is_gated;
+        platform_config.gatekeeper = gatekeeper;
+
+        platform_config.validate_gatekeeper()?;
+
+        emit!(events::GatingSet {
+            is_gated,
+            gatekeeper
+        });
+
+        Ok(())
+    }
+
+    pub fn set_max_bet_amount(ctx: Context<SetPlatformConfig>, max_bet_amount: u64) -> Result<()> {
+        ctx.accounts
+            .platform_config
+            .global_round_info
+            .max_bet_amount = max_bet_amount;
+
+        emit!(events::MaxBetAmountSet { max_bet_amount });
+
+        Ok(())
+    }
+
+    pub fn set_max_user_bet_amount_per_round(
+        ctx: Context<SetPlatformConfig>,
+        max_user_bet_amount_per_round: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .platform_config
+            .global_round_info
+            .max_user_bet_amount_per_round = max_user_bet_amount_per_round;
+
+        emit!(events::MaxUserBetAmountPerRoundSet {
+            max_user_bet_amount_per_round
+        });
+
+        Ok(())
+    }
+
+    pub fn set_bucket_boundaries(
+        ctx: Context<SetPlatformConfig>,
+        bucket_boundaries: Vec<i32>,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.bucket_boundaries = bucket_boundaries.clone();
+
+        platform_config.validate_bucket_boundaries()?;
+
+        emit!(events::BucketBoundariesSet { bucket_boundaries });
+
+        Ok(())
+    }
+
+    pub fn set_strike_offset(ctx: Context<SetPlatformConfig>, strike_offset: i32) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.strike_offset = strike_offset;
+
+        platform_config.validate_strike_offset()?;
+
+        emit!(events::StrikeOffsetSet { strike_offset });
+
+        Ok(())
+    }
+
+    pub fn set_strike_authority(
+        ctx: Context<SetPlatformConfig>,
+        strike_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.platform_config.strike_authority = strike_authority;
+
+        emit!(events::StrikeAuthoritySet { strike_authority });
+
+        Ok(())
+    }
+
+    pub fn set_max_round_imbalance(
+        ctx: Context<SetPlatformConfig>,
+        max_round_imbalance: u64,
+        max_one_sided_amount: u64,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.global_round_info.max_round_imbalance = max_round_imbalance;
+        platform_config.global_round_info.max_one_sided_amount = max_one_sided_amount;
+
+        platform_config.validate_max_round_imbalance()?;
+
+        emit!(events::MaxRoundImbalanceSet {
+            max_round_imbalance,
+            max_one_sided_amount
+        });
+
+        Ok(())
+    }
 }
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, utils, PlatformConfig, Round};
+
+#[derive(Accounts)]
+pub struct StartRoundWithStrike<'info> {
+    /// The owner or the strike authority.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Round::INIT_SPACE,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    /// CHECK: The pyth price account to fetch the latest price from.
+    #[account(address = platform_config.global_round_info.price_account)]
+    pub price_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetStrikeOverridePending<'info> {
+    /// The owner or the strike authority.
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl StartRoundWithStrike<'_> {
+    /// Starts the next round like `start_round`, but with a strike price picked by the owner or
+    /// strike authority instead of one derived from the starting price. Clears a pending strike
+    /// override.
+    pub fn start_round_with_strike(
+        ctx: Context<StartRoundWithStrike>,
+        strike_price: u64,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+        let round = &mut ctx.accounts.round;
+
+        platform_config.validate_strike_authority(ctx.accounts.authority.key)?;
+
+        let price = utils::general::get_price(
+            &ctx.accounts.price_account,
+            platform_config.global_round_info.staleness_threshold,
+        );
+        round.start(price, Some(strike_price), platform_config, ctx.bumps.round)?;
+        platform_config.is_strike_override_pending = false;
+
+        emit!(events::RoundStarted {
+            round: platform_config.global_round_info.round + 1,
+            starting_price: price,
+            strike_price
+        });
+
+        Ok(())
+    }
+}
+
+impl SetStrikeOverridePending<'_> {
+    /// Holds off `start_round` until the next round is started with `start_round_with_strike`,
+    /// or releases it again.
+    pub fn set_strike_override_pending(
+        ctx: Context<SetStrikeOverridePending>,
+        is_pending: bool,
+    ) -> Result<()> {
+        let platform_config = &mut ctx.accounts.platform_config;
+
+        platform_config.validate_strike_authority(ctx.accounts.authority.key)?;
+
+        platform_config.is_strike_override_pending = is_pending;
+
+        emit!(events::StrikeOverridePendingSet { is_pending });
+
+        Ok(())
+    }
+}
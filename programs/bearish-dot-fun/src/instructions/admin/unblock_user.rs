@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, BlockedUser, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct UnblockUser<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            constants::seeds::BLOCKED_USER,
+            blocked_user.user.as_ref()
+        ],
+        bump = blocked_user.bump,
+    )]
+    pub blocked_user: Account<'info, BlockedUser>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl UnblockUser<'_> {
+    pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+        emit!(events::UserUnblocked {
+            user: ctx.accounts.blocked_user.user
+        });
+
+        Ok(())
+    }
+}
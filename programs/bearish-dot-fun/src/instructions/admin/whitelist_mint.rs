@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants, error, events, utils, AllowedMintExtensions, MintMarket, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct WhitelistMint<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account()]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + MintMarket::INIT_SPACE,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            mint.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = mint_vault
+    )]
+    pub mint_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SetMintMarket<'info> {
+    #[account(address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+}
+
+impl WhitelistMint<'_> {
+    pub fn whitelist_mint(
+        ctx: Context<WhitelistMint>,
+        allowed_mint_extensions: AllowedMintExtensions,
+    ) -> Result<()> {
+        let mint = ctx.accounts.mint.key();
+        let mint_market = &mut ctx.accounts.mint_market;
+
+        require!(
+            mint != ctx.accounts.platform_config.stablecoin,
+            error::ErrorCodes::MintIsPlatformStablecoin
+        );
+        utils::token::validate_mint_extensions(
+            &ctx.accounts.mint.to_account_info(),
+            &allowed_mint_extensions,
+        )?;
+
+        mint_market.mint = mint;
+        mint_market.is_enabled = true;
+        mint_market.bump = ctx.bumps.mint_market;
+        mint_market.vault_bump = ctx.bumps.mint_vault;
+
+        emit!(events::MintWhitelisted { mint });
+
+        Ok(())
+    }
+}
+
+impl SetMintMarket<'_> {
+    pub fn set_mint_market_enabled(ctx: Context<SetMintMarket>, is_enabled: bool) -> Result<()> {
+        let mint_market = &mut ctx.accounts.mint_market;
+
+        mint_market.is_enabled = is_enabled;
+
+        emit!(events::MintMarketEnabledSet {
+            mint: mint_market.mint,
+            is_enabled
+        });
+
+        Ok(())
+    }
+
+    pub fn set_mint_market_bet_limits(
+        ctx: Context<SetMintMarket>,
+        min_bet_amount: u64,
+        max_bet_amount: u64,
+        max_user_bet_amount_per_round: u64,
+        max_one_sided_amount: u64,
+    ) -> Result<()> {
+        let mint_market = &mut ctx.accounts.mint_market;
+
+        mint_market.min_bet_amount = min_bet_amount;
+        mint_market.max_bet_amount = max_bet_amount;
+        mint_market.max_user_bet_amount_per_round = max_user_bet_amount_per_round;
+        mint_market.max_one_sided_amount = max_one_sided_amount;
+
+        emit!(events::MintMarketBetLimitsSet {
+            mint: mint_market.mint,
+            min_bet_amount,
+            max_bet_amount,
+            max_user_bet_amount_per_round,
+            max_one_sided_amount
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token::spl_token;
+
+use crate::{constants, events, MintMarket, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct WhitelistNativeSol<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + MintMarket::INIT_SPACE,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump,
+    )]
+    pub native_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl WhitelistNativeSol<'_> {
+    pub fn whitelist_native_sol(ctx: Context<WhitelistNativeSol>) -> Result<()> {
+        let mint_market = &mut ctx.accounts.mint_market;
+        let native_vault = &ctx.accounts.native_vault;
+
+        mint_market.mint = spl_token::native_mint::ID;
+        mint_market.is_enabled = true;
+        mint_market.is_native = true;
+        mint_market.bump = ctx.bumps.mint_market;
+        mint_market.vault_bump = ctx.bumps.native_vault;
+
+        // The vault is topped up to the rent exempt minimum so that user balances can always be
+        // withdrawn in full.
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        if native_vault.lamports() < rent_exempt_minimum {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: native_vault.to_account_info(),
+                    },
+                ),
+                rent_exempt_minimum - native_vault.lamports(),
+            )?;
+        }
+
+        emit!(events::MintWhitelisted {
+            mint: spl_token::native_mint::ID
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{constants, error, events, MintMarket, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct WithdrawMintPlatformFees<'info> {
+    #[account(address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(address = mint_market.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.vault_bump,
+        token::mint = mint,
+        token::authority = mint_vault
+    )]
+    pub mint_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl WithdrawMintPlatformFees<'_> {
+    pub fn withdraw_mint_platform_fees(ctx: Context<WithdrawMintPlatformFees>) -> Result<()> {
+        let mint = &ctx.accounts.mint;
+        let mint_market = &mut ctx.accounts.mint_market;
+        let mint_vault = &mut ctx.accounts.mint_vault;
+
+        let accumulated_platform_fees = mint_market.accumulated_platform_fees;
+        require!(
+            accumulated_platform_fees > 0,
+            error::ErrorCodes::PlatformFeeAmountZero
+        );
+
+        mint_market.accumulated_platform_fees = 0;
+
+        let mint_key = mint.key();
+        let mint_vault_bump = &[mint_market.vault_bump];
+        let mint_vault_signer = &[&[
+            constants::seeds::PLATFORM_VAULT,
+            mint_key.as_ref(),
+            mint_vault_bump,
+        ][..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: mint_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: mint_vault.to_account_info(),
+                },
+                mint_vault_signer,
+            ),
+            accumulated_platform_fees,
+            mint.decimals,
+        )?;
+
+        emit!(events::CollectedMintPlatformFees {
+            owner: ctx.accounts.owner.key(),
+            mint: mint_key,
+            amount: accumulated_platform_fees
+        });
+
+        Ok(())
+    }
+}
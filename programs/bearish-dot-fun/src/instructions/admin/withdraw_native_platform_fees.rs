@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+
+use crate::{constants, error, events, utils, MintMarket, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct WithdrawNativePlatformFees<'info> {
+    #[account(mut, address = platform_config.owner)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.vault_bump,
+    )]
+    pub native_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl WithdrawNativePlatformFees<'_> {
+    pub fn withdraw_native_platform_fees(ctx: Context<WithdrawNativePlatformFees>) -> Result<()> {
+        let mint_market = &mut ctx.accounts.mint_market;
+
+        mint_market.validate_native()?;
+
+        let accumulated_platform_fees = mint_market.accumulated_platform_fees;
+        require!(
+            accumulated_platform_fees > 0,
+            error::ErrorCodes::PlatformFeeAmountZero
+        );
+
+        mint_market.accumulated_platform_fees = 0;
+
+        utils::native::transfer_from_native_vault(
+            &ctx.accounts.native_vault.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            mint_market.vault_bump,
+            accumulated_platform_fees,
+        )?;
+
+        emit!(events::CollectedMintPlatformFees {
+            owner: ctx.accounts.owner.key(),
+            mint: spl_token::native_mint::ID,
+            amount: accumulated_platform_fees
+        });
+
+        Ok(())
+    }
+}
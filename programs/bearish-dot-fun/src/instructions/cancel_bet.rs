@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, utils, Bet, HousePool, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct CancelBet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = user_bet.bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    /// Required once the house pool is enabled, to move the house's side of the round with the cancellation.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CancelBet<'_> {
+    pub fn cancel_bet(ctx: Context<CancelBet>, amount: u64) -> Result<()> {
+        ctx.accounts
+            .platform_config
+            .validate_house_pool(ctx.accounts.house_pool.is_some())?;
+
+        let global_round_info = &mut ctx.accounts.platform_config.global_round_info;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &mut ctx.accounts.round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        require!(amount > 0, error::ErrorCodes::CancelAmountZero);
+        require!(
+            amount <= user_bet.amount,
+            error::ErrorCodes::CancelAmountExceedsBetAmount
+        );
+        require!(
+            round.ending_price == 0,
+            error::ErrorCodes::RoundAlreadyEnded
+        );
+        round.validate_cancellation_window(global_round_info.cancellation_window)?;
+
+        let fee = u64::try_from(utils::math::mul_div_down(
+            &(amount as u128),
+            &(global_round_info.cancellation_fee as u128),
+            &(constants::general::BPS as u128),
+        ))
+        .unwrap();
+
+        global_round_info.jackpot_pool_amount += fee;
+        user_info.amount += amount - fee;
+        user_bet.amount -= amount;
+
+        let is_position_closed = user_bet.amount == 0;
+        let bet_limits = global_round_info.bet_limits();
+        HousePool::rebalance(ctx.accounts.house_pool.as_deref_mut(), round, |round| {
+            if user_bet.is_long {
+                round.total_bet_amount_long -= amount;
+
+                if is_position_closed {
+                    round.long_positions -= 1;
+
+                    if user_bet.affiliate != Pubkey::default() {
+                        round.affiliates_for_long_positions -= 1;
+                    }
+                }
+            } else {
+                round.total_bet_amount_short -= amount;
+
+                if is_position_closed {
+                    round.short_positions -= 1;
+
+                    if user_bet.affiliate != Pubkey::default() {
+                        round.affiliates_for_short_positions -= 1;
+                    }
+                }
+            }
+
+            // Shrinking one side must not leave the other side above the imbalance limit.
+            round.validate_imbalance(!user_bet.is_long, &bet_limits)
+        })?;
+
+        // A partially cancelled bet must still satisfy the minimum bet amount.
+        if !is_position_closed {
+            bet_limits.validate_amount(user_bet.amount)?;
+        }
+
+        emit!(events::BetCancelled {
+            user: ctx.accounts.user.key(),
+            round: global_round_info.round + 1,
+            amount,
+            fee,
+            is_long: user_bet.is_long,
+            remaining_amount: user_bet.amount,
+        });
+
+        if is_position_closed {
+            user_bet.close(ctx.accounts.user.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
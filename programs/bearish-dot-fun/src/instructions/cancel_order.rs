@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, Order, UserInfo};
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            constants::seeds::ORDER,
+            user.key().as_ref(),
+            order.order_id.to_be_bytes().as_ref()
+        ],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, Order>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CancelOrder<'_> {
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        let order = &ctx.accounts.order;
+
+        ctx.accounts.user_info.amount += order.amount + order.executor_fee;
+
+        emit!(events::OrderCancelled {
+            user: ctx.accounts.user.key(),
+            order_id: order.order_id,
+        });
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{
     transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
-use crate::{constants, error, events, utils, Bet, PlatformConfig, Round};
+use crate::{constants, error, events, Bet, PlatformConfig, Round};
 
 #[derive(Accounts)]
 #[instruction(round_index: u64)]
@@ -65,11 +65,11 @@ pub struct ClaimAffiliateWinnings<'info> {
 }
 
 impl ClaimAffiliateWinnings<'_> {
+    #[allow(clippy::redundant_field_names)]
     pub fn claim_affiliate_winnings(
         ctx: Context<ClaimAffiliateWinnings>,
         round_index: u64,
     ) -> Result<()> {
-        let allocation = &ctx.accounts.platform_config.global_round_info.allocation;
         let platform_vault = &mut ctx.accounts.platform_vault;
         let stablecoin = &ctx.accounts.stablecoin;
         let round = &ctx.accounts.round;
@@ -84,42 +84,11 @@ impl ClaimAffiliateWinnings<'_> {
             error::ErrorCodes::AlreadyClaimedWinnings
         );
 
-        let have_longs_won =
-            utils::math::is_greater_than(&round.ending_price, &round.starting_price);
-        require!(
-            round.ending_price != round.starting_price
-                && ((have_longs_won && user_bet.is_long) || (!have_longs_won && !user_bet.is_long)),
-            error::ErrorCodes::IneligibleForClaim
-        );
+        round.validate_claim(user_bet)?;
 
         user_bet.has_affiliate_claimed_winnings = true;
 
-        let affiliate_pool_amount_to_claim_winnings_from: u64;
-        let amount: u64;
-        if have_longs_won {
-            affiliate_pool_amount_to_claim_winnings_from =
-                u64::try_from(utils::math::mul_div_down(
-                    &(round.total_bet_amount_short as u128),
-                    &(allocation.affiliate_share as u128),
-                    &(constants::general::BPS as u128),
-                ))
-                .unwrap();
-
-            amount = affiliate_pool_amount_to_claim_winnings_from
-                .checked_div(round.affiliates_for_long_positions)
-                .unwrap();
-        } else {
-            affiliate_pool_amount_to_claim_winnings_from =
-                u64::try_from(utils::math::mul_div_down(
-                    &(round.total_bet_amount_long as u128),
-                    &(allocation.affiliate_share as u128),
-                    &(constants::general::BPS as u128),
-                ))
-                .unwrap();
-            amount = affiliate_pool_amount_to_claim_winnings_from
-                .checked_div(round.affiliates_for_short_positions)
-                .unwrap();
-        }
+        let amount = round.affiliate_winnings(round.allocation.affiliate_share);
 
         require!(amount > 0, error::ErrorCodes::ClaimAmountZero);
 
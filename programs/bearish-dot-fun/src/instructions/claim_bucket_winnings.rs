@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, BucketBet, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct ClaimBucketWinnings<'info> {
+    #[account()]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::BUCKET_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = bucket_bet.bump,
+    )]
+    pub bucket_bet: Account<'info, BucketBet>,
+}
+
+impl ClaimBucketWinnings<'_> {
+    /// Credits the bet amount plus its share of the losing buckets' pools. Bucket bets have no
+    /// affiliates, so the affiliate share goes to the winners too.
+    pub fn claim_bucket_winnings(
+        ctx: Context<ClaimBucketWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &ctx.accounts.round;
+        let bucket_bet = &mut ctx.accounts.bucket_bet;
+
+        require!(
+            round.ending_price != 0,
+            error::ErrorCodes::RoundHasNotEndedYet
+        );
+        require!(
+            !bucket_bet.has_claimed_winnings,
+            error::ErrorCodes::AlreadyClaimedWinnings
+        );
+
+        round.validate_bucket_claim(bucket_bet)?;
+
+        bucket_bet.has_claimed_winnings = true;
+
+        let amount = round.bucket_winnings(
+            bucket_bet.amount,
+            round.allocation.winners_share + round.allocation.affiliate_share,
+        );
+
+        user_info.amount += bucket_bet.amount + amount;
+
+        emit!(events::BucketWinningsClaimed {
+            user: ctx.accounts.user.key(),
+            round_index,
+            bucket: bucket_bet.bucket,
+            amount
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, utils, Bet, Round, UserInfo};
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct ClaimFixedOddsWinnings<'info> {
+    #[account()]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::FIXED_ODDS_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = fixed_odds_bet.bump,
+    )]
+    pub fixed_odds_bet: Account<'info, Bet>,
+}
+
+impl ClaimFixedOddsWinnings<'_> {
+    /// Credits the stake times the locked-in multiplier. The house pool already set the payout
+    /// aside when the round ended. Fixed-odds wins do not count towards jackpot streaks.
+    pub fn claim_fixed_odds_winnings(
+        ctx: Context<ClaimFixedOddsWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &ctx.accounts.round;
+        let fixed_odds_bet = &mut ctx.accounts.fixed_odds_bet;
+
+        require!(
+            round.ending_price != 0,
+            error::ErrorCodes::RoundHasNotEndedYet
+        );
+        require!(
+            !fixed_odds_bet.has_claimed_winnings,
+            error::ErrorCodes::AlreadyClaimedWinnings
+        );
+
+        round.validate_claim(fixed_odds_bet)?;
+
+        fixed_odds_bet.has_claimed_winnings = true;
+
+        let amount = u64::try_from(utils::math::mul_div_down(
+            &(fixed_odds_bet.amount as u128),
+            &(fixed_odds_bet.multiplier as u128),
+            &(constants::general::BPS as u128),
+        ))
+        .unwrap();
+
+        user_info.amount += amount;
+
+        emit!(events::FixedOddsWinningsClaimed {
+            user: ctx.accounts.user.key(),
+            round_index,
+            amount
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{constants, error, events, Bet, MintMarket, PlatformConfig, Round};
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct ClaimMintAffiliateWinnings<'info> {
+    /// CHECK: The user who has placed a bet in the specified round.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(address = user_bet.affiliate)]
+    pub affiliate: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(address = mint_market.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.vault_bump,
+        token::mint = mint,
+        token::authority = mint_vault
+    )]
+    pub mint_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = mint_round.bump,
+    )]
+    pub mint_round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = user_bet.bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = affiliate,
+    )]
+    pub affiliate_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimMintAffiliateWinnings<'_> {
+    pub fn claim_mint_affiliate_winnings(
+        ctx: Context<ClaimMintAffiliateWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        let mint = &ctx.accounts.mint;
+        let mint_vault = &mut ctx.accounts.mint_vault;
+        let mint_round = &ctx.accounts.mint_round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        require!(
+            mint_round.ending_price != 0,
+            error::ErrorCodes::PriceCannotBeZero
+        );
+        require!(
+            !user_bet.has_affiliate_claimed_winnings,
+            error::ErrorCodes::AlreadyClaimedWinnings
+        );
+
+        mint_round.validate_claim(user_bet)?;
+
+        user_bet.has_affiliate_claimed_winnings = true;
+
+        let amount = mint_round.affiliate_winnings(mint_round.allocation.affiliate_share);
+
+        require!(amount > 0, error::ErrorCodes::ClaimAmountZero);
+
+        let mint_key = mint.key();
+        let mint_vault_bump = &[ctx.accounts.mint_market.vault_bump];
+        let mint_vault_signer = &[&[
+            constants::seeds::PLATFORM_VAULT,
+            mint_key.as_ref(),
+            mint_vault_bump,
+        ][..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: mint_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.affiliate_token_account.to_account_info(),
+                    authority: mint_vault.to_account_info(),
+                },
+                mint_vault_signer,
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        emit!(events::MintAffiliateWinningsClaimed {
+            affiliate: ctx.accounts.affiliate.key(),
+            mint: mint_key,
+            round_index,
+            amount
+        });
+
+        Ok(())
+    }
+}
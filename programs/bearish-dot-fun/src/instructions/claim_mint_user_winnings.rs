@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, Bet, MintMarket, PlatformConfig, Round, Session, UserInfo};
+
+/// Unlike `claim_user_winnings`, winnings are always credited to the user's balance in the mint.
+/// The user's `WinningsPreference` is kept on their stablecoin account and is denominated in the
+/// stablecoin: the payout token account of `WithdrawToWallet` can't hold this mint and the amount
+/// of `RepeatBet` is a stablecoin amount, so neither applies here.
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct ClaimMintUserWinnings<'info> {
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The user claiming winnings. Either the user themselves or a delegate with a valid
+    /// session must sign as the authority.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_round.bump,
+    )]
+    pub mint_round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump = user_bet.bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    #[account(
+        seeds = [
+            constants::seeds::SESSION,
+            user.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session.bump,
+    )]
+    pub session: Option<Account<'info, Session>>,
+}
+
+impl ClaimMintUserWinnings<'_> {
+    pub fn claim_mint_user_winnings(
+        ctx: Context<ClaimMintUserWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        if ctx.accounts.authority.key() != ctx.accounts.user.key() {
+            let Some(session) = ctx.accounts.session.as_ref() else {
+                return err!(error::ErrorCodes::SessionRequired);
+            };
+            session.validate_claim()?;
+        }
+
+        let global_round_info = &ctx.accounts.platform_config.global_round_info;
+        let mint_market = &mut ctx.accounts.mint_market;
+        let user_info = &mut ctx.accounts.user_info;
+        let mint_round = &ctx.accounts.mint_round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        mint_round.validate_claimable(user_bet)?;
+
+        user_bet.has_claimed_winnings = true;
+
+        let mut amount = mint_round.winnings(user_bet.amount, mint_round.allocation.winners_share);
+        amount += user_info.record_win(
+            round_index,
+            &global_round_info.jackpot_allocation,
+            &mut mint_market.jackpot_pool_amount,
+        );
+
+        user_info.amount += user_bet.amount + amount;
+
+        emit!(events::MintWinningsClaimed {
+            user: ctx.accounts.user.key(),
+            mint: mint_market.mint,
+            round_index,
+            is_long: user_bet.is_long,
+            amount
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+
+use crate::{constants, error, events, utils, Bet, MintMarket, PlatformConfig, Round};
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct ClaimNativeAffiliateWinnings<'info> {
+    /// CHECK: The user who has placed a bet in the specified round.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(mut, address = user_bet.affiliate)]
+    pub affiliate: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.vault_bump,
+    )]
+    pub native_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_round.bump,
+    )]
+    pub mint_round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = user_bet.bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ClaimNativeAffiliateWinnings<'_> {
+    pub fn claim_native_affiliate_winnings(
+        ctx: Context<ClaimNativeAffiliateWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        let mint_market = &ctx.accounts.mint_market;
+        let mint_round = &ctx.accounts.mint_round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        mint_market.validate_native()?;
+        require!(
+            mint_round.ending_price != 0,
+            error::ErrorCodes::PriceCannotBeZero
+        );
+        require!(
+            !user_bet.has_affiliate_claimed_winnings,
+            error::ErrorCodes::AlreadyClaimedWinnings
+        );
+
+        mint_round.validate_claim(user_bet)?;
+
+        user_bet.has_affiliate_claimed_winnings = true;
+
+        let amount = mint_round.affiliate_winnings(mint_round.allocation.affiliate_share);
+
+        require!(amount > 0, error::ErrorCodes::ClaimAmountZero);
+
+        utils::native::transfer_from_native_vault(
+            &ctx.accounts.native_vault.to_account_info(),
+            &ctx.accounts.affiliate.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            mint_market.vault_bump,
+            amount,
+        )?;
+
+        emit!(events::MintAffiliateWinningsClaimed {
+            affiliate: ctx.accounts.affiliate.key(),
+            mint: spl_token::native_mint::ID,
+            round_index,
+            amount
+        });
+
+        Ok(())
+    }
+}
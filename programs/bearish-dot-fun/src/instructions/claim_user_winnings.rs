@@ -1,12 +1,22 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
-use crate::{constants, error, events, utils, Bet, PlatformConfig, Round, UserInfo};
+use crate::{
+    constants, error, events, Bet, PlatformConfig, Round, Session, UserInfo, WinningsPreference,
+};
 
 #[derive(Accounts)]
 #[instruction(round_index: u64)]
 pub struct ClaimUserWinnings<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The user claiming winnings. Either the user themselves or a delegate with a valid
+    /// session must sign as the authority.
+    #[account()]
+    pub user: AccountInfo<'info>,
 
     #[account(
         mut,
@@ -45,99 +55,106 @@ pub struct ClaimUserWinnings<'info> {
     )]
     pub user_bet: Account<'info, Bet>,
 
+    #[account(
+        seeds = [
+            constants::seeds::SESSION,
+            user.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session.bump,
+    )]
+    pub session: Option<Account<'info, Session>>,
+
+    /// The following accounts are only required if the user has chosen to have their winnings
+    /// withdrawn to their wallet on claim.
+    #[account(address = platform_config.stablecoin)]
+    pub stablecoin: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_VAULT],
+        bump = platform_config.platform_vault_bump,
+    )]
+    pub platform_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payout_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl ClaimUserWinnings<'_> {
+    #[allow(clippy::redundant_field_names)]
     pub fn claim_user_winnings(ctx: Context<ClaimUserWinnings>, round_index: u64) -> Result<()> {
+        if ctx.accounts.authority.key() != ctx.accounts.user.key() {
+            let Some(session) = ctx.accounts.session.as_ref() else {
+                return err!(error::ErrorCodes::SessionRequired);
+            };
+            session.validate_claim()?;
+        }
+
+        let platform_vault_bump = ctx.accounts.platform_config.platform_vault_bump;
         let global_round_info = &mut ctx.accounts.platform_config.global_round_info;
         let user_info = &mut ctx.accounts.user_info;
         let round = &ctx.accounts.round;
         let user_bet = &mut ctx.accounts.user_bet;
 
-        require!(
-            round.ending_price != 0,
-            error::ErrorCodes::RoundHasNotEndedYet
-        );
-        require!(
-            !user_bet.has_claimed_winnings,
-            error::ErrorCodes::AlreadyClaimedWinnings
-        );
-
-        let have_longs_won =
-            utils::math::is_greater_than(&round.ending_price, &round.starting_price);
-        require!(
-            round.ending_price != round.starting_price
-                && ((have_longs_won && user_bet.is_long) || (!have_longs_won && !user_bet.is_long)),
-            error::ErrorCodes::IneligibleForClaim
-        );
+        round.validate_claimable(user_bet)?;
 
         user_bet.has_claimed_winnings = true;
 
-        let mut amount: u64;
-        let pool_amount_to_claim_winnings_from: u64;
-        if have_longs_won {
-            pool_amount_to_claim_winnings_from = u64::try_from(utils::math::mul_div_down(
-                &(round.total_bet_amount_short as u128),
-                &(global_round_info.allocation.winners_share as u128),
-                &(constants::general::BPS as u128),
-            ))
-            .unwrap();
-            amount = u64::try_from(utils::math::mul_div_down(
-                &(user_bet.amount as u128),
-                &(pool_amount_to_claim_winnings_from as u128),
-                &(round.total_bet_amount_long as u128),
-            ))
-            .unwrap();
-        } else {
-            pool_amount_to_claim_winnings_from = u64::try_from(utils::math::mul_div_down(
-                &(round.total_bet_amount_long as u128),
-                &(global_round_info.allocation.winners_share as u128),
-                &(constants::general::BPS as u128),
-            ))
-            .unwrap();
-            amount = u64::try_from(utils::math::mul_div_down(
-                &(user_bet.amount as u128),
-                &(pool_amount_to_claim_winnings_from as u128),
-                &(round.total_bet_amount_short as u128),
-            ))
-            .unwrap();
-        }
+        let mut amount = round.winnings(user_bet.amount, round.allocation.winners_share);
+        amount += user_info.record_win(
+            round_index,
+            &global_round_info.jackpot_allocation,
+            &mut global_round_info.jackpot_pool_amount,
+        );
 
-        user_info.times_won += 1;
-        let streak_winnings_share = match user_info.times_won {
-            10 => global_round_info.jackpot_allocation.streak_10,
-            9 => global_round_info.jackpot_allocation.streak_9,
-            8 => global_round_info.jackpot_allocation.streak_8,
-            7 => global_round_info.jackpot_allocation.streak_7,
-            6 => global_round_info.jackpot_allocation.streak_6,
-            5 => global_round_info.jackpot_allocation.streak_5,
-            _ => 0,
-        };
-        if streak_winnings_share > 0
-            && round_index == user_info.last_won_round
-            && global_round_info.jackpot_pool_amount > 0
+        let payout = user_bet.amount + amount;
+        if let WinningsPreference::WithdrawToWallet { token_account } =
+            user_info.winnings_preference
         {
-            let jackpot_amount = u64::try_from(utils::math::mul_div_down(
-                &(global_round_info.jackpot_pool_amount as u128),
-                &(streak_winnings_share as u128),
-                &(constants::general::BPS as u128),
-            ))
-            .unwrap();
-            amount += jackpot_amount;
-
-            global_round_info.jackpot_pool_amount -= jackpot_amount;
-
-            if user_info.times_won == 10 {
-                user_info.times_won = 0;
-            }
-        }
-
-        user_info.amount += user_bet.amount + amount;
-        if round_index != user_info.last_won_round {
-            user_info.times_won = 1;
+            let (
+                Some(stablecoin),
+                Some(platform_vault),
+                Some(payout_token_account),
+                Some(token_program),
+            ) = (
+                &ctx.accounts.stablecoin,
+                &ctx.accounts.platform_vault,
+                &ctx.accounts.payout_token_account,
+                &ctx.accounts.token_program,
+            )
+            else {
+                return err!(error::ErrorCodes::MissingPayoutAccounts);
+            };
+            require!(
+                payout_token_account.key() == token_account,
+                error::ErrorCodes::InvalidPayoutTokenAccount
+            );
+
+            let platform_vault_bump = &[platform_vault_bump];
+            let platform_vault_signer =
+                &[&[constants::seeds::PLATFORM_VAULT, platform_vault_bump][..]];
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: platform_vault.to_account_info(),
+                        mint: stablecoin.to_account_info(),
+                        to: payout_token_account.to_account_info(),
+                        authority: platform_vault.to_account_info(),
+                    },
+                    platform_vault_signer,
+                ),
+                payout,
+                stablecoin.decimals,
+            )?;
+        } else {
+            user_info.amount += payout;
         }
-        user_info.last_won_round = round_index + 1;
 
         emit!(events::WinningsClaimed {
             user: ctx.accounts.user.key(),
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, Order, OrderCondition, PlatformConfig, UserInfo};
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct CreateOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        init,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Order::INIT_SPACE,
+        seeds = [
+            constants::seeds::ORDER,
+            user.key().as_ref(),
+            order_id.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub order: Account<'info, Order>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateOrder<'_> {
+    pub fn create_order(
+        ctx: Context<CreateOrder>,
+        order_id: u64,
+        amount: u64,
+        is_long: bool,
+        condition: OrderCondition,
+        executor_fee: u64,
+    ) -> Result<()> {
+        let user = &ctx.accounts.user;
+        let user_info = &mut ctx.accounts.user_info;
+        let order = &mut ctx.accounts.order;
+
+        order.user = user.key();
+        order.order_id = order_id;
+        order.amount = amount;
+        order.is_long = is_long;
+        order.condition = condition;
+        order.executor_fee = executor_fee;
+        order.target_round = ctx.accounts.platform_config.global_round_info.round + 2;
+
+        order.bump = ctx.bumps.order;
+
+        order.validate_amount()?;
+        order.validate_condition()?;
+
+        // The bet amount and the executor fee are escrowed in the order until it is either
+        // executed or cancelled.
+        let escrow_amount = amount
+            .checked_add(executor_fee)
+            .ok_or(error::ErrorCodes::InsufficientBalance)?;
+        user_info.debit(escrow_amount)?;
+
+        emit!(events::OrderCreated {
+            user: user.key(),
+            order_id,
+            amount,
+            is_long,
+            condition,
+            executor_fee,
+            target_round: order.target_round,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, Session, SessionPermissions};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Session::INIT_SPACE,
+        seeds = [
+            constants::seeds::SESSION,
+            user.key().as_ref(),
+            delegate.as_ref()
+        ],
+        bump,
+    )]
+    pub session: Account<'info, Session>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateSession<'_> {
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        delegate: Pubkey,
+        expires_at: u64,
+        max_spend: u64,
+        permissions: SessionPermissions,
+    ) -> Result<()> {
+        let user = &ctx.accounts.user;
+        let session = &mut ctx.accounts.session;
+
+        session.user = user.key();
+        session.delegate = delegate;
+        session.expires_at = expires_at;
+        session.max_spend = max_spend;
+        session.spent = 0;
+        session.permissions = permissions;
+
+        session.bump = ctx.bumps.session;
+
+        session.validate_delegate()?;
+        session.validate_expiry()?;
+
+        emit!(events::SessionCreated {
+            user: user.key(),
+            delegate,
+            expires_at,
+            max_spend,
+            permissions,
+        });
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use anchor_spl::{
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface},
 };
 
+use super::user_access::*;
 use crate::{constants, error, events, PlatformConfig, UserInfo};
 
 #[derive(Accounts)]
@@ -48,6 +49,8 @@ pub struct DepositAndWithdraw<'info> {
     )]
     pub user_info: Account<'info, UserInfo>,
 
+    pub access: UserAccess<'info>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
 }
@@ -56,23 +59,28 @@ impl DepositAndWithdraw<'_> {
     pub fn deposit(ctx: Context<DepositAndWithdraw>, amount: u64) -> Result<()> {
         let user = &ctx.accounts.user;
         let stablecoin = &ctx.accounts.stablecoin;
+        let platform_vault = &mut ctx.accounts.platform_vault;
         let user_info = &mut ctx.accounts.user_info;
 
         require!(amount > 0, error::ErrorCodes::DepositAmountZero);
-
-        user_info.amount += amount;
+        ctx.accounts
+            .platform_config
+            .validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
 
         if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
             user_info.bump = ctx.bumps.user_info;
         }
 
+        let platform_vault_balance_before = platform_vault.amount;
+
         transfer_checked(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
                 TransferChecked {
                     from: ctx.accounts.user_token_account.to_account_info(),
                     mint: stablecoin.to_account_info(),
-                    to: ctx.accounts.platform_vault.to_account_info(),
+                    to: platform_vault.to_account_info(),
                     authority: user.to_account_info(),
                 },
             ),
@@ -80,15 +88,24 @@ impl DepositAndWithdraw<'_> {
             stablecoin.decimals,
         )?;
 
+        // Token-2022 transfer fees are withheld from the amount received by the vault, so only
+        // what actually arrived is credited.
+        platform_vault.reload()?;
+        let received_amount = platform_vault.amount - platform_vault_balance_before;
+        require!(received_amount > 0, error::ErrorCodes::DepositAmountZero);
+
+        user_info.amount += received_amount;
+
         emit!(events::Deposited {
             user: user.key(),
             stablecoin: stablecoin.key(),
-            amount: amount
+            amount: received_amount
         });
 
         Ok(())
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn withdraw(ctx: Context<DepositAndWithdraw>, amount: u64) -> Result<()> {
         let stablecoin = &ctx.accounts.stablecoin;
         let platform_vault = &mut ctx.accounts.platform_vault;
@@ -96,7 +113,9 @@ impl DepositAndWithdraw<'_> {
 
         require!(amount > 0, error::ErrorCodes::WithdrawAmountZero);
 
-        user_info.amount -= amount;
+        // The full amount leaves the vault, so any Token-2022 transfer fee is borne by the user and
+        // the vault remains solvent.
+        user_info.debit(amount)?;
 
         let platform_vault_bump = &[ctx.accounts.platform_config.platform_vault_bump];
         let platform_vault_signer = &[&[constants::seeds::PLATFORM_VAULT, platform_vault_bump][..]];
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+
+use super::user_access::*;
+use crate::{constants, error, events, Bet, HousePool, Order, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct ExecuteOrder<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: The user who created the order.
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER,
+            executor.key().as_ref()
+        ],
+        bump,
+    )]
+    pub executor_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            constants::seeds::ORDER,
+            user.key().as_ref(),
+            order.order_id.to_be_bytes().as_ref()
+        ],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            platform_config.global_round_info.round.to_be_bytes().as_ref()
+        ],
+        bump = previous_round.bump,
+    )]
+    pub previous_round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    pub access: UserAccess<'info>,
+
+    /// Required once the house pool is enabled, to move the house's side of the round with the bet.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ExecuteOrder<'_> {
+    pub fn execute_order(ctx: Context<ExecuteOrder>) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let user_info = &mut ctx.accounts.user_info;
+        let executor_info = &mut ctx.accounts.executor_info;
+        let order = &ctx.accounts.order;
+        let round = &mut ctx.accounts.round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        require!(
+            round.ending_price == 0,
+            error::ErrorCodes::RoundAlreadyEnded
+        );
+        order.validate_target_round(platform_config.global_round_info.round + 1)?;
+        order.validate_condition_met(&ctx.accounts.previous_round)?;
+
+        if executor_info.bump == 0 {
+            executor_info.user = ctx.accounts.executor.key();
+            executor_info.bump = ctx.bumps.executor_info;
+        }
+        executor_info.amount += order.executor_fee;
+
+        // Release the escrowed amount back to the user's balance so that the bet can debit it.
+        user_info.amount += order.amount;
+        platform_config.validate_house_pool(ctx.accounts.house_pool.is_some())?;
+        HousePool::rebalance(ctx.accounts.house_pool.as_deref_mut(), round, |round| {
+            user_bet.place(
+                user_info,
+                round,
+                order.amount,
+                order.is_long,
+                ctx.bumps.user_bet,
+                &platform_config.global_round_info.bet_limits(),
+            )
+        })?;
+
+        emit!(events::BetPlaced {
+            user: ctx.accounts.user.key(),
+            round: platform_config.global_round_info.round + 1,
+            amount: order.amount,
+            is_long: order.is_long,
+            affiliate: user_bet.affiliate,
+            total_amount: user_bet.amount,
+        });
+        emit!(events::OrderExecuted {
+            user: ctx.accounts.user.key(),
+            order_id: order.order_id,
+            round: platform_config.global_round_info.round + 1,
+            executor: ctx.accounts.executor.key(),
+            executor_fee: order.executor_fee,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use super::user_access::*;
+use crate::{
+    constants, error, events, utils, Bet, HousePool, PlatformConfig, Round, UserInfo,
+    WinningsPreference,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteRepeatBet<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: The user whose repeat bet standing order is executed.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Escrows the rent of the user's repeat bets in the lamports it holds above its own rent.
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init,
+        payer = keeper,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    pub access: UserAccess<'info>,
+
+    /// Required once the house pool is enabled, to move the house's side of the round with the bet.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ExecuteRepeatBet<'_> {
+    pub fn execute_repeat_bet(ctx: Context<ExecuteRepeatBet>) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &mut ctx.accounts.round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        let WinningsPreference::RepeatBet { amount, is_long } = user_info.winnings_preference
+        else {
+            return err!(error::ErrorCodes::NoRepeatBet);
+        };
+        require!(
+            round.ending_price == 0,
+            error::ErrorCodes::RoundAlreadyEnded
+        );
+        require!(
+            user_info.amount >= amount,
+            error::ErrorCodes::InsufficientBalance
+        );
+
+        platform_config.validate_house_pool(ctx.accounts.house_pool.is_some())?;
+        HousePool::rebalance(ctx.accounts.house_pool.as_deref_mut(), round, |round| {
+            user_bet.place(
+                user_info,
+                round,
+                amount,
+                is_long,
+                ctx.bumps.user_bet,
+                &platform_config.global_round_info.bet_limits(),
+            )
+        })?;
+
+        // The keeper fronts the rent of the bet account and is paid back out of the user's escrow,
+        // so that cranking repeat bets only costs it transaction fees.
+        let rent = user_bet.get_lamports();
+        require!(
+            utils::general::lamports_above_rent(&user_info.to_account_info())? >= rent,
+            error::ErrorCodes::RepeatBetRentNotEscrowed
+        );
+        user_info.sub_lamports(rent)?;
+        ctx.accounts.keeper.add_lamports(rent)?;
+
+        emit!(events::BetPlaced {
+            user: ctx.accounts.user.key(),
+            round: platform_config.global_round_info.round + 1,
+            amount,
+            is_long,
+            affiliate: user_bet.affiliate,
+            total_amount: user_bet.amount,
+        });
+
+        Ok(())
+    }
+}
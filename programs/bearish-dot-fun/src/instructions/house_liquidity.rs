@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    burn, mint_to, transfer_checked, Burn, Mint, MintTo, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{constants, error, events, HousePool, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct HouseLiquidity<'info> {
+    #[account()]
+    pub liquidity_provider: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Account<'info, HousePool>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::LP_MINT],
+        bump = house_pool.lp_mint_bump,
+    )]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = platform_config.stablecoin)]
+    pub stablecoin: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_VAULT],
+        bump = platform_config.platform_vault_bump,
+        token::mint = stablecoin,
+        token::authority = platform_vault
+    )]
+    pub platform_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = stablecoin,
+        token::authority = liquidity_provider,
+    )]
+    pub liquidity_provider_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = liquidity_provider,
+    )]
+    pub liquidity_provider_lp_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl HouseLiquidity<'_> {
+    pub fn deposit_liquidity(ctx: Context<HouseLiquidity>, amount: u64) -> Result<()> {
+        let stablecoin = &ctx.accounts.stablecoin;
+        let platform_vault = &mut ctx.accounts.platform_vault;
+        let house_pool = &mut ctx.accounts.house_pool;
+
+        require!(amount > 0, error::ErrorCodes::LiquidityAmountZero);
+
+        let platform_vault_balance_before = platform_vault.amount;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx
+                        .accounts
+                        .liquidity_provider_token_account
+                        .to_account_info(),
+                    mint: stablecoin.to_account_info(),
+                    to: platform_vault.to_account_info(),
+                    authority: ctx.accounts.liquidity_provider.to_account_info(),
+                },
+            ),
+            amount,
+            stablecoin.decimals,
+        )?;
+
+        platform_vault.reload()?;
+        let received_amount = platform_vault.amount - platform_vault_balance_before;
+
+        let shares = house_pool.shares_for_liquidity(received_amount)?;
+        require!(shares > 0, error::ErrorCodes::SharesAmountZero);
+
+        house_pool.total_liquidity += received_amount;
+        house_pool.total_shares += shares;
+
+        let house_pool_bump = &[house_pool.bump];
+        let house_pool_signer = &[&[constants::seeds::HOUSE_POOL, house_pool_bump][..]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .liquidity_provider_lp_token_account
+                        .to_account_info(),
+                    authority: house_pool.to_account_info(),
+                },
+                house_pool_signer,
+            ),
+            shares,
+        )?;
+
+        emit!(events::LiquidityDeposited {
+            liquidity_provider: ctx.accounts.liquidity_provider.key(),
+            amount: received_amount,
+            shares
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_liquidity(ctx: Context<HouseLiquidity>, shares: u64) -> Result<()> {
+        let stablecoin = &ctx.accounts.stablecoin;
+        let platform_vault = &mut ctx.accounts.platform_vault;
+        let house_pool = &mut ctx.accounts.house_pool;
+
+        require!(shares > 0, error::ErrorCodes::SharesAmountZero);
+
+        let amount = house_pool.liquidity_for_shares(shares);
+        house_pool.validate_withdrawal(amount)?;
+
+        house_pool.total_liquidity -= amount;
+        house_pool.total_shares -= shares;
+
+        burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx
+                        .accounts
+                        .liquidity_provider_lp_token_account
+                        .to_account_info(),
+                    authority: ctx.accounts.liquidity_provider.to_account_info(),
+                },
+            ),
+            shares,
+        )?;
+
+        if amount > 0 {
+            let platform_vault_bump = &[ctx.accounts.platform_config.platform_vault_bump];
+            let platform_vault_signer =
+                &[&[constants::seeds::PLATFORM_VAULT, platform_vault_bump][..]];
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: platform_vault.to_account_info(),
+                        mint: stablecoin.to_account_info(),
+                        to: ctx
+                            .accounts
+                            .liquidity_provider_token_account
+                            .to_account_info(),
+                        authority: platform_vault.to_account_info(),
+                    },
+                    platform_vault_signer,
+                ),
+                amount,
+                stablecoin.decimals,
+            )?;
+        }
+
+        emit!(events::LiquidityWithdrawn {
+            liquidity_provider: ctx.accounts.liquidity_provider.key(),
+            amount,
+            shares
+        });
+
+        Ok(())
+    }
+}
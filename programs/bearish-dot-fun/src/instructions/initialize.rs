@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{constants, events, GlobalRoundInfo, PlatformConfig};
+use crate::{constants, events, utils, AllowedMintExtensions, GlobalRoundInfo, PlatformConfig};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -35,9 +35,19 @@ pub struct Initialize<'info> {
 }
 
 impl Initialize<'_> {
-    pub fn initialize(ctx: Context<Initialize>, global_round_info: GlobalRoundInfo) -> Result<()> {
+    #[allow(clippy::redundant_field_names)]
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        global_round_info: GlobalRoundInfo,
+        allowed_mint_extensions: AllowedMintExtensions,
+    ) -> Result<()> {
         let platform_config = &mut ctx.accounts.platform_config;
 
+        utils::token::validate_mint_extensions(
+            &ctx.accounts.stablecoin.to_account_info(),
+            &allowed_mint_extensions,
+        )?;
+
         let owner_pubkey = ctx.accounts.owner.key();
         let stablecoin_pubkey = ctx.accounts.stablecoin.key();
 
@@ -56,6 +66,8 @@ impl Initialize<'_> {
         platform_config.validate_allocation()?;
         platform_config.validate_jackpot_allocation()?;
         platform_config.validate_price_account()?;
+        platform_config.validate_cancellation_fee()?;
+        platform_config.validate_max_round_imbalance()?;
 
         emit!(events::Initialized {
             owner: owner_pubkey,
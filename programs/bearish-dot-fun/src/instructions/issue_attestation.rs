@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, Attestation, PlatformConfig};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct IssueAttestation<'info> {
+    #[account(mut, address = platform_config.gatekeeper)]
+    pub gatekeeper: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = gatekeeper,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Attestation::INIT_SPACE,
+        seeds = [
+            constants::seeds::ATTESTATION,
+            user.as_ref()
+        ],
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl IssueAttestation<'_> {
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        user: Pubkey,
+        expires_at: u64,
+    ) -> Result<()> {
+        let gatekeeper = &ctx.accounts.gatekeeper;
+        let attestation = &mut ctx.accounts.attestation;
+
+        // Re-issuing an attestation renews it and lifts any earlier revocation.
+        attestation.user = user;
+        attestation.gatekeeper = gatekeeper.key();
+        attestation.expires_at = expires_at;
+        attestation.is_revoked = false;
+
+        attestation.bump = ctx.bumps.attestation;
+
+        attestation.validate(&gatekeeper.key())?;
+
+        emit!(events::AttestationIssued {
+            user,
+            gatekeeper: gatekeeper.key(),
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
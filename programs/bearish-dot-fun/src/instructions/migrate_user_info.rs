@@ -0,0 +1,67 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{constants, UserInfo};
+
+#[derive(Accounts)]
+pub struct MigrateUserInfo<'info> {
+    /// Pays the rent of the fields the account grows by.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The user whose info account is migrated.
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: The user's info account. Accounts created before the fields at the end of
+    /// `UserInfo` existed don't deserialize until they have been grown to the current layout.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub user_info: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateUserInfo<'_> {
+    /// Grows a user info account to the current `UserInfo` layout and records the user it belongs
+    /// to. The other appended fields start out zeroed, which keeps winnings in the balance.
+    pub fn migrate_user_info(ctx: Context<MigrateUserInfo>) -> Result<()> {
+        let user_info = &ctx.accounts.user_info;
+        let space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE;
+
+        if user_info.data_len() < space {
+            let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+            let rent_owed = rent_exempt_minimum.saturating_sub(user_info.lamports());
+            if rent_owed > 0 {
+                transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: user_info.clone(),
+                        },
+                    ),
+                    rent_owed,
+                )?;
+            }
+
+            user_info.realloc(space, true)?;
+        }
+
+        // Checks the discriminator, and that the account deserializes now.
+        let mut migrated_user_info =
+            UserInfo::try_deserialize(&mut &user_info.try_borrow_data()?[..])?;
+        migrated_user_info.user = ctx.accounts.user.key();
+        migrated_user_info.try_serialize(&mut &mut user_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use super::user_access::*;
+use crate::{constants, error, events, MintMarket, PlatformConfig, UserInfo};
+
+#[derive(Accounts)]
+pub struct MintDepositAndWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(address = mint_market.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            mint.key().as_ref()
+        ],
+        bump = mint_market.vault_bump,
+        token::mint = mint,
+        token::authority = mint_vault
+    )]
+    pub mint_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    pub access: UserAccess<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl MintDepositAndWithdraw<'_> {
+    pub fn deposit_mint(ctx: Context<MintDepositAndWithdraw>, amount: u64) -> Result<()> {
+        let user = &ctx.accounts.user;
+        let mint = &ctx.accounts.mint;
+        let mint_vault = &mut ctx.accounts.mint_vault;
+        let user_info = &mut ctx.accounts.user_info;
+
+        require!(amount > 0, error::ErrorCodes::DepositAmountZero);
+        ctx.accounts.mint_market.validate_enabled()?;
+        ctx.accounts
+            .platform_config
+            .validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
+            user_info.bump = ctx.bumps.user_info;
+        }
+
+        let mint_vault_balance_before = mint_vault.amount;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: mint_vault.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        mint_vault.reload()?;
+        let received_amount = mint_vault.amount - mint_vault_balance_before;
+        require!(received_amount > 0, error::ErrorCodes::DepositAmountZero);
+
+        user_info.amount += received_amount;
+
+        emit!(events::Deposited {
+            user: user.key(),
+            stablecoin: mint.key(),
+            amount: received_amount
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_mint(ctx: Context<MintDepositAndWithdraw>, amount: u64) -> Result<()> {
+        let mint = &ctx.accounts.mint;
+        let mint_vault = &mut ctx.accounts.mint_vault;
+        let user_info = &mut ctx.accounts.user_info;
+
+        require!(amount > 0, error::ErrorCodes::WithdrawAmountZero);
+
+        user_info.debit(amount)?;
+
+        let mint_key = mint.key();
+        let mint_vault_bump = &[ctx.accounts.mint_market.vault_bump];
+        let mint_vault_signer = &[&[
+            constants::seeds::PLATFORM_VAULT,
+            mint_key.as_ref(),
+            mint_vault_bump,
+        ][..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: mint_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: mint_vault.to_account_info(),
+                },
+                mint_vault_signer,
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        emit!(events::Withdrawn {
+            user: ctx.accounts.user.key(),
+            stablecoin: mint_key,
+            amount
+        });
+
+        Ok(())
+    }
+}
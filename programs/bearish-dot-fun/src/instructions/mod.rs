@@ -1,17 +1,73 @@
 pub mod admin;
+pub mod cancel_bet;
+pub mod cancel_order;
 pub mod claim_affiliate_winnings;
+pub mod claim_bucket_winnings;
+pub mod claim_fixed_odds_winnings;
+pub mod claim_mint_affiliate_winnings;
+pub mod claim_mint_user_winnings;
+pub mod claim_native_affiliate_winnings;
 pub mod claim_user_winnings;
+pub mod create_order;
+pub mod create_session;
 pub mod deposit_and_withdraw;
+pub mod execute_order;
+pub mod execute_repeat_bet;
+pub mod house_liquidity;
 pub mod initialize;
+pub mod issue_attestation;
+pub mod migrate_user_info;
+pub mod mint_deposit_and_withdraw;
+pub mod native_deposit_and_withdraw;
 pub mod place_bet;
+pub mod place_bet_from_wallet;
+pub mod place_bucket_bet;
+pub mod place_fixed_odds_bet;
+pub mod place_mint_bet;
+pub mod quote_bet;
+pub mod quote_claim;
+pub mod quote_jackpot;
+pub mod revoke_attestation;
+pub mod revoke_session;
 pub mod run_round;
 pub mod set_affiliate;
+pub mod set_winnings_preference;
+pub mod settle_mint_round;
+pub mod user_access;
 
 pub use admin::*;
+pub use cancel_bet::*;
+pub use cancel_order::*;
 pub use claim_affiliate_winnings::*;
+pub use claim_bucket_winnings::*;
+pub use claim_fixed_odds_winnings::*;
+pub use claim_mint_affiliate_winnings::*;
+pub use claim_mint_user_winnings::*;
+pub use claim_native_affiliate_winnings::*;
 pub use claim_user_winnings::*;
+pub use create_order::*;
+pub use create_session::*;
 pub use deposit_and_withdraw::*;
+pub use execute_order::*;
+pub use execute_repeat_bet::*;
+pub use house_liquidity::*;
 pub use initialize::*;
+pub use issue_attestation::*;
+pub use migrate_user_info::*;
+pub use mint_deposit_and_withdraw::*;
+pub use native_deposit_and_withdraw::*;
 pub use place_bet::*;
+pub use place_bet_from_wallet::*;
+pub use place_bucket_bet::*;
+pub use place_fixed_odds_bet::*;
+pub use place_mint_bet::*;
+pub use quote_bet::*;
+pub use quote_claim::*;
+pub use quote_jackpot::*;
+pub use revoke_attestation::*;
+pub use revoke_session::*;
 pub use run_round::*;
 pub use set_affiliate::*;
+pub use set_winnings_preference::*;
+pub use settle_mint_round::*;
+pub use user_access::*;
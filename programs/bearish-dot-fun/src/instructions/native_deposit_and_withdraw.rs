@@ -0,0 +1,123 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token::spl_token;
+
+use super::user_access::*;
+use crate::{constants, error, events, utils, MintMarket, PlatformConfig, UserInfo};
+
+#[derive(Accounts)]
+pub struct NativeDepositAndWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::PLATFORM_VAULT,
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump = mint_market.vault_bump,
+    )]
+    pub native_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref(),
+            spl_token::native_mint::ID.as_ref()
+        ],
+        bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    pub access: UserAccess<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl NativeDepositAndWithdraw<'_> {
+    pub fn deposit_native_sol(ctx: Context<NativeDepositAndWithdraw>, amount: u64) -> Result<()> {
+        let user = &ctx.accounts.user;
+        let mint_market = &ctx.accounts.mint_market;
+        let user_info = &mut ctx.accounts.user_info;
+
+        require!(amount > 0, error::ErrorCodes::DepositAmountZero);
+        mint_market.validate_native()?;
+        mint_market.validate_enabled()?;
+        ctx.accounts
+            .platform_config
+            .validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        user_info.amount += amount;
+
+        if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
+            user_info.bump = ctx.bumps.user_info;
+        }
+
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: user.to_account_info(),
+                    to: ctx.accounts.native_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(events::Deposited {
+            user: user.key(),
+            stablecoin: spl_token::native_mint::ID,
+            amount
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_native_sol(ctx: Context<NativeDepositAndWithdraw>, amount: u64) -> Result<()> {
+        let mint_market = &ctx.accounts.mint_market;
+        let user_info = &mut ctx.accounts.user_info;
+
+        require!(amount > 0, error::ErrorCodes::WithdrawAmountZero);
+        mint_market.validate_native()?;
+
+        user_info.debit(amount)?;
+
+        utils::native::transfer_from_native_vault(
+            &ctx.accounts.native_vault.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            mint_market.vault_bump,
+            amount,
+        )?;
+
+        emit!(events::Withdrawn {
+            user: ctx.accounts.user.key(),
+            stablecoin: spl_token::native_mint::ID,
+            amount
+        });
+
+        Ok(())
+    }
+}
@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, error, events, Bet, PlatformConfig, Round, UserInfo};
+use super::user_access::*;
+use crate::{constants, error, events, Bet, HousePool, PlatformConfig, Round, Session, UserInfo};
 
 #[derive(Accounts)]
 pub struct PlaceBet<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    /// CHECK: The user placing the bet. Either the user themselves or a delegate with a valid
+    /// session must sign as the authority.
+    #[account()]
+    pub user: AccountInfo<'info>,
 
     #[account(
         seeds = [constants::seeds::PLATFORM_CONFIG],
@@ -34,8 +40,8 @@ pub struct PlaceBet<'info> {
     pub round: Account<'info, Round>,
 
     #[account(
-        init,
-        payer = user,
+        init_if_needed,
+        payer = authority,
         space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
         seeds = [
             constants::seeds::USER_BET,
@@ -46,6 +52,27 @@ pub struct PlaceBet<'info> {
     )]
     pub user_bet: Account<'info, Bet>,
 
+    pub access: UserAccess<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::SESSION,
+            user.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = session.bump,
+    )]
+    pub session: Option<Account<'info, Session>>,
+
+    /// Required once the house pool is enabled, to move the house's side of the round with the bet.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -56,38 +83,34 @@ impl PlaceBet<'_> {
         let round = &mut ctx.accounts.round;
         let user_bet = &mut ctx.accounts.user_bet;
 
-        user_info.amount -= amount;
-        user_bet.amount += amount;
-
-        user_bet.bump = ctx.bumps.user_bet;
-
-        if is_long {
-            round.long_positions += 1;
-            round.total_bet_amount_long += amount;
-            user_bet.is_long = true;
-
-            if user_info.affiliate != Pubkey::default() {
-                user_bet.affiliate = user_info.affiliate;
-                round.affiliates_for_long_positions += 1;
-            }
-        } else {
-            round.short_positions += 1;
-            round.total_bet_amount_short += amount;
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
 
-            if user_info.affiliate != Pubkey::default() {
-                user_bet.affiliate = user_info.affiliate;
-                round.affiliates_for_short_positions += 1;
-            }
+        if ctx.accounts.authority.key() != ctx.accounts.user.key() {
+            let Some(session) = ctx.accounts.session.as_mut() else {
+                return err!(error::ErrorCodes::SessionRequired);
+            };
+            session.spend(amount)?;
         }
 
-        user_bet.validate_amount(platform_config.global_round_info.min_bet_amount)?;
+        platform_config.validate_house_pool(ctx.accounts.house_pool.is_some())?;
+        HousePool::rebalance(ctx.accounts.house_pool.as_deref_mut(), round, |round| {
+            user_bet.place(
+                user_info,
+                round,
+                amount,
+                is_long,
+                ctx.bumps.user_bet,
+                &platform_config.global_round_info.bet_limits(),
+            )
+        })?;
 
         emit!(events::BetPlaced {
             user: ctx.accounts.user.key(),
             round: platform_config.global_round_info.round + 1,
-            amount: amount,
-            is_long: is_long,
-            affiliate: user_info.affiliate,
+            amount,
+            is_long,
+            affiliate: user_bet.affiliate,
+            total_amount: user_bet.amount,
         });
 
         Ok(())
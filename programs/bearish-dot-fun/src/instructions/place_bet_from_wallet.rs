@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use super::user_access::*;
+use crate::{constants, error, events, Bet, HousePool, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct PlaceBetFromWallet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(address = platform_config.stablecoin)]
+    pub stablecoin: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::PLATFORM_VAULT],
+        bump = platform_config.platform_vault_bump,
+        token::mint = stablecoin,
+        token::authority = platform_vault
+    )]
+    pub platform_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = stablecoin,
+        token::authority = user,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    pub access: UserAccess<'info>,
+
+    /// Required once the house pool is enabled, to move the house's side of the round with the bet.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl PlaceBetFromWallet<'_> {
+    pub fn place_bet_from_wallet(
+        ctx: Context<PlaceBetFromWallet>,
+        amount: u64,
+        is_long: bool,
+    ) -> Result<()> {
+        let user = &ctx.accounts.user;
+        let platform_config = &ctx.accounts.platform_config;
+        let stablecoin = &ctx.accounts.stablecoin;
+        let platform_vault = &mut ctx.accounts.platform_vault;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &mut ctx.accounts.round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        require!(amount > 0, error::ErrorCodes::DepositAmountZero);
+
+        if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
+            user_info.bump = ctx.bumps.user_info;
+        }
+
+        let platform_vault_balance_before = platform_vault.amount;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    mint: stablecoin.to_account_info(),
+                    to: platform_vault.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            amount,
+            stablecoin.decimals,
+        )?;
+
+        // Only what the vault actually received after Token-2022 transfer fees is bet.
+        platform_vault.reload()?;
+        let received_amount = platform_vault.amount - platform_vault_balance_before;
+
+        // The deposited amount is credited and immediately debited again by the bet, so the
+        // user's existing balance is left untouched.
+        user_info.amount += received_amount;
+        platform_config.validate_house_pool(ctx.accounts.house_pool.is_some())?;
+        HousePool::rebalance(ctx.accounts.house_pool.as_deref_mut(), round, |round| {
+            user_bet.place(
+                user_info,
+                round,
+                received_amount,
+                is_long,
+                ctx.bumps.user_bet,
+                &platform_config.global_round_info.bet_limits(),
+            )
+        })?;
+
+        emit!(events::Deposited {
+            user: user.key(),
+            stablecoin: stablecoin.key(),
+            amount: received_amount
+        });
+        emit!(events::BetPlaced {
+            user: user.key(),
+            round: platform_config.global_round_info.round + 1,
+            amount: received_amount,
+            is_long,
+            affiliate: user_bet.affiliate,
+            total_amount: user_bet.amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use super::user_access::*;
+use crate::{constants, events, BucketBet, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct PlaceBucketBet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + BucketBet::INIT_SPACE,
+        seeds = [
+            constants::seeds::BUCKET_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket_bet: Account<'info, BucketBet>,
+
+    pub access: UserAccess<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceBucketBet<'_> {
+    pub fn place_bucket_bet(ctx: Context<PlaceBucketBet>, amount: u64, bucket: u8) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &mut ctx.accounts.round;
+        let bucket_bet = &mut ctx.accounts.bucket_bet;
+
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        bucket_bet.place(
+            user_info,
+            round,
+            amount,
+            bucket,
+            ctx.bumps.bucket_bet,
+            &platform_config.global_round_info.bet_limits(),
+        )?;
+
+        emit!(events::BucketBetPlaced {
+            user: ctx.accounts.user.key(),
+            round: platform_config.global_round_info.round + 1,
+            amount,
+            bucket,
+            total_amount: bucket_bet.amount,
+        });
+
+        Ok(())
+    }
+}
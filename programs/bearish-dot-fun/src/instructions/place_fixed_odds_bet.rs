@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use super::user_access::*;
+use crate::{constants, events, Bet, HousePool, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct PlaceFixedOddsBet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Account<'info, HousePool>,
+
+    #[account(
+        init,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        seeds = [
+            constants::seeds::FIXED_ODDS_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub fixed_odds_bet: Account<'info, Bet>,
+
+    pub access: UserAccess<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceFixedOddsBet<'_> {
+    /// Places a bet whose payout multiplier is locked in now. The stake goes to the house pool,
+    /// which pays out winning fixed-odds bets instead of the losing side of the round.
+    pub fn place_fixed_odds_bet(
+        ctx: Context<PlaceFixedOddsBet>,
+        amount: u64,
+        is_long: bool,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let global_round_info = &platform_config.global_round_info;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &mut ctx.accounts.round;
+        let house_pool = &mut ctx.accounts.house_pool;
+        let fixed_odds_bet = &mut ctx.accounts.fixed_odds_bet;
+
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        let multiplier = house_pool.fixed_odds_multiplier(round, amount, is_long)?;
+
+        user_info.debit(amount)?;
+
+        fixed_odds_bet.amount = amount;
+        fixed_odds_bet.is_long = is_long;
+        fixed_odds_bet.multiplier = multiplier;
+        fixed_odds_bet.bump = ctx.bumps.fixed_odds_bet;
+
+        let bet_limits = global_round_info.bet_limits();
+        bet_limits.validate_amount(fixed_odds_bet.amount)?;
+        bet_limits.validate_exposure(amount, fixed_odds_bet.amount)?;
+
+        house_pool.place_fixed_odds(round, amount, is_long, multiplier)?;
+
+        emit!(events::FixedOddsBetPlaced {
+            user: ctx.accounts.user.key(),
+            round: global_round_info.round + 1,
+            amount,
+            is_long,
+            multiplier
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use super::user_access::*;
+use crate::{constants, events, Bet, MintMarket, PlatformConfig, Round, UserInfo};
+
+#[derive(Accounts)]
+pub struct PlaceMintBet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    /// The user's stablecoin account, from which the affiliate is picked up if the user hasn't
+    /// bet in this mint before.
+    #[account(
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = platform_user_info.bump,
+    )]
+    pub platform_user_info: Option<Account<'info, UserInfo>>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Round::INIT_SPACE,
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub mint_round: Account<'info, Round>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+
+    pub access: UserAccess<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceMintBet<'_> {
+    pub fn place_mint_bet(ctx: Context<PlaceMintBet>, amount: u64, is_long: bool) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let mint_market = &ctx.accounts.mint_market;
+        let user_info = &mut ctx.accounts.user_info;
+        let round = &ctx.accounts.round;
+        let mint_round = &mut ctx.accounts.mint_round;
+        let user_bet = &mut ctx.accounts.user_bet;
+
+        mint_market.validate_enabled()?;
+        platform_config.validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
+        // The mint's pools follow the round started for the platform stablecoin.
+        if mint_round.bump == 0 {
+            mint_round.start_time = round.start_time;
+            mint_round.starting_price = round.starting_price;
+            mint_round.strike_price = round.strike_price;
+            mint_round.bump = ctx.bumps.mint_round;
+        }
+
+        if user_info.affiliate == Pubkey::default() {
+            if let Some(platform_user_info) = &ctx.accounts.platform_user_info {
+                user_info.affiliate = platform_user_info.affiliate;
+            }
+        }
+
+        user_bet.place(
+            user_info,
+            mint_round,
+            amount,
+            is_long,
+            ctx.bumps.user_bet,
+            &mint_market.bet_limits(&platform_config.global_round_info),
+        )?;
+
+        emit!(events::MintBetPlaced {
+            user: ctx.accounts.user.key(),
+            mint: mint_market.mint,
+            round: platform_config.global_round_info.round + 1,
+            amount,
+            is_long,
+            affiliate: user_bet.affiliate,
+            total_amount: user_bet.amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, HousePool, PlatformConfig, Round};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BetQuote {
+    /// The share of the losing side's pool the bet would win.
+    pub winnings: u64,
+    /// The bet amount plus its winnings.
+    pub payout: u64,
+}
+
+#[derive(Accounts)]
+pub struct QuoteBet<'info> {
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (platform_config.global_round_info.round + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    /// Required once the house pool is enabled, so that the quote moves the house's side of the
+    /// round with the bet like placing it would.
+    #[account(
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+}
+
+impl QuoteBet<'_> {
+    /// Returns what a bet of `amount` on the current round pays out if its side wins, given the
+    /// pools as they are now. Later bets, and the house pool moving its side with them, change the
+    /// actual payout.
+    pub fn quote_bet(ctx: Context<QuoteBet>, amount: u64, is_long: bool) -> Result<BetQuote> {
+        let platform_config = &ctx.accounts.platform_config;
+        let global_round_info = &platform_config.global_round_info;
+        let mut round = Round::clone(&ctx.accounts.round);
+        let mut house_pool = ctx.accounts.house_pool.as_deref().cloned();
+
+        require!(amount > 0, error::ErrorCodes::BetAmountZero);
+        require!(
+            round.ending_price == 0,
+            error::ErrorCodes::RoundAlreadyEnded
+        );
+
+        platform_config.validate_house_pool(house_pool.is_some())?;
+        HousePool::rebalance(house_pool.as_mut(), &mut round, |round| {
+            if is_long {
+                round.total_bet_amount_long += amount;
+            } else {
+                round.total_bet_amount_short += amount;
+            }
+
+            Ok(())
+        })?;
+        let winnings =
+            round.winnings_if_won(amount, is_long, global_round_info.allocation.winners_share);
+
+        Ok(BetQuote {
+            winnings,
+            payout: amount + winnings,
+        })
+    }
+}
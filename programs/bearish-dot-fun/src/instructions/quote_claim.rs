@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Bet, PlatformConfig, Round, UserInfo};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimQuote {
+    /// The bet amount, which is returned to the user.
+    pub amount: u64,
+    /// The user's share of the winners' cut of the losing side.
+    pub winnings: u64,
+    /// The streak jackpot the claim pays out, if any.
+    pub jackpot_amount: u64,
+    /// Everything the claim credits to the user.
+    pub payout: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct QuoteClaim<'info> {
+    /// CHECK: The user to quote the claim for.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        seeds = [
+            constants::seeds::USER_BET,
+            user.key().as_ref(),
+            (round_index + 1).to_be_bytes().as_ref(),
+        ],
+        bump = user_bet.bump,
+    )]
+    pub user_bet: Account<'info, Bet>,
+}
+
+impl QuoteClaim<'_> {
+    /// Returns exactly what `claim_user_winnings` would credit for the user's bet right now,
+    /// failing the same way if the bet can't be claimed.
+    pub fn quote_claim(ctx: Context<QuoteClaim>, round_index: u64) -> Result<ClaimQuote> {
+        let global_round_info = &ctx.accounts.platform_config.global_round_info;
+        let mut user_info = UserInfo::clone(&ctx.accounts.user_info);
+        let mut jackpot_pool_amount = global_round_info.jackpot_pool_amount;
+        let round = &ctx.accounts.round;
+        let user_bet = &ctx.accounts.user_bet;
+
+        round.validate_claimable(user_bet)?;
+
+        let winnings = round.winnings(user_bet.amount, round.allocation.winners_share);
+        let jackpot_amount = user_info.record_win(
+            round_index,
+            &global_round_info.jackpot_allocation,
+            &mut jackpot_pool_amount,
+        );
+
+        Ok(ClaimQuote {
+            amount: user_bet.amount,
+            winnings,
+            jackpot_amount,
+            payout: user_bet.amount + winnings + jackpot_amount,
+        })
+    }
+}
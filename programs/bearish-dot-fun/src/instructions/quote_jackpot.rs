@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, PlatformConfig, UserInfo};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JackpotQuote {
+    /// The round the user has to win next to extend their streak.
+    pub round: u64,
+    /// The user's streak once they win that round.
+    pub streak: u64,
+    /// The streak jackpot claiming that win pays out, at the current jackpot pool amount.
+    pub jackpot_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct QuoteJackpot<'info> {
+    /// CHECK: The user to quote the streak jackpot for.
+    #[account()]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump = user_info.bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+}
+
+impl QuoteJackpot<'_> {
+    /// Returns the streak jackpot the user gets if they win the round after their last win. The
+    /// pool keeps changing until the win is claimed, so the amount is only indicative.
+    pub fn quote_jackpot(ctx: Context<QuoteJackpot>) -> Result<JackpotQuote> {
+        let global_round_info = &ctx.accounts.platform_config.global_round_info;
+        let mut user_info = UserInfo::clone(&ctx.accounts.user_info);
+        let mut jackpot_pool_amount = global_round_info.jackpot_pool_amount;
+
+        // `last_won_round` is the index of the round following the last win.
+        let round_index = user_info.last_won_round;
+        let jackpot_amount = user_info.record_win(
+            round_index,
+            &global_round_info.jackpot_allocation,
+            &mut jackpot_pool_amount,
+        );
+        // A 10 win streak resets once its jackpot is paid out.
+        let streak = if user_info.times_won == 0 {
+            10
+        } else {
+            user_info.times_won
+        };
+
+        Ok(JackpotQuote {
+            round: round_index + 1,
+            streak,
+            jackpot_amount,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, Attestation, PlatformConfig};
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    #[account(address = platform_config.gatekeeper)]
+    pub gatekeeper: Signer<'info>,
+
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ATTESTATION,
+            attestation.user.as_ref()
+        ],
+        bump = attestation.bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+impl RevokeAttestation<'_> {
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+
+        attestation.is_revoked = true;
+
+        emit!(events::AttestationRevoked {
+            user: attestation.user,
+            gatekeeper: ctx.accounts.gatekeeper.key(),
+        });
+
+        Ok(())
+    }
+}
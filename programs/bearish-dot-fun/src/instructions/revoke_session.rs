@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, Session};
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            constants::seeds::SESSION,
+            user.key().as_ref(),
+            session.delegate.as_ref()
+        ],
+        bump = session.bump,
+    )]
+    pub session: Account<'info, Session>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl RevokeSession<'_> {
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        emit!(events::SessionRevoked {
+            user: ctx.accounts.user.key(),
+            delegate: ctx.accounts.session.delegate,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, error, events, utils, PlatformConfig, Round};
+use crate::{constants, error, events, utils, HousePool, PlatformConfig, Round};
 
 #[derive(Accounts)]
 pub struct RunRound<'info> {
@@ -30,6 +30,15 @@ pub struct RunRound<'info> {
     #[account(address = platform_config.global_round_info.price_account)]
     pub price_account: AccountInfo<'info>,
 
+    /// Only used when ending a round, to settle the house's position and fixed-odds bets.
+    /// Required if the house pool is enabled or the round has fixed-odds bets.
+    #[account(
+        mut,
+        seeds = [constants::seeds::HOUSE_POOL],
+        bump = house_pool.bump,
+    )]
+    pub house_pool: Option<Account<'info, HousePool>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -39,32 +48,30 @@ impl RunRound<'_> {
         let round = &mut ctx.accounts.round;
 
         require!(
-            round.start_time == 0,
-            error::ErrorCodes::RoundAlreadyStarted
+            !platform_config.is_strike_override_pending,
+            error::ErrorCodes::StrikeOverridePending
         );
 
-        let current_time = Clock::get()?.unix_timestamp as u64;
-        round.start_time = current_time;
-
         let price = utils::general::get_price(
             &ctx.accounts.price_account,
             platform_config.global_round_info.staleness_threshold,
         );
-        round.starting_price = price;
-
-        round.bump = ctx.bumps.round;
-
-        round.validate_starting_price()?;
+        round.start(price, None, platform_config, ctx.bumps.round)?;
 
         emit!(events::RoundStarted {
             round: platform_config.global_round_info.round + 1,
-            starting_price: price
+            starting_price: price,
+            strike_price: round.strike_price
         });
 
         Ok(())
     }
 
     pub fn end_round(ctx: Context<RunRound>) -> Result<()> {
+        ctx.accounts
+            .platform_config
+            .validate_house_pool(ctx.accounts.house_pool.is_some())?;
+
         let global_round_info = &mut ctx.accounts.platform_config.global_round_info;
         let round = &mut ctx.accounts.round;
 
@@ -73,95 +80,82 @@ impl RunRound<'_> {
             error::ErrorCodes::RoundAlreadyEnded
         );
 
+        require!(
+            ctx.accounts.house_pool.is_some()
+                || (round.fixed_odds_payout_long == 0 && round.fixed_odds_payout_short == 0),
+            error::ErrorCodes::HousePoolRequired
+        );
+
         let price = utils::general::get_price(
             &ctx.accounts.price_account,
             global_round_info.staleness_threshold,
         );
         round.ending_price = price;
+        round.allocation = global_round_info.allocation.clone();
         global_round_info.round += 1;
 
-        if round.ending_price == round.starting_price {
-            global_round_info.jackpot_pool_amount += u64::try_from(utils::math::mul_div_down(
-                &((round.total_bet_amount_long + round.total_bet_amount_short) as u128),
-                &((global_round_info.allocation.jackpot_share
-                    + global_round_info.allocation.winners_share
-                    + global_round_info.allocation.affiliate_share) as u128),
-                &(constants::general::BPS as u128),
-            ))
-            .unwrap();
-
-            global_round_info.accumulated_platform_fees +=
-                u64::try_from(utils::math::mul_div_down(
-                    &((round.total_bet_amount_long + round.total_bet_amount_short) as u128),
-                    &(global_round_info.allocation.platform_share as u128),
-                    &(constants::general::BPS as u128),
-                ))
-                .unwrap();
-        } else {
-            let have_longs_won =
-                utils::math::is_greater_than(&round.ending_price, &round.starting_price);
-            if have_longs_won {
-                global_round_info.jackpot_pool_amount += u64::try_from(utils::math::mul_div_down(
-                    &(round.total_bet_amount_short as u128),
-                    &(global_round_info.allocation.jackpot_share as u128),
-                    &(constants::general::BPS as u128),
-                ))
-                .unwrap();
-
-                global_round_info.accumulated_platform_fees +=
-                    u64::try_from(utils::math::mul_div_down(
-                        &(round.total_bet_amount_short as u128),
-                        &(global_round_info.allocation.platform_share as u128),
-                        &(constants::general::BPS as u128),
-                    ))
-                    .unwrap();
-
-                if round.total_bet_amount_long == 0 {
-                    global_round_info.jackpot_pool_amount +=
-                        u64::try_from(utils::math::mul_div_down(
-                            &(round.total_bet_amount_short as u128),
-                            &((global_round_info.allocation.winners_share
-                                + global_round_info.allocation.affiliate_share)
-                                as u128),
-                            &(constants::general::BPS as u128),
-                        ))
-                        .unwrap();
-                }
-            } else {
-                global_round_info.jackpot_pool_amount += u64::try_from(utils::math::mul_div_down(
-                    &(round.total_bet_amount_long as u128),
-                    &(global_round_info.allocation.jackpot_share as u128),
-                    &(constants::general::BPS as u128),
-                ))
-                .unwrap();
-
-                global_round_info.accumulated_platform_fees +=
-                    u64::try_from(utils::math::mul_div_down(
-                        &(round.total_bet_amount_long as u128),
-                        &(global_round_info.allocation.platform_share as u128),
-                        &(constants::general::BPS as u128),
-                    ))
-                    .unwrap();
-
-                if round.total_bet_amount_short == 0 {
-                    global_round_info.jackpot_pool_amount +=
-                        u64::try_from(utils::math::mul_div_down(
-                            &(round.total_bet_amount_long as u128),
-                            &((global_round_info.allocation.winners_share
-                                + global_round_info.allocation.affiliate_share)
-                                as u128),
-                            &(constants::general::BPS as u128),
-                        ))
-                        .unwrap();
-                }
+        let (mut jackpot_amount, mut platform_fee) = round.settle(&global_round_info.allocation);
+
+        if let Some(house_pool) = ctx.accounts.house_pool.as_mut() {
+            let fixed_odds_payout = house_pool.settle_fixed_odds(round);
+            let (house_payout, lp_fee) = house_pool.settle(
+                round,
+                global_round_info.allocation.winners_share,
+                platform_fee,
+            );
+            platform_fee -= lp_fee;
+
+            if round.house_bet_amount > 0 || lp_fee > 0 || fixed_odds_payout > 0 {
+                emit!(events::HouseBetSettled {
+                    round: global_round_info.round,
+                    amount: round.house_bet_amount,
+                    is_long: round.house_is_long,
+                    payout: house_payout,
+                    lp_fee,
+                    fixed_odds_payout
+                });
             }
         }
 
+        if round.has_buckets() {
+            round.winning_bucket = round.bucket_for_price_change();
+
+            let (bucket_jackpot_amount, bucket_platform_fee) =
+                round.settle_buckets(&global_round_info.allocation);
+            jackpot_amount += bucket_jackpot_amount;
+            platform_fee += bucket_platform_fee;
+
+            emit!(events::BucketsSettled {
+                round: global_round_info.round,
+                winning_bucket: round.winning_bucket,
+                jackpot_amount: bucket_jackpot_amount,
+                platform_fee: bucket_platform_fee
+            });
+        }
+
+        global_round_info.jackpot_pool_amount += jackpot_amount;
+        global_round_info.accumulated_platform_fees += platform_fee;
+
         round.validate_round_duration(global_round_info.duration)?;
 
+        let (winners_pool, affiliate_pool) =
+            round.distributable_pools(&global_round_info.allocation);
+
         emit!(events::RoundEnded {
             round: global_round_info.round,
-            ending_price: price
+            starting_price: round.starting_price,
+            strike_price: round.strike_price,
+            ending_price: price,
+            outcome: round.outcome(),
+            total_bet_amount_long: round.total_bet_amount_long,
+            total_bet_amount_short: round.total_bet_amount_short,
+            long_positions: round.long_positions,
+            short_positions: round.short_positions,
+            jackpot_amount,
+            platform_fee,
+            winners_pool,
+            affiliate_pool,
+            jackpot_pool_amount: global_round_info.jackpot_pool_amount
         });
 
         Ok(())
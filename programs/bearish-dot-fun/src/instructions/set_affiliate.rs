@@ -1,12 +1,19 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, error, events, UserInfo};
+use super::user_access::*;
+use crate::{constants, events, PlatformConfig, UserInfo};
 
 #[derive(Accounts)]
 pub struct SetAffiliate<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -19,17 +26,25 @@ pub struct SetAffiliate<'info> {
     )]
     pub user_info: Account<'info, UserInfo>,
 
+    pub access: UserAccess<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl SetAffiliate<'_> {
+    #[allow(clippy::redundant_field_names)]
     pub fn set_affiliate(ctx: Context<SetAffiliate>, affiliate: Pubkey) -> Result<()> {
         let user = &ctx.accounts.user;
         let user_info = &mut ctx.accounts.user_info;
 
+        ctx.accounts
+            .platform_config
+            .validate_access(ctx.accounts.user.key, &ctx.accounts.access)?;
+
         user_info.affiliate = affiliate;
 
         if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
             user_info.bump = ctx.bumps.user_info;
         }
 
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, events, utils, UserInfo, WinningsPreference};
+
+#[derive(Accounts)]
+pub struct SetWinningsPreference<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Lamports sent to this account above its rent escrow the rent of the user's repeat bets.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = constants::general::ANCHOR_DISCRIMINATOR_SIZE + UserInfo::INIT_SPACE,
+        seeds = [
+            constants::seeds::USER,
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub user_info: Account<'info, UserInfo>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetWinningsPreference<'_> {
+    pub fn set_winnings_preference(
+        ctx: Context<SetWinningsPreference>,
+        winnings_preference: WinningsPreference,
+    ) -> Result<()> {
+        let user_info = &mut ctx.accounts.user_info;
+
+        user_info.winnings_preference = winnings_preference;
+
+        if user_info.bump == 0 {
+            user_info.user = ctx.accounts.user.key();
+            user_info.bump = ctx.bumps.user_info;
+        }
+
+        user_info.validate_winnings_preference()?;
+
+        // Rent escrowed for repeat bets goes back to the user once they stop repeating bets.
+        if !matches!(winnings_preference, WinningsPreference::RepeatBet { .. }) {
+            let escrowed_rent = utils::general::lamports_above_rent(&user_info.to_account_info())?;
+            user_info.sub_lamports(escrowed_rent)?;
+            ctx.accounts.user.add_lamports(escrowed_rent)?;
+        }
+
+        emit!(events::WinningsPreferenceSet {
+            user: ctx.accounts.user.key(),
+            winnings_preference
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, events, MintMarket, PlatformConfig, Round};
+
+#[derive(Accounts)]
+#[instruction(round_index: u64)]
+pub struct SettleMintRound<'info> {
+    #[account(
+        seeds = [constants::seeds::PLATFORM_CONFIG],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::MINT_MARKET,
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_market.bump,
+    )]
+    pub mint_market: Account<'info, MintMarket>,
+
+    #[account(
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref()
+        ],
+        bump = round.bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::seeds::ROUND,
+            (round_index + 1).to_be_bytes().as_ref(),
+            mint_market.mint.as_ref()
+        ],
+        bump = mint_round.bump,
+    )]
+    pub mint_round: Account<'info, Round>,
+}
+
+impl SettleMintRound<'_> {
+    /// Settles a mint's pools for a round that has been ended by `end_round`. Anyone can call
+    /// this, and it must be called before winnings in that mint can be claimed.
+    pub fn settle_mint_round(ctx: Context<SettleMintRound>, round_index: u64) -> Result<()> {
+        let mint_market = &mut ctx.accounts.mint_market;
+        let round = &ctx.accounts.round;
+        // Settle at the allocation the round ended with, which the owner may have changed since.
+        let allocation = &round.allocation;
+        let mint_round = &mut ctx.accounts.mint_round;
+
+        require!(
+            round.ending_price != 0,
+            error::ErrorCodes::RoundHasNotEndedYet
+        );
+        require!(
+            mint_round.ending_price == 0,
+            error::ErrorCodes::RoundAlreadyEnded
+        );
+
+        mint_round.ending_price = round.ending_price;
+        mint_round.allocation = allocation.clone();
+
+        let (jackpot_amount, platform_fee) = mint_round.settle(allocation);
+        mint_market.jackpot_pool_amount += jackpot_amount;
+        mint_market.accumulated_platform_fees += platform_fee;
+
+        emit!(events::MintRoundSettled {
+            mint: mint_market.mint,
+            round: round_index + 1,
+            ending_price: round.ending_price
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error};
+
+/// The user's attestation and blocklist PDAs, taken by every instruction that lets a user move
+/// funds or bet. Either may be empty if it was never created, so they are unchecked here and
+/// `PlatformConfig::validate_access` checks their addresses against the user before reading
+/// them.
+#[derive(Accounts)]
+pub struct UserAccess<'info> {
+    /// CHECK: The user's attestation PDA. Only required to be initialized if gating is enabled.
+    pub attestation: AccountInfo<'info>,
+
+    /// CHECK: The user's blocklist PDA. Only initialized if the user has been blocked.
+    pub blocked_user: AccountInfo<'info>,
+}
+
+impl UserAccess<'_> {
+    pub fn validate_addresses(&self, user: &Pubkey) -> Result<()> {
+        let (attestation, _) = Pubkey::find_program_address(
+            &[constants::seeds::ATTESTATION, user.as_ref()],
+            &crate::ID,
+        );
+        let (blocked_user, _) = Pubkey::find_program_address(
+            &[constants::seeds::BLOCKED_USER, user.as_ref()],
+            &crate::ID,
+        );
+
+        require!(
+            self.attestation.key() == attestation && self.blocked_user.key() == blocked_user,
+            error::ErrorCodes::InvalidAccessAccount
+        );
+
+        Ok(())
+    }
+}
@@ -31,8 +31,12 @@ declare_id!("3QeFNCSAeShUHBZGWtBANjPSevFtN7XygkwngohM7meP");
 pub mod bearish_dot_fun {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, global_round_info: GlobalRoundInfo) -> Result<()> {
-        Initialize::initialize(ctx, global_round_info)
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        global_round_info: GlobalRoundInfo,
+        allowed_mint_extensions: AllowedMintExtensions,
+    ) -> Result<()> {
+        Initialize::initialize(ctx, global_round_info, allowed_mint_extensions)
     }
 
     pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
@@ -69,10 +73,152 @@ pub mod bearish_dot_fun {
         SetPlatformConfig::set_staleness_threshold(ctx, staleness_threshold)
     }
 
+    pub fn set_cancellation_window(
+        ctx: Context<SetPlatformConfig>,
+        cancellation_window: u64,
+    ) -> Result<()> {
+        SetPlatformConfig::set_cancellation_window(ctx, cancellation_window)
+    }
+
+    pub fn set_cancellation_fee(
+        ctx: Context<SetPlatformConfig>,
+        cancellation_fee: u16,
+    ) -> Result<()> {
+        SetPlatformConfig::set_cancellation_fee(ctx, cancellation_fee)
+    }
+
+    pub fn set_gating(
+        ctx: Context<SetPlatformConfig>,
+        is_gated: bool,
+        gatekeeper: Pubkey,
+    ) -> Result<()> {
+        SetPlatformConfig::set_gating(ctx, is_gated, gatekeeper)
+    }
+
+    pub fn set_max_bet_amount(ctx: Context<SetPlatformConfig>, max_bet_amount: u64) -> Result<()> {
+        SetPlatformConfig::set_max_bet_amount(ctx, max_bet_amount)
+    }
+
+    pub fn set_max_user_bet_amount_per_round(
+        ctx: Context<SetPlatformConfig>,
+        max_user_bet_amount_per_round: u64,
+    ) -> Result<()> {
+        SetPlatformConfig::set_max_user_bet_amount_per_round(ctx, max_user_bet_amount_per_round)
+    }
+
+    pub fn set_max_round_imbalance(
+        ctx: Context<SetPlatformConfig>,
+        max_round_imbalance: u64,
+        max_one_sided_amount: u64,
+    ) -> Result<()> {
+        SetPlatformConfig::set_max_round_imbalance(ctx, max_round_imbalance, max_one_sided_amount)
+    }
+
+    pub fn set_bucket_boundaries(
+        ctx: Context<SetPlatformConfig>,
+        bucket_boundaries: Vec<i32>,
+    ) -> Result<()> {
+        SetPlatformConfig::set_bucket_boundaries(ctx, bucket_boundaries)
+    }
+
+    pub fn set_strike_offset(ctx: Context<SetPlatformConfig>, strike_offset: i32) -> Result<()> {
+        SetPlatformConfig::set_strike_offset(ctx, strike_offset)
+    }
+
+    pub fn set_strike_authority(
+        ctx: Context<SetPlatformConfig>,
+        strike_authority: Pubkey,
+    ) -> Result<()> {
+        SetPlatformConfig::set_strike_authority(ctx, strike_authority)
+    }
+
+    pub fn initialize_house_pool(
+        ctx: Context<InitializeHousePool>,
+        max_exposure: u16,
+        platform_fee_share: u16,
+    ) -> Result<()> {
+        InitializeHousePool::initialize_house_pool(ctx, max_exposure, platform_fee_share)
+    }
+
+    pub fn set_house_pool_config(
+        ctx: Context<SetHousePoolConfig>,
+        max_exposure: u16,
+        platform_fee_share: u16,
+    ) -> Result<()> {
+        SetHousePoolConfig::set_house_pool_config(ctx, max_exposure, platform_fee_share)
+    }
+
+    pub fn set_fixed_odds_config(
+        ctx: Context<SetHousePoolConfig>,
+        base_multiplier: u64,
+        skew: u16,
+    ) -> Result<()> {
+        SetHousePoolConfig::set_fixed_odds_config(ctx, base_multiplier, skew)
+    }
+
+    pub fn block_user(ctx: Context<BlockUser>, user: Pubkey) -> Result<()> {
+        BlockUser::block_user(ctx, user)
+    }
+
+    pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+        UnblockUser::unblock_user(ctx)
+    }
+
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        user: Pubkey,
+        expires_at: u64,
+    ) -> Result<()> {
+        IssueAttestation::issue_attestation(ctx, user, expires_at)
+    }
+
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        RevokeAttestation::revoke_attestation(ctx)
+    }
+
     pub fn withdraw_platform_fees(ctx: Context<WithdrawPlatformFees>) -> Result<()> {
         WithdrawPlatformFees::withdraw_platform_fees(ctx)
     }
 
+    pub fn whitelist_mint(
+        ctx: Context<WhitelistMint>,
+        allowed_mint_extensions: AllowedMintExtensions,
+    ) -> Result<()> {
+        WhitelistMint::whitelist_mint(ctx, allowed_mint_extensions)
+    }
+
+    pub fn set_mint_market_enabled(ctx: Context<SetMintMarket>, is_enabled: bool) -> Result<()> {
+        SetMintMarket::set_mint_market_enabled(ctx, is_enabled)
+    }
+
+    pub fn set_mint_market_bet_limits(
+        ctx: Context<SetMintMarket>,
+        min_bet_amount: u64,
+        max_bet_amount: u64,
+        max_user_bet_amount_per_round: u64,
+        max_one_sided_amount: u64,
+    ) -> Result<()> {
+        SetMintMarket::set_mint_market_bet_limits(
+            ctx,
+            min_bet_amount,
+            max_bet_amount,
+            max_user_bet_amount_per_round,
+            max_one_sided_amount,
+        )
+    }
+
+    pub fn withdraw_mint_platform_fees(ctx: Context<WithdrawMintPlatformFees>) -> Result<()> {
+        WithdrawMintPlatformFees::withdraw_mint_platform_fees(ctx)
+    }
+
+    pub fn whitelist_native_sol(ctx: Context<WhitelistNativeSol>) -> Result<()> {
+        WhitelistNativeSol::whitelist_native_sol(ctx)
+    }
+
+    pub fn withdraw_native_platform_fees(ctx: Context<WithdrawNativePlatformFees>) -> Result<()> {
+        WithdrawNativePlatformFees::withdraw_native_platform_fees(ctx)
+    }
+
     pub fn deposit(ctx: Context<DepositAndWithdraw>, amount: u64) -> Result<()> {
         DepositAndWithdraw::deposit(ctx, amount)
     }
@@ -81,22 +227,140 @@ pub mod bearish_dot_fun {
         DepositAndWithdraw::withdraw(ctx, amount)
     }
 
+    pub fn deposit_mint(ctx: Context<MintDepositAndWithdraw>, amount: u64) -> Result<()> {
+        MintDepositAndWithdraw::deposit_mint(ctx, amount)
+    }
+
+    pub fn withdraw_mint(ctx: Context<MintDepositAndWithdraw>, amount: u64) -> Result<()> {
+        MintDepositAndWithdraw::withdraw_mint(ctx, amount)
+    }
+
+    pub fn deposit_native_sol(ctx: Context<NativeDepositAndWithdraw>, amount: u64) -> Result<()> {
+        NativeDepositAndWithdraw::deposit_native_sol(ctx, amount)
+    }
+
+    pub fn withdraw_native_sol(ctx: Context<NativeDepositAndWithdraw>, amount: u64) -> Result<()> {
+        NativeDepositAndWithdraw::withdraw_native_sol(ctx, amount)
+    }
+
+    pub fn deposit_liquidity(ctx: Context<HouseLiquidity>, amount: u64) -> Result<()> {
+        HouseLiquidity::deposit_liquidity(ctx, amount)
+    }
+
+    pub fn withdraw_liquidity(ctx: Context<HouseLiquidity>, shares: u64) -> Result<()> {
+        HouseLiquidity::withdraw_liquidity(ctx, shares)
+    }
+
     pub fn set_affiliate(ctx: Context<SetAffiliate>, affiliate: Pubkey) -> Result<()> {
         SetAffiliate::set_affiliate(ctx, affiliate)
     }
 
+    pub fn set_winnings_preference(
+        ctx: Context<SetWinningsPreference>,
+        winnings_preference: WinningsPreference,
+    ) -> Result<()> {
+        SetWinningsPreference::set_winnings_preference(ctx, winnings_preference)
+    }
+
+    pub fn migrate_user_info(ctx: Context<MigrateUserInfo>) -> Result<()> {
+        MigrateUserInfo::migrate_user_info(ctx)
+    }
+
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        delegate: Pubkey,
+        expires_at: u64,
+        max_spend: u64,
+        permissions: SessionPermissions,
+    ) -> Result<()> {
+        CreateSession::create_session(ctx, delegate, expires_at, max_spend, permissions)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        RevokeSession::revoke_session(ctx)
+    }
+
     pub fn start_round(ctx: Context<RunRound>) -> Result<()> {
         RunRound::start_round(ctx)
     }
 
+    pub fn start_round_with_strike(
+        ctx: Context<StartRoundWithStrike>,
+        strike_price: u64,
+    ) -> Result<()> {
+        StartRoundWithStrike::start_round_with_strike(ctx, strike_price)
+    }
+
+    pub fn set_strike_override_pending(
+        ctx: Context<SetStrikeOverridePending>,
+        is_pending: bool,
+    ) -> Result<()> {
+        SetStrikeOverridePending::set_strike_override_pending(ctx, is_pending)
+    }
+
     pub fn end_round(ctx: Context<RunRound>) -> Result<()> {
         RunRound::end_round(ctx)
     }
 
+    pub fn settle_mint_round(ctx: Context<SettleMintRound>, round_index: u64) -> Result<()> {
+        SettleMintRound::settle_mint_round(ctx, round_index)
+    }
+
     pub fn place_bet(ctx: Context<PlaceBet>, amount: u64, is_long: bool) -> Result<()> {
         PlaceBet::place_bet(ctx, amount, is_long)
     }
 
+    pub fn place_bet_from_wallet(
+        ctx: Context<PlaceBetFromWallet>,
+        amount: u64,
+        is_long: bool,
+    ) -> Result<()> {
+        PlaceBetFromWallet::place_bet_from_wallet(ctx, amount, is_long)
+    }
+
+    pub fn place_mint_bet(ctx: Context<PlaceMintBet>, amount: u64, is_long: bool) -> Result<()> {
+        PlaceMintBet::place_mint_bet(ctx, amount, is_long)
+    }
+
+    pub fn place_bucket_bet(ctx: Context<PlaceBucketBet>, amount: u64, bucket: u8) -> Result<()> {
+        PlaceBucketBet::place_bucket_bet(ctx, amount, bucket)
+    }
+
+    pub fn place_fixed_odds_bet(
+        ctx: Context<PlaceFixedOddsBet>,
+        amount: u64,
+        is_long: bool,
+    ) -> Result<()> {
+        PlaceFixedOddsBet::place_fixed_odds_bet(ctx, amount, is_long)
+    }
+
+    pub fn execute_repeat_bet(ctx: Context<ExecuteRepeatBet>) -> Result<()> {
+        ExecuteRepeatBet::execute_repeat_bet(ctx)
+    }
+
+    pub fn create_order(
+        ctx: Context<CreateOrder>,
+        order_id: u64,
+        amount: u64,
+        is_long: bool,
+        condition: OrderCondition,
+        executor_fee: u64,
+    ) -> Result<()> {
+        CreateOrder::create_order(ctx, order_id, amount, is_long, condition, executor_fee)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        CancelOrder::cancel_order(ctx)
+    }
+
+    pub fn execute_order(ctx: Context<ExecuteOrder>) -> Result<()> {
+        ExecuteOrder::execute_order(ctx)
+    }
+
+    pub fn cancel_bet(ctx: Context<CancelBet>, amount: u64) -> Result<()> {
+        CancelBet::cancel_bet(ctx, amount)
+    }
+
     pub fn claim_user_winnings(ctx: Context<ClaimUserWinnings>, round_index: u64) -> Result<()> {
         ClaimUserWinnings::claim_user_winnings(ctx, round_index)
     }
@@ -107,4 +371,51 @@ pub mod bearish_dot_fun {
     ) -> Result<()> {
         ClaimAffiliateWinnings::claim_affiliate_winnings(ctx, round_index)
     }
+
+    pub fn claim_mint_user_winnings(
+        ctx: Context<ClaimMintUserWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        ClaimMintUserWinnings::claim_mint_user_winnings(ctx, round_index)
+    }
+
+    pub fn claim_mint_affiliate_winnings(
+        ctx: Context<ClaimMintAffiliateWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        ClaimMintAffiliateWinnings::claim_mint_affiliate_winnings(ctx, round_index)
+    }
+
+    pub fn claim_bucket_winnings(
+        ctx: Context<ClaimBucketWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        ClaimBucketWinnings::claim_bucket_winnings(ctx, round_index)
+    }
+
+    pub fn claim_fixed_odds_winnings(
+        ctx: Context<ClaimFixedOddsWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        ClaimFixedOddsWinnings::claim_fixed_odds_winnings(ctx, round_index)
+    }
+
+    pub fn claim_native_affiliate_winnings(
+        ctx: Context<ClaimNativeAffiliateWinnings>,
+        round_index: u64,
+    ) -> Result<()> {
+        ClaimNativeAffiliateWinnings::claim_native_affiliate_winnings(ctx, round_index)
+    }
+
+    pub fn quote_bet(ctx: Context<QuoteBet>, amount: u64, is_long: bool) -> Result<BetQuote> {
+        QuoteBet::quote_bet(ctx, amount, is_long)
+    }
+
+    pub fn quote_claim(ctx: Context<QuoteClaim>, round_index: u64) -> Result<ClaimQuote> {
+        QuoteClaim::quote_claim(ctx, round_index)
+    }
+
+    pub fn quote_jackpot(ctx: Context<QuoteJackpot>) -> Result<JackpotQuote> {
+        QuoteJackpot::quote_jackpot(ctx)
+    }
 }
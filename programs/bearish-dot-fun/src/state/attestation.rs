@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::error;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub user: Pubkey,
+    pub gatekeeper: Pubkey,
+    pub expires_at: u64,
+    pub is_revoked: bool,
+
+    pub bump: u8,
+}
+
+impl Attestation {
+    pub fn validate(&self, gatekeeper: &Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        require!(
+            self.gatekeeper == *gatekeeper,
+            error::ErrorCodes::InvalidAttestation
+        );
+        require!(!self.is_revoked, error::ErrorCodes::AttestationRevoked);
+        require!(
+            current_time < self.expires_at,
+            error::ErrorCodes::AttestationExpired
+        );
+
+        Ok(())
+    }
+}
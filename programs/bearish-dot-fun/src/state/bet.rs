@@ -1,6 +1,45 @@
 use anchor_lang::prelude::*;
 
-use crate::error;
+use crate::{error, Round, UserInfo};
+
+/// The limits a bet is checked against, in the base units of the token it is placed in. A max of
+/// 0 disables that check.
+#[derive(Clone, Copy)]
+pub struct BetLimits {
+    pub min_bet_amount: u64,
+    pub max_bet_amount: u64,
+    pub max_user_bet_amount_per_round: u64,
+    pub max_round_imbalance: u64,
+    pub max_one_sided_amount: u64,
+}
+
+impl BetLimits {
+    /// Checks a position of `total_amount` against the minimum bet amount.
+    pub fn validate_amount(&self, total_amount: u64) -> Result<()> {
+        require!(
+            total_amount > self.min_bet_amount,
+            error::ErrorCodes::BetAmountBelowMinBetAmount
+        );
+
+        Ok(())
+    }
+
+    /// Checks a bet of `amount` that brings the user's position in the round to `total_amount`
+    /// against the max bet amount and the per-round cap.
+    pub fn validate_exposure(&self, amount: u64, total_amount: u64) -> Result<()> {
+        require!(
+            self.max_bet_amount == 0 || amount <= self.max_bet_amount,
+            error::ErrorCodes::BetAmountAboveMaxBetAmount
+        );
+        require!(
+            self.max_user_bet_amount_per_round == 0
+                || total_amount <= self.max_user_bet_amount_per_round,
+            error::ErrorCodes::UserRoundExposureExceeded
+        );
+
+        Ok(())
+    }
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -10,17 +49,67 @@ pub struct Bet {
     pub affiliate: Pubkey,
     pub has_claimed_winnings: bool,
     pub has_affiliate_claimed_winnings: bool,
+    /// The payout multiplier in bips locked in for fixed-odds bets. 0 for parimutuel bets.
+    pub multiplier: u64,
 
     pub bump: u8,
 }
 
 impl Bet {
-    pub fn validate_amount(&self, min_bet_amount: u64) -> Result<()> {
-        require!(
-            self.amount > min_bet_amount,
-            error::ErrorCodes::BetAmountBelowMinBetAmount
-        );
+    /// Debits `amount` from the user's balance and adds it to this bet. A bet account with a
+    /// non-zero amount already holds a position in the round, in which case only the pool totals
+    /// grow and the position and affiliate counters are left untouched.
+    pub fn place(
+        &mut self,
+        user_info: &mut UserInfo,
+        round: &mut Round,
+        amount: u64,
+        is_long: bool,
+        bump: u8,
+        bet_limits: &BetLimits,
+    ) -> Result<()> {
+        user_info.debit(amount)?;
 
-        Ok(())
+        if self.amount > 0 {
+            require!(amount > 0, error::ErrorCodes::BetAmountZero);
+            require!(
+                self.is_long == is_long,
+                error::ErrorCodes::CannotBetOnBothSides
+            );
+
+            self.amount += amount;
+
+            if is_long {
+                round.total_bet_amount_long += amount;
+            } else {
+                round.total_bet_amount_short += amount;
+            }
+        } else {
+            self.amount = amount;
+            self.bump = bump;
+
+            if is_long {
+                round.long_positions += 1;
+                round.total_bet_amount_long += amount;
+                self.is_long = true;
+
+                if user_info.affiliate != Pubkey::default() {
+                    self.affiliate = user_info.affiliate;
+                    round.affiliates_for_long_positions += 1;
+                }
+            } else {
+                round.short_positions += 1;
+                round.total_bet_amount_short += amount;
+
+                if user_info.affiliate != Pubkey::default() {
+                    self.affiliate = user_info.affiliate;
+                    round.affiliates_for_short_positions += 1;
+                }
+            }
+        }
+
+        bet_limits.validate_amount(self.amount)?;
+        bet_limits.validate_exposure(amount, self.amount)?;
+        round.validate_imbalance(is_long, bet_limits)
     }
 }
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct BlockedUser {
+    pub user: Pubkey,
+
+    pub bump: u8,
+}
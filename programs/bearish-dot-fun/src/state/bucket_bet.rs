@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{error, BetLimits, Round, UserInfo};
+
+#[account]
+#[derive(InitSpace)]
+pub struct BucketBet {
+    pub amount: u64,
+    pub bucket: u8,
+    pub has_claimed_winnings: bool,
+
+    pub bump: u8,
+}
+
+impl BucketBet {
+    /// Debits `amount` from the user's balance and adds it to the bet's bucket. Like `Bet::place`,
+    /// a bucket bet can be topped up but cannot move to another bucket.
+    pub fn place(
+        &mut self,
+        user_info: &mut UserInfo,
+        round: &mut Round,
+        amount: u64,
+        bucket: u8,
+        bump: u8,
+        bet_limits: &BetLimits,
+    ) -> Result<()> {
+        round.validate_bucket(bucket)?;
+
+        user_info.debit(amount)?;
+
+        if self.amount > 0 {
+            require!(amount > 0, error::ErrorCodes::BetAmountZero);
+            require!(
+                self.bucket == bucket,
+                error::ErrorCodes::CannotBetOnMultipleBuckets
+            );
+        } else {
+            self.bucket = bucket;
+            self.bump = bump;
+
+            round.bucket_positions[bucket as usize] += 1;
+        }
+
+        self.amount += amount;
+        round.bucket_totals[bucket as usize] += amount;
+
+        bet_limits.validate_amount(self.amount)?;
+        bet_limits.validate_exposure(amount, self.amount)
+    }
+}
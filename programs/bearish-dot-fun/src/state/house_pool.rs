@@ -0,0 +1,294 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, utils, Round};
+
+/// Stablecoin liquidity provided by LPs against their shares of `lp_mint`. As bets come in, the
+/// pool bets on the thinner side of the round, up to `max_exposure` (in bips) of its liquidity,
+/// and it also earns `platform_fee_share` (in bips) of the round's platform fees.
+///
+/// The pool is also the counterparty of fixed-odds bets. `reserved_liability` is the worst-case
+/// payout owed on the current round's fixed-odds bets, which LPs cannot withdraw.
+#[account]
+#[derive(InitSpace)]
+pub struct HousePool {
+    pub lp_mint: Pubkey,
+    pub total_liquidity: u64,
+    pub total_shares: u64,
+    pub max_exposure: u16,
+    pub platform_fee_share: u16,
+    pub fixed_odds_base_multiplier: u64,
+    pub fixed_odds_skew: u16,
+    pub reserved_liability: u64,
+
+    pub bump: u8,
+    pub lp_mint_bump: u8,
+}
+
+impl HousePool {
+    pub fn validate_max_exposure(&self) -> Result<()> {
+        require!(
+            self.max_exposure <= constants::general::BPS,
+            error::ErrorCodes::InvalidMaxExposure
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_platform_fee_share(&self) -> Result<()> {
+        require!(
+            self.platform_fee_share <= constants::general::BPS,
+            error::ErrorCodes::ExceedsMaxFee
+        );
+
+        Ok(())
+    }
+
+    /// A base multiplier of 0 disables fixed-odds betting. Otherwise, the multiplier must stay
+    /// above 1x even for bets on the most crowded side.
+    pub fn validate_fixed_odds_config(&self) -> Result<()> {
+        require!(
+            self.fixed_odds_skew <= constants::general::BPS,
+            error::ErrorCodes::InvalidFixedOddsConfig
+        );
+        require!(
+            self.fixed_odds_base_multiplier == 0
+                || utils::math::mul_div_down(
+                    &(self.fixed_odds_base_multiplier as u128),
+                    &((constants::general::BPS - self.fixed_odds_skew) as u128),
+                    &(constants::general::BPS as u128),
+                ) > constants::general::BPS as u128,
+            error::ErrorCodes::InvalidFixedOddsConfig
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_withdrawal(&self, amount: u64) -> Result<()> {
+        let remaining_liquidity = self
+            .total_liquidity
+            .checked_sub(amount)
+            .ok_or(error::ErrorCodes::InsufficientHouseLiquidity)?;
+        require!(
+            remaining_liquidity >= self.reserved_liability,
+            error::ErrorCodes::InsufficientHouseLiquidity
+        );
+
+        Ok(())
+    }
+
+    pub fn available_liquidity(&self) -> Result<u64> {
+        Ok(self
+            .total_liquidity
+            .checked_sub(self.reserved_liability)
+            .ok_or(error::ErrorCodes::InsufficientHouseLiquidity)?)
+    }
+
+    pub fn shares_for_liquidity(&self, amount: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(amount);
+        }
+        require!(
+            self.total_liquidity > 0,
+            error::ErrorCodes::HousePoolDepleted
+        );
+
+        Ok(u64::try_from(utils::math::mul_div_down(
+            &(amount as u128),
+            &(self.total_shares as u128),
+            &(self.total_liquidity as u128),
+        ))
+        .unwrap())
+    }
+
+    pub fn liquidity_for_shares(&self, shares: u64) -> u64 {
+        u64::try_from(utils::math::mul_div_down(
+            &(shares as u128),
+            &(self.total_liquidity as u128),
+            &(self.total_shares as u128),
+        ))
+        .unwrap()
+    }
+
+    /// Applies `change` to the round with the house's position taken out of it, then takes the
+    /// opposite side of the updated round. Every bet and cancellation goes through this while
+    /// the house pool is enabled, so the house's position is settled by the bets alone and is
+    /// fixed before the round's outcome is known.
+    pub fn rebalance<T>(
+        house_pool: Option<&mut HousePool>,
+        round: &mut Round,
+        change: impl FnOnce(&mut Round) -> Result<T>,
+    ) -> Result<T> {
+        let Some(house_pool) = house_pool else {
+            return change(round);
+        };
+
+        house_pool.release_position(round);
+        let result = change(round)?;
+        house_pool.take_opposite_side(round)?;
+
+        Ok(result)
+    }
+
+    /// Takes the house's position out of the round and returns it to the pool.
+    pub fn release_position(&mut self, round: &mut Round) {
+        let house_bet_amount = round.house_bet_amount;
+        if house_bet_amount == 0 {
+            return;
+        }
+
+        self.total_liquidity += house_bet_amount;
+
+        round.house_bet_amount = 0;
+        if round.house_is_long {
+            round.long_positions -= 1;
+            round.total_bet_amount_long -= house_bet_amount;
+        } else {
+            round.short_positions -= 1;
+            round.total_bet_amount_short -= house_bet_amount;
+        }
+    }
+
+    /// Moves pool liquidity into the round on the side with fewer bets, enough to even out the
+    /// round or up to the pool's max exposure, whichever is smaller. The round must not hold a
+    /// house position already.
+    pub fn take_opposite_side(&mut self, round: &mut Round) -> Result<()> {
+        let max_house_bet_amount = u64::try_from(utils::math::mul_div_down(
+            &(self.available_liquidity()? as u128),
+            &(self.max_exposure as u128),
+            &(constants::general::BPS as u128),
+        ))
+        .unwrap();
+        let house_bet_amount = round
+            .total_bet_amount_long
+            .abs_diff(round.total_bet_amount_short)
+            .min(max_house_bet_amount);
+        if house_bet_amount == 0 {
+            return Ok(());
+        }
+
+        self.total_liquidity -= house_bet_amount;
+
+        round.house_bet_amount = house_bet_amount;
+        round.house_is_long = round.total_bet_amount_long < round.total_bet_amount_short;
+        if round.house_is_long {
+            round.long_positions += 1;
+            round.total_bet_amount_long += house_bet_amount;
+        } else {
+            round.short_positions += 1;
+            round.total_bet_amount_short += house_bet_amount;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the house bet and its winnings to the pool if the house side won, and takes the
+    /// pool's share of the platform fee. Returns the house payout and the LP fee.
+    pub fn settle(&mut self, round: &Round, winners_share: u16, platform_fee: u64) -> (u64, u64) {
+        let mut house_payout = 0;
+        if round.house_bet_amount > 0
+            && !round.is_tie()
+            && round.have_longs_won() == round.house_is_long
+        {
+            house_payout =
+                round.house_bet_amount + round.winnings(round.house_bet_amount, winners_share);
+        }
+
+        let mut lp_fee = 0;
+        if self.total_shares > 0 {
+            lp_fee = u64::try_from(utils::math::mul_div_down(
+                &(platform_fee as u128),
+                &(self.platform_fee_share as u128),
+                &(constants::general::BPS as u128),
+            ))
+            .unwrap();
+        }
+
+        self.total_liquidity += house_payout + lp_fee;
+
+        (house_payout, lp_fee)
+    }
+
+    /// Returns the multiplier (in bips) for a fixed-odds bet of `amount` on the given side. The
+    /// base multiplier is scaled down by up to `fixed_odds_skew` for bets that add to the side
+    /// with the larger payout liability, and scaled up by as much for bets on the other side.
+    pub fn fixed_odds_multiplier(&self, round: &Round, amount: u64, is_long: bool) -> Result<u64> {
+        require!(
+            self.fixed_odds_base_multiplier > 0,
+            error::ErrorCodes::FixedOddsDisabled
+        );
+
+        let bps = constants::general::BPS as i128;
+        let (side_payout, opposite_side_payout) = if is_long {
+            (round.fixed_odds_payout_long, round.fixed_odds_payout_short)
+        } else {
+            (round.fixed_odds_payout_short, round.fixed_odds_payout_long)
+        };
+        let side_payout = side_payout as i128
+            + utils::math::mul_div_down(
+                &(amount as u128),
+                &(self.fixed_odds_base_multiplier as u128),
+                &(constants::general::BPS as u128),
+            ) as i128;
+        let opposite_side_payout = opposite_side_payout as i128;
+
+        let imbalance = (side_payout - opposite_side_payout) * bps
+            / (side_payout + opposite_side_payout).max(1);
+        let adjustment = imbalance * self.fixed_odds_skew as i128 / bps;
+
+        Ok(
+            u64::try_from(self.fixed_odds_base_multiplier as i128 * (bps - adjustment) / bps)
+                .unwrap(),
+        )
+    }
+
+    /// Takes the stake of a fixed-odds bet into the pool and reserves the round's worst-case
+    /// payout, failing if the pool can't cover it.
+    pub fn place_fixed_odds(
+        &mut self,
+        round: &mut Round,
+        amount: u64,
+        is_long: bool,
+        multiplier: u64,
+    ) -> Result<()> {
+        let payout = u64::try_from(utils::math::mul_div_down(
+            &(amount as u128),
+            &(multiplier as u128),
+            &(constants::general::BPS as u128),
+        ))
+        .unwrap();
+        if is_long {
+            round.fixed_odds_payout_long += payout;
+        } else {
+            round.fixed_odds_payout_short += payout;
+        }
+
+        self.total_liquidity += amount;
+        self.reserved_liability = round
+            .fixed_odds_payout_long
+            .max(round.fixed_odds_payout_short);
+        require!(
+            self.reserved_liability <= self.total_liquidity,
+            error::ErrorCodes::InsufficientHouseLiquidity
+        );
+
+        Ok(())
+    }
+
+    /// Releases the round's reserved liability and moves the payout owed to winning fixed-odds
+    /// bets out of the pool. Stakes are kept by the pool on a tie.
+    pub fn settle_fixed_odds(&mut self, round: &Round) -> u64 {
+        let mut payout = 0;
+        if !round.is_tie() {
+            payout = if round.have_longs_won() {
+                round.fixed_odds_payout_long
+            } else {
+                round.fixed_odds_payout_short
+            };
+        }
+
+        self.total_liquidity -= payout;
+        self.reserved_liability = 0;
+
+        payout
+    }
+}
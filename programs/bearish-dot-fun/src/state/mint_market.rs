@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{error, BetLimits, GlobalRoundInfo};
+
+/// A whitelisted mint that users can deposit and bet in alongside the platform's stablecoin.
+/// Rounds are shared with the stablecoin market, but balances, pools, the jackpot and platform
+/// fees are all tracked separately for each mint. Native markets are keyed by the native mint but
+/// hold lamports in a system-owned vault instead of a token account.
+///
+/// The platform's bet limits are denominated in the stablecoin, so each mint has its own, in its
+/// base units. Only the round imbalance, which is a ratio, is shared with the stablecoin market.
+/// While it is enabled, bets in a mint fail until the mint's `max_one_sided_amount` is set.
+#[account]
+#[derive(InitSpace)]
+pub struct MintMarket {
+    pub mint: Pubkey,
+    pub is_enabled: bool,
+    pub is_native: bool,
+    pub jackpot_pool_amount: u64,
+    pub accumulated_platform_fees: u64,
+    pub min_bet_amount: u64,
+    pub max_bet_amount: u64,
+    pub max_user_bet_amount_per_round: u64,
+    pub max_one_sided_amount: u64,
+
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl MintMarket {
+    pub fn validate_enabled(&self) -> Result<()> {
+        require!(self.is_enabled, error::ErrorCodes::MintMarketDisabled);
+
+        Ok(())
+    }
+
+    pub fn bet_limits(&self, global_round_info: &GlobalRoundInfo) -> BetLimits {
+        BetLimits {
+            min_bet_amount: self.min_bet_amount,
+            max_bet_amount: self.max_bet_amount,
+            max_user_bet_amount_per_round: self.max_user_bet_amount_per_round,
+            max_round_imbalance: global_round_info.max_round_imbalance,
+            max_one_sided_amount: self.max_one_sided_amount,
+        }
+    }
+
+    pub fn validate_native(&self) -> Result<()> {
+        require!(self.is_native, error::ErrorCodes::MintMarketNotNative);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,23 @@
+pub mod attestation;
 pub mod bet;
+pub mod blocked_user;
+pub mod bucket_bet;
+pub mod house_pool;
+pub mod mint_market;
+pub mod order;
 pub mod platform_config;
 pub mod round;
+pub mod session;
 pub mod user_info;
 
+pub use attestation::*;
 pub use bet::*;
+pub use blocked_user::*;
+pub use bucket_bet::*;
+pub use house_pool::*;
+pub use mint_market::*;
+pub use order::*;
 pub use platform_config::*;
 pub use round::*;
+pub use session::*;
 pub use user_info::*;
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, error, utils, Round};
+
+#[derive(Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub enum OrderCondition {
+    Always,
+    PriceDroppedBy { bps: u16 },
+    PriceRoseBy { bps: u16 },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Order {
+    pub user: Pubkey,
+    pub order_id: u64,
+    pub amount: u64,
+    pub is_long: bool,
+    pub condition: OrderCondition,
+    pub executor_fee: u64,
+    /// The round the order bets in, the one after the round that was current when it was
+    /// created. Its condition is checked against the round before it.
+    pub target_round: u64,
+
+    pub bump: u8,
+}
+
+impl Order {
+    pub fn validate_amount(&self) -> Result<()> {
+        require!(self.amount > 0, error::ErrorCodes::BetAmountZero);
+
+        Ok(())
+    }
+
+    pub fn validate_condition(&self) -> Result<()> {
+        match self.condition {
+            OrderCondition::PriceDroppedBy { bps } | OrderCondition::PriceRoseBy { bps } => {
+                require!(
+                    bps <= constants::general::BPS,
+                    error::ErrorCodes::InvalidOrderCondition
+                )
+            }
+            OrderCondition::Always => {}
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `round` is the round the order bets in.
+    pub fn validate_target_round(&self, round: u64) -> Result<()> {
+        require!(
+            round == self.target_round,
+            error::ErrorCodes::NotOrderTargetRound
+        );
+
+        Ok(())
+    }
+
+    /// Checks the order's condition against the price movement of the previous round.
+    pub fn validate_condition_met(&self, previous_round: &Round) -> Result<()> {
+        let starting_price = previous_round.starting_price;
+        let ending_price = previous_round.ending_price;
+
+        let is_met = match self.condition {
+            OrderCondition::Always => true,
+            OrderCondition::PriceDroppedBy { bps } => {
+                !utils::math::is_greater_than(&ending_price, &starting_price)
+                    && utils::math::mul_div_down(
+                        &((starting_price - ending_price) as u128),
+                        &(constants::general::BPS as u128),
+                        &(starting_price as u128),
+                    ) >= bps as u128
+            }
+            OrderCondition::PriceRoseBy { bps } => {
+                utils::math::is_greater_than(&ending_price, &starting_price)
+                    && utils::math::mul_div_down(
+                        &((ending_price - starting_price) as u128),
+                        &(constants::general::BPS as u128),
+                        &(starting_price as u128),
+                    ) >= bps as u128
+            }
+        };
+        require!(is_met, error::ErrorCodes::OrderConditionNotMet);
+
+        Ok(())
+    }
+}
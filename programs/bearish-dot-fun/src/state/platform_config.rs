@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, error};
+use crate::{constants, error, Attestation, BetLimits, UserAccess};
 
 #[derive(Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct Allocation {
@@ -20,6 +20,38 @@ pub struct JackPotAllocation {
     pub streak_10: u16,
 }
 
+impl From<&Allocation> for bearish_dot_fun_math::Allocation {
+    fn from(allocation: &Allocation) -> Self {
+        Self {
+            winners_share: allocation.winners_share,
+            affiliate_share: allocation.affiliate_share,
+            jackpot_share: allocation.jackpot_share,
+            platform_share: allocation.platform_share,
+        }
+    }
+}
+
+impl From<&JackPotAllocation> for bearish_dot_fun_math::JackPotAllocation {
+    fn from(jackpot_allocation: &JackPotAllocation) -> Self {
+        Self {
+            streak_5: jackpot_allocation.streak_5,
+            streak_6: jackpot_allocation.streak_6,
+            streak_7: jackpot_allocation.streak_7,
+            streak_8: jackpot_allocation.streak_8,
+            streak_9: jackpot_allocation.streak_9,
+            streak_10: jackpot_allocation.streak_10,
+        }
+    }
+}
+
+/// Token-2022 mint extensions that are rejected by default when configuring a mint.
+#[derive(Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct AllowedMintExtensions {
+    pub permanent_delegate: bool,
+    pub non_transferable: bool,
+    pub transfer_hook: bool,
+}
+
 #[derive(Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct GlobalRoundInfo {
     pub round: u64,
@@ -31,6 +63,26 @@ pub struct GlobalRoundInfo {
     pub staleness_threshold: u64,
     pub jackpot_pool_amount: u64,
     pub accumulated_platform_fees: u64,
+    pub cancellation_window: u64,
+    pub cancellation_fee: u16,
+    pub max_bet_amount: u64,
+    pub max_user_bet_amount_per_round: u64,
+    pub max_round_imbalance: u64,
+    /// The most a side may hold while the other side has no bets. Only applies when
+    /// `max_round_imbalance` is set, in which case it must be non-zero.
+    pub max_one_sided_amount: u64,
+}
+
+impl GlobalRoundInfo {
+    pub fn bet_limits(&self) -> BetLimits {
+        BetLimits {
+            min_bet_amount: self.min_bet_amount,
+            max_bet_amount: self.max_bet_amount,
+            max_user_bet_amount_per_round: self.max_user_bet_amount_per_round,
+            max_round_imbalance: self.max_round_imbalance,
+            max_one_sided_amount: self.max_one_sided_amount,
+        }
+    }
 }
 
 #[account]
@@ -39,6 +91,25 @@ pub struct PlatformConfig {
     pub owner: Pubkey,
     pub stablecoin: Pubkey,
     pub global_round_info: GlobalRoundInfo,
+    pub is_gated: bool,
+    pub gatekeeper: Pubkey,
+    /// Price change boundaries (in bips) of the buckets of bucket markets, in increasing order.
+    /// `n` boundaries make `n + 1` buckets. Empty if bucket markets are disabled.
+    #[max_len(7)]
+    pub bucket_boundaries: Vec<i32>,
+    /// Offset (in bips) of the strike price from the starting price of each round, above -100%
+    /// and at most +100%.
+    pub strike_offset: i32,
+    /// Set once the house pool is initialized. From then on, the pool takes its side of each
+    /// round as bets are placed, and must be passed to every instruction that changes a round's
+    /// pools and to `end_round`.
+    pub has_house_pool: bool,
+    /// Can start rounds with an explicit strike price, along with the owner. The default pubkey
+    /// if only the owner can.
+    pub strike_authority: Pubkey,
+    /// Set by the owner or strike authority ahead of `start_round_with_strike`, so that the next
+    /// round cannot be started with a derived strike price in the meantime.
+    pub is_strike_override_pending: bool,
 
     pub bump: u8,
     pub platform_vault_bump: u8,
@@ -93,6 +164,112 @@ impl PlatformConfig {
         Ok(())
     }
 
+    pub fn validate_cancellation_fee(&self) -> Result<()> {
+        require!(
+            self.global_round_info.cancellation_fee <= constants::general::BPS,
+            error::ErrorCodes::ExceedsMaxFee
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_max_round_imbalance(&self) -> Result<()> {
+        let max_round_imbalance = self.global_round_info.max_round_imbalance;
+
+        require!(
+            max_round_imbalance == 0 || max_round_imbalance >= constants::general::BPS as u64,
+            error::ErrorCodes::InvalidMaxRoundImbalance
+        );
+        require!(
+            max_round_imbalance == 0 || self.global_round_info.max_one_sided_amount > 0,
+            error::ErrorCodes::InvalidMaxOneSidedAmount
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_gatekeeper(&self) -> Result<()> {
+        require!(
+            !self.is_gated || self.gatekeeper != Pubkey::default(),
+            error::ErrorCodes::GatekeeperDefaultPubkey
+        );
+
+        Ok(())
+    }
+
+    /// Checks that the user is not on the blocklist and, if gating is enabled, holds a valid
+    /// attestation issued by the gatekeeper.
+    pub fn validate_access(&self, user: &Pubkey, access: &UserAccess) -> Result<()> {
+        access.validate_addresses(user)?;
+
+        let UserAccess {
+            attestation,
+            blocked_user,
+        } = access;
+
+        require!(blocked_user.data_is_empty(), error::ErrorCodes::UserBlocked);
+
+        if self.is_gated {
+            require!(
+                !attestation.data_is_empty() && *attestation.owner == crate::ID,
+                error::ErrorCodes::InvalidAttestation
+            );
+
+            let attestation =
+                Attestation::try_deserialize(&mut &attestation.try_borrow_data()?[..])?;
+            attestation.validate(&self.gatekeeper)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_house_pool(&self, is_house_pool_passed: bool) -> Result<()> {
+        require!(
+            !self.has_house_pool || is_house_pool_passed,
+            error::ErrorCodes::HousePoolRequired
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_bucket_boundaries(&self) -> Result<()> {
+        require!(
+            self.bucket_boundaries.len() < constants::general::MAX_BUCKETS
+                && self
+                    .bucket_boundaries
+                    .windows(2)
+                    .all(|pair| pair[0] < pair[1]),
+            error::ErrorCodes::InvalidBucketBoundaries
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_strike_offset(&self) -> Result<()> {
+        require!(
+            self.strike_offset > -(constants::general::BPS as i32)
+                && self.strike_offset <= constants::general::BPS as i32,
+            error::ErrorCodes::InvalidStrikeOffset
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_strike_authority(&self, authority: &Pubkey) -> Result<()> {
+        require!(
+            *authority == self.owner
+                || (self.strike_authority != Pubkey::default()
+                    && *authority == self.strike_authority),
+            error::ErrorCodes::NotStrikeAuthority
+        );
+
+        Ok(())
+    }
+
+    pub fn strike_price(&self, starting_price: u64) -> u64 {
+        bearish_dot_fun_math::strike_price(starting_price, self.strike_offset)
+    }
+
     pub fn validate_price_account(&self) -> Result<()> {
         require!(
             self.global_round_info.price_account != Pubkey::default(),
@@ -1,6 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::error;
+use crate::{constants, error, utils, Allocation, Bet, BetLimits, BucketBet, PlatformConfig};
+
+#[derive(Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum RoundOutcome {
+    LongsWon,
+    ShortsWon,
+    /// The round closed at the strike price.
+    Tie,
+    /// Nobody bet on the winning side, so the winners and affiliate shares roll over into the
+    /// jackpot.
+    OneSided,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -8,17 +19,54 @@ pub struct Round {
     pub start_time: u64,
     pub starting_price: u64,
     pub ending_price: u64,
+    /// The price the ending price is compared against. Longs win if the round closes above it.
+    pub strike_price: u64,
     pub long_positions: u64,
     pub short_positions: u64,
     pub affiliates_for_long_positions: u64,
     pub affiliates_for_short_positions: u64,
     pub total_bet_amount_long: u64,
     pub total_bet_amount_short: u64,
+    pub house_bet_amount: u64,
+    pub house_is_long: bool,
+    pub fixed_odds_payout_long: u64,
+    pub fixed_odds_payout_short: u64,
+    /// Snapshot of the platform's bucket boundaries at the start of the round.
+    #[max_len(7)]
+    pub bucket_boundaries: Vec<i32>,
+    pub bucket_totals: [u64; constants::general::MAX_BUCKETS],
+    pub bucket_positions: [u64; constants::general::MAX_BUCKETS],
+    pub winning_bucket: u8,
+    /// Snapshot of the platform's allocation when the round was settled. Winnings are claimed at
+    /// it, so changing the allocation doesn't change what the round already set aside.
+    pub allocation: Allocation,
 
     pub bump: u8,
 }
 
 impl Round {
+    /// Starts the round at `starting_price`. The strike defaults to the starting price moved by
+    /// the platform's strike offset, unless an explicit `strike_price` is given.
+    pub fn start(
+        &mut self,
+        starting_price: u64,
+        strike_price: Option<u64>,
+        platform_config: &PlatformConfig,
+        bump: u8,
+    ) -> Result<()> {
+        require!(self.start_time == 0, error::ErrorCodes::RoundAlreadyStarted);
+
+        self.start_time = Clock::get()?.unix_timestamp as u64;
+        self.starting_price = starting_price;
+        self.strike_price =
+            strike_price.unwrap_or_else(|| platform_config.strike_price(starting_price));
+        self.bucket_boundaries = platform_config.bucket_boundaries.clone();
+        self.bump = bump;
+
+        self.validate_starting_price()?;
+        self.validate_strike_price()
+    }
+
     pub fn validate_starting_price(&self) -> Result<()> {
         require!(
             self.starting_price > 0,
@@ -28,12 +76,61 @@ impl Round {
         Ok(())
     }
 
+    pub fn validate_strike_price(&self) -> Result<()> {
+        require!(self.strike_price > 0, error::ErrorCodes::PriceCannotBeZero);
+
+        Ok(())
+    }
+
     pub fn validate_ending_price(&self) -> Result<()> {
         require!(self.ending_price > 0, error::ErrorCodes::PriceCannotBeZero);
 
         Ok(())
     }
 
+    /// Checks that the side given by `is_long` is at most `max_round_imbalance` (in bips) times
+    /// the opposite side's pool, or at most `max_one_sided_amount` while the opposite side has no
+    /// bets. A `max_round_imbalance` of 0 disables the check.
+    pub fn validate_imbalance(&self, is_long: bool, bet_limits: &BetLimits) -> Result<()> {
+        let (side_amount, opposite_side_amount) = if is_long {
+            (self.total_bet_amount_long, self.total_bet_amount_short)
+        } else {
+            (self.total_bet_amount_short, self.total_bet_amount_long)
+        };
+
+        if bet_limits.max_round_imbalance == 0 {
+            return Ok(());
+        }
+
+        let max_side_amount = if opposite_side_amount == 0 {
+            bet_limits.max_one_sided_amount as u128
+        } else {
+            utils::math::mul_div_down(
+                &(opposite_side_amount as u128),
+                &(bet_limits.max_round_imbalance as u128),
+                &(constants::general::BPS as u128),
+            )
+        };
+        require!(
+            side_amount as u128 <= max_side_amount,
+            error::ErrorCodes::RoundImbalanceExceeded
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_cancellation_window(&self, cancellation_window: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let elapsed = current_time.checked_sub(self.start_time).unwrap();
+
+        require!(
+            elapsed < cancellation_window,
+            error::ErrorCodes::CancellationWindowElapsed
+        );
+
+        Ok(())
+    }
+
     pub fn validate_round_duration(&self, min_duration: u64) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp as u64;
         let duration = current_time.checked_sub(self.start_time).unwrap();
@@ -45,4 +142,201 @@ impl Round {
 
         Ok(())
     }
+
+    pub fn is_tie(&self) -> bool {
+        self.ending_price == self.strike_price
+    }
+
+    pub fn have_longs_won(&self) -> bool {
+        utils::math::is_greater_than(&self.ending_price, &self.strike_price)
+    }
+
+    pub fn outcome(&self) -> RoundOutcome {
+        let (winning_side_amount, outcome) = if self.have_longs_won() {
+            (self.total_bet_amount_long, RoundOutcome::LongsWon)
+        } else {
+            (self.total_bet_amount_short, RoundOutcome::ShortsWon)
+        };
+
+        if self.is_tie() {
+            RoundOutcome::Tie
+        } else if winning_side_amount == 0 {
+            RoundOutcome::OneSided
+        } else {
+            outcome
+        }
+    }
+
+    /// Returns the winners and affiliate shares of the losing side's pool that winning bets and
+    /// their affiliates claim from. Both are 0 on a tie or if nobody bet on the winning side.
+    pub fn distributable_pools(&self, allocation: &Allocation) -> (u64, u64) {
+        let losing_side_amount = match self.outcome() {
+            RoundOutcome::LongsWon => self.total_bet_amount_short,
+            RoundOutcome::ShortsWon => self.total_bet_amount_long,
+            RoundOutcome::Tie | RoundOutcome::OneSided => return (0, 0),
+        };
+
+        bearish_dot_fun_math::distributable_pools(losing_side_amount, &allocation.into())
+    }
+
+    /// Returns the amounts of the round's pools that go to the jackpot and to the platform once
+    /// the round has ended. On a tie with the strike price, everything except the platform share
+    /// rolls over into the jackpot. Otherwise the jackpot and platform shares are taken from the
+    /// losing side, and the winners and affiliate shares roll over into the jackpot too if nobody
+    /// bet on the winning side.
+    pub fn settle(&self, allocation: &Allocation) -> (u64, u64) {
+        let (winning_side_amount, losing_side_amount) = self.sides(self.have_longs_won());
+
+        bearish_dot_fun_math::settle(
+            winning_side_amount,
+            losing_side_amount,
+            self.is_tie(),
+            &allocation.into(),
+        )
+    }
+
+    pub fn validate_claim(&self, bet: &Bet) -> Result<()> {
+        let have_longs_won = self.have_longs_won();
+        require!(
+            !self.is_tie()
+                && ((have_longs_won && bet.is_long) || (!have_longs_won && !bet.is_long)),
+            error::ErrorCodes::IneligibleForClaim
+        );
+
+        Ok(())
+    }
+
+    /// Checks that `bet` can be claimed: the round has ended, the bet is on the winning side and
+    /// it hasn't been claimed yet.
+    pub fn validate_claimable(&self, bet: &Bet) -> Result<()> {
+        require!(
+            self.ending_price != 0,
+            error::ErrorCodes::RoundHasNotEndedYet
+        );
+        require!(
+            !bet.has_claimed_winnings,
+            error::ErrorCodes::AlreadyClaimedWinnings
+        );
+
+        self.validate_claim(bet)
+    }
+
+    /// Returns the pro-rata share of the winners' cut of the losing side's pool for a winning
+    /// position of `amount`. The amount itself is not included.
+    pub fn winnings(&self, amount: u64, winners_share: u16) -> u64 {
+        self.winnings_if_won(amount, self.have_longs_won(), winners_share)
+    }
+
+    /// Same as `winnings`, as if the side given by `is_long` wins the round with the current
+    /// pools.
+    pub fn winnings_if_won(&self, amount: u64, is_long: bool, winners_share: u16) -> u64 {
+        let (winning_side_amount, losing_side_amount) = self.sides(is_long);
+
+        bearish_dot_fun_math::winnings(
+            amount,
+            winning_side_amount,
+            losing_side_amount,
+            winners_share,
+        )
+    }
+
+    /// Returns the pools of the side given by `is_long` and of the opposite side.
+    fn sides(&self, is_long: bool) -> (u64, u64) {
+        if is_long {
+            (self.total_bet_amount_long, self.total_bet_amount_short)
+        } else {
+            (self.total_bet_amount_short, self.total_bet_amount_long)
+        }
+    }
+
+    /// Returns an equal share of the affiliates' cut of the losing side's pool, split between all
+    /// affiliates of winning positions.
+    pub fn affiliate_winnings(&self, affiliate_share: u16) -> u64 {
+        let (affiliates, losing_side_amount) = if self.have_longs_won() {
+            (
+                self.affiliates_for_long_positions,
+                self.total_bet_amount_short,
+            )
+        } else {
+            (
+                self.affiliates_for_short_positions,
+                self.total_bet_amount_long,
+            )
+        };
+
+        bearish_dot_fun_math::affiliate_winnings(affiliates, losing_side_amount, affiliate_share)
+    }
+
+    pub fn has_buckets(&self) -> bool {
+        !self.bucket_boundaries.is_empty()
+    }
+
+    /// Returns the price change over the round in bips, rounded towards zero.
+    pub fn price_change(&self) -> i128 {
+        (self.ending_price as i128 - self.starting_price as i128) * constants::general::BPS as i128
+            / self.starting_price as i128
+    }
+
+    /// Returns the bucket the round's price change falls into. Each boundary belongs to the
+    /// bucket above it.
+    pub fn bucket_for_price_change(&self) -> u8 {
+        let price_change = self.price_change();
+
+        self.bucket_boundaries
+            .iter()
+            .filter(|boundary| **boundary as i128 <= price_change)
+            .count() as u8
+    }
+
+    pub fn validate_bucket(&self, bucket: u8) -> Result<()> {
+        require!(
+            self.has_buckets() && (bucket as usize) <= self.bucket_boundaries.len(),
+            error::ErrorCodes::InvalidBucket
+        );
+
+        Ok(())
+    }
+
+    /// Same as `settle`, for the round's bucket pools. The winning bucket takes the place of the
+    /// winning side and all other buckets are losing. There are no ties.
+    pub fn settle_buckets(&self, allocation: &Allocation) -> (u64, u64) {
+        let (winning_bucket_amount, losing_buckets_amount) = self.bucket_sides();
+
+        bearish_dot_fun_math::settle(
+            winning_bucket_amount,
+            losing_buckets_amount,
+            false,
+            &allocation.into(),
+        )
+    }
+
+    /// Returns the pool of the winning bucket and the pools of all other buckets combined.
+    fn bucket_sides(&self) -> (u64, u64) {
+        let winning_bucket_amount = self.bucket_totals[self.winning_bucket as usize];
+        let losing_buckets_amount = self.bucket_totals.iter().sum::<u64>() - winning_bucket_amount;
+
+        (winning_bucket_amount, losing_buckets_amount)
+    }
+
+    pub fn validate_bucket_claim(&self, bucket_bet: &BucketBet) -> Result<()> {
+        require!(
+            self.has_buckets() && bucket_bet.bucket == self.winning_bucket,
+            error::ErrorCodes::IneligibleForClaim
+        );
+
+        Ok(())
+    }
+
+    /// Same as `winnings`, for a position of `amount` in the winning bucket. Bucket bets have no
+    /// affiliates, so `winners_share` is expected to include the affiliate share.
+    pub fn bucket_winnings(&self, amount: u64, winners_share: u16) -> u64 {
+        let (winning_bucket_amount, losing_buckets_amount) = self.bucket_sides();
+
+        bearish_dot_fun_math::winnings(
+            amount,
+            winning_bucket_amount,
+            losing_buckets_amount,
+            winners_share,
+        )
+    }
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::error;
+
+#[derive(Clone, Copy, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct SessionPermissions {
+    pub place_bet: bool,
+    pub claim_winnings: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Session {
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: u64,
+    pub max_spend: u64,
+    pub spent: u64,
+    pub permissions: SessionPermissions,
+
+    pub bump: u8,
+}
+
+impl Session {
+    pub fn validate_delegate(&self) -> Result<()> {
+        require!(
+            self.delegate != Pubkey::default() && self.delegate != self.user,
+            error::ErrorCodes::InvalidDelegate
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_expiry(&self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        require!(
+            current_time < self.expires_at,
+            error::ErrorCodes::SessionExpired
+        );
+
+        Ok(())
+    }
+
+    /// Checks that the delegate may place a bet of `amount` and records it against the
+    /// session's spending cap.
+    pub fn spend(&mut self, amount: u64) -> Result<()> {
+        self.validate_expiry()?;
+        require!(
+            self.permissions.place_bet,
+            error::ErrorCodes::SessionPermissionDenied
+        );
+
+        self.spent = self
+            .spent
+            .checked_add(amount)
+            .ok_or(error::ErrorCodes::SessionSpendCapExceeded)?;
+
+        require!(
+            self.spent <= self.max_spend,
+            error::ErrorCodes::SessionSpendCapExceeded
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_claim(&self) -> Result<()> {
+        self.validate_expiry()?;
+        require!(
+            self.permissions.claim_winnings,
+            error::ErrorCodes::SessionPermissionDenied
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::error;
+use crate::{error, JackPotAllocation};
+
+#[derive(Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub enum WinningsPreference {
+    KeepInBalance,
+    WithdrawToWallet { token_account: Pubkey },
+    RepeatBet { amount: u64, is_long: bool },
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -11,12 +18,67 @@ pub struct UserInfo {
     pub times_won: u64,
 
     pub bump: u8,
+
+    // Fields below were added after the first deployment. Accounts created before them are
+    // grown to this layout with `migrate_user_info`, and new fields must be appended here.
+    /// The wallet the account belongs to, so that keepers can act on users found by their
+    /// winnings preference.
+    pub user: Pubkey,
+    pub winnings_preference: WinningsPreference,
 }
 
 impl UserInfo {
+    /// Takes `amount` out of the user's balance, failing rather than underflowing if they don't
+    /// have enough.
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self
+            .amount
+            .checked_sub(amount)
+            .ok_or(error::ErrorCodes::InsufficientBalance)?;
+
+        Ok(())
+    }
+
     pub fn validate_affiliate(&self, user: &Pubkey) -> Result<()> {
         require!(self.affiliate != *user, error::ErrorCodes::InvalidAffiliate);
 
         Ok(())
     }
+
+    pub fn validate_winnings_preference(&self) -> Result<()> {
+        match self.winnings_preference {
+            WinningsPreference::WithdrawToWallet { token_account } => require!(
+                token_account != Pubkey::default(),
+                error::ErrorCodes::InvalidPayoutTokenAccount
+            ),
+            WinningsPreference::RepeatBet { amount, .. } => {
+                require!(amount > 0, error::ErrorCodes::BetAmountZero)
+            }
+            WinningsPreference::KeepInBalance => {}
+        }
+
+        Ok(())
+    }
+
+    /// Records a win in round `round_index` on the user's streak and returns the streak jackpot
+    /// they are entitled to, if any, deducting it from `jackpot_pool_amount`. See
+    /// [`bearish_dot_fun_math::Streak::record_win`].
+    pub fn record_win(
+        &mut self,
+        round_index: u64,
+        jackpot_allocation: &JackPotAllocation,
+        jackpot_pool_amount: &mut u64,
+    ) -> u64 {
+        let mut streak = bearish_dot_fun_math::Streak {
+            last_won_round: self.last_won_round,
+            times_won: self.times_won,
+        };
+        let jackpot_amount =
+            streak.record_win(round_index, &jackpot_allocation.into(), jackpot_pool_amount);
+
+        self.last_won_round = streak.last_won_round;
+        self.times_won = streak.times_won;
+
+        jackpot_amount
+    }
 }
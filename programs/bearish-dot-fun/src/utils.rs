@@ -1,21 +1,12 @@
 pub mod math {
-    pub fn is_greater_than(x: &u64, y: &u64) -> bool {
-        if *x > *y {
-            true
-        } else {
-            false
-        }
-    }
-
-    pub fn mul_div_down(x: &u128, y: &u128, d: &u128) -> u128 {
-        x.checked_mul(*y).unwrap().checked_div(*d).unwrap()
-    }
+    pub use bearish_dot_fun_math::{is_greater_than, mul_div_down};
 }
 
 pub mod general {
     use anchor_lang::prelude::*;
     use pyth_sdk_solana::state::SolanaPriceAccount;
 
+    #[allow(clippy::needless_borrow)]
     pub fn get_price(price_account: &AccountInfo, staleness_threshold: u64) -> u64 {
         let price_account = SolanaPriceAccount::account_info_to_feed(&price_account).unwrap();
         let current_time = Clock::get().unwrap().unix_timestamp;
@@ -25,4 +16,86 @@ pub mod general {
             .unwrap()
             .price as u64
     }
+
+    /// Returns the lamports `account` holds above its rent exempt minimum.
+    pub fn lamports_above_rent(account: &AccountInfo) -> Result<u64> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(account.data_len());
+
+        Ok(account.lamports().saturating_sub(rent_exempt_minimum))
+    }
+}
+
+pub mod token {
+    use anchor_lang::prelude::*;
+    use anchor_spl::token_interface::spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Mint,
+    };
+
+    use crate::{error, AllowedMintExtensions};
+
+    /// Rejects Token-2022 mints with extensions that let tokens leave the vault without the
+    /// program's involvement, or that would make transfers into and out of the vault fail,
+    /// unless they have been explicitly allowed. Legacy token program mints are always accepted.
+    pub fn validate_mint_extensions(
+        mint: &AccountInfo,
+        allowed_mint_extensions: &AllowedMintExtensions,
+    ) -> Result<()> {
+        if *mint.owner != spl_token_2022::ID {
+            return Ok(());
+        }
+
+        let mint_data = mint.try_borrow_data()?;
+        let mint_with_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+        for extension_type in mint_with_extensions.get_extension_types()? {
+            let is_allowed = match extension_type {
+                ExtensionType::PermanentDelegate => allowed_mint_extensions.permanent_delegate,
+                ExtensionType::NonTransferable => allowed_mint_extensions.non_transferable,
+                ExtensionType::TransferHook => allowed_mint_extensions.transfer_hook,
+                _ => true,
+            };
+            require!(is_allowed, error::ErrorCodes::UnsupportedMintExtension);
+        }
+
+        Ok(())
+    }
+}
+
+pub mod native {
+    use anchor_lang::{
+        prelude::*,
+        system_program::{transfer, Transfer},
+    };
+    use anchor_spl::token::spl_token;
+
+    use crate::constants;
+
+    /// Moves lamports out of the native SOL vault, signing for the vault PDA.
+    pub fn transfer_from_native_vault<'info>(
+        native_vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        native_vault_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let native_vault_bump = &[native_vault_bump];
+        let native_vault_signer = &[&[
+            constants::seeds::PLATFORM_VAULT,
+            spl_token::native_mint::ID.as_ref(),
+            native_vault_bump,
+        ][..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Transfer {
+                    from: native_vault.clone(),
+                    to: to.clone(),
+                },
+                native_vault_signer,
+            ),
+            amount,
+        )
+    }
 }
@@ -0,0 +1,600 @@
+//! In-process harness for the program's Rust tests. The program runs natively under `cargo test`,
+//! or as the built shared object under `cargo test-sbf`. The bank's clock and the Pyth price
+//! account the rounds settle against are both controlled by the test.
+
+#![allow(dead_code)]
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, instruction::Instruction},
+    system_program, AccountDeserialize, InstructionData,
+};
+use anchor_spl::{
+    associated_token::{
+        get_associated_token_address, spl_associated_token_account::instruction as ata,
+    },
+    token::spl_token::{self, instruction as token, state::Mint},
+};
+use bearish_dot_fun::{Allocation, AllowedMintExtensions, GlobalRoundInfo, JackPotAllocation};
+use bearish_dot_fun_client::{instructions, pda};
+use pyth_sdk_solana::state::{AccountType, PriceStatus, SolanaPriceAccount, MAGIC, VERSION_2};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+pub const DECIMALS: u8 = 6;
+pub const EXPO: i32 = -8;
+pub const DURATION: u64 = 60;
+pub const STALENESS_THRESHOLD: u64 = 30;
+
+pub fn sample_global_round_info(price_account: Pubkey) -> GlobalRoundInfo {
+    GlobalRoundInfo {
+        round: 0,
+        duration: DURATION,
+        allocation: Allocation {
+            winners_share: 4_500,
+            affiliate_share: 500,
+            jackpot_share: 4_000,
+            platform_share: 1_000,
+        },
+        jackpot_allocation: JackPotAllocation {
+            streak_5: 1_000,
+            streak_6: 1_500,
+            streak_7: 2_000,
+            streak_8: 2_500,
+            streak_9: 3_000,
+            streak_10: 10_000,
+        },
+        min_bet_amount: 0,
+        price_account,
+        staleness_threshold: STALENESS_THRESHOLD,
+        jackpot_pool_amount: 0,
+        accumulated_platform_fees: 0,
+        cancellation_window: 0,
+        cancellation_fee: 0,
+        max_bet_amount: 0,
+        max_user_bet_amount_per_round: 0,
+        max_round_imbalance: 0,
+        max_one_sided_amount: 0,
+    }
+}
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    bearish_dot_fun::entry(program_id, narrow(accounts), instruction_data)
+}
+
+fn process_mock_oracle_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    mock_oracle::entry(program_id, narrow(accounts), instruction_data)
+}
+
+/// The anchor entrypoints tie the lifetime of the account slice to the accounts themselves, which
+/// the native processor signature doesn't. Narrows the accounts to the slice's lifetime, which
+/// covers the whole call.
+fn narrow<'a>(accounts: &'a [AccountInfo]) -> &'a [AccountInfo<'a>] {
+    // SAFETY: the accounts outlive the slice, and the entrypoints don't keep any of their
+    // borrows past the call.
+    unsafe { std::mem::transmute(accounts) }
+}
+
+pub fn mock_price_account(authority: &Pubkey, feed_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            mock_oracle::PRICE_ACCOUNT_SEED,
+            authority.as_ref(),
+            feed_id.to_be_bytes().as_ref(),
+        ],
+        &mock_oracle::ID,
+    )
+    .0
+}
+
+pub struct TestContext {
+    pub context: ProgramTestContext,
+    pub owner: Keypair,
+    pub stablecoin: Pubkey,
+    pub price_account: Pubkey,
+}
+
+impl TestContext {
+    /// Starts a bank with the program and an uninitialized platform. The stablecoin mint is owned
+    /// by `owner`, and the price account holds no price until [`TestContext::set_price`].
+    pub async fn new() -> Self {
+        let mut program_test = ProgramTest::new(
+            "bearish_dot_fun",
+            bearish_dot_fun::ID,
+            processor!(process_instruction),
+        );
+        program_test.add_program(
+            "mock_oracle",
+            mock_oracle::ID,
+            processor!(process_mock_oracle_instruction),
+        );
+
+        let owner = Keypair::new();
+        program_test.add_account(
+            owner.pubkey(),
+            Account::new(100_000_000_000, 0, &system_program::ID),
+        );
+
+        let stablecoin = Pubkey::new_unique();
+        let mut mint_data = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                mint_authority: Some(owner.pubkey()).into(),
+                supply: 0,
+                decimals: DECIMALS,
+                is_initialized: true,
+                freeze_authority: None.into(),
+            },
+            &mut mint_data,
+        )
+        .unwrap();
+        program_test.add_account(
+            stablecoin,
+            Account {
+                lamports: 1_000_000_000,
+                data: mint_data,
+                owner: spl_token::ID,
+                ..Account::default()
+            },
+        );
+
+        Self {
+            context: program_test.start_with_context().await,
+            owner,
+            stablecoin,
+            price_account: Pubkey::new_unique(),
+        }
+    }
+
+    /// Starts a bank like [`TestContext::new`] and initializes the platform with
+    /// [`sample_global_round_info`] and a fresh price.
+    pub async fn initialized(price: i64) -> Self {
+        let mut test = Self::new().await;
+        test.set_price(price).await;
+        test.initialize(sample_global_round_info(test.price_account))
+            .await
+            .unwrap();
+
+        test
+    }
+
+    pub fn payer(&self) -> Keypair {
+        self.context.payer.insecure_clone()
+    }
+
+    pub async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), BanksClientError> {
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+
+        let blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
+    /// Moves the bank to the next slot and its clock `seconds` forward. Prices published before
+    /// the warp age with it. The new slot comes with a new blockhash, so a transaction that failed
+    /// before the warp can be sent again unchanged.
+    pub async fn warp(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.slot += 1;
+        clock.unix_timestamp += seconds;
+        self.context.warp_to_slot(clock.slot).unwrap();
+        self.context.set_sysvar(&clock);
+    }
+
+    /// Publishes `price` at the current time.
+    pub async fn set_price(&mut self, price: i64) {
+        let publish_time = self.clock().await.unix_timestamp;
+        self.set_price_at(price, publish_time);
+    }
+
+    /// Writes a trading Pyth price account holding `price` (with exponent [`EXPO`]) published at
+    /// `publish_time`.
+    pub fn set_price_at(&mut self, price: i64, publish_time: i64) {
+        let mut price_account = SolanaPriceAccount {
+            magic: MAGIC,
+            ver: VERSION_2,
+            atype: AccountType::Price as u32,
+            size: std::mem::size_of::<SolanaPriceAccount>() as u32,
+            expo: EXPO,
+            timestamp: publish_time,
+            prev_price: price,
+            prev_timestamp: publish_time,
+            ..SolanaPriceAccount::default()
+        };
+        price_account.agg.price = price;
+        price_account.agg.conf = 1;
+        price_account.agg.status = PriceStatus::Trading;
+
+        self.context.set_account(
+            &self.price_account,
+            &Account {
+                lamports: 1_000_000_000,
+                data: bytemuck::bytes_of(&price_account).to_vec(),
+                owner: Pubkey::new_unique(),
+                ..Account::default()
+            }
+            .into(),
+        );
+    }
+
+    /// Sets the price of the owner's mock oracle feed `feed_id` through the mock oracle program.
+    pub async fn set_mock_price(
+        &mut self,
+        feed_id: u64,
+        price: i64,
+        conf: u64,
+        expo: i32,
+        publish_time: i64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owner = self.owner.insecure_clone();
+        let set_price = Instruction {
+            program_id: mock_oracle::ID,
+            accounts: mock_oracle::accounts::SetPrice {
+                authority: owner.pubkey(),
+                price_account: mock_price_account(&owner.pubkey(), feed_id),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: mock_oracle::instruction::SetPrice {
+                feed_id,
+                price,
+                conf,
+                expo,
+                publish_time,
+            }
+            .data(),
+        };
+
+        self.send(&[set_price], &[&owner]).await
+    }
+
+    pub async fn initialize(
+        &mut self,
+        global_round_info: GlobalRoundInfo,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owner = self.owner.insecure_clone();
+        self.send(
+            &[instructions::initialize(
+                owner.pubkey(),
+                self.stablecoin,
+                spl_token::ID,
+                global_round_info,
+                AllowedMintExtensions {
+                    permanent_delegate: false,
+                    non_transferable: false,
+                    transfer_hook: false,
+                },
+            )],
+            &[&owner],
+        )
+        .await
+    }
+
+    /// Creates a funded user holding `amount` stablecoins in their associated token account.
+    pub async fn create_user(&mut self, amount: u64) -> Keypair {
+        let user = Keypair::new();
+        self.context.set_account(
+            &user.pubkey(),
+            &Account::new(10_000_000_000, 0, &system_program::ID).into(),
+        );
+        self.create_token_account(&user.pubkey(), amount).await;
+
+        user
+    }
+
+    /// Creates the associated token account of `owner` and mints `amount` stablecoins to it.
+    pub async fn create_token_account(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let mint_authority = self.owner.insecure_clone();
+        let token_account = self.token_account(owner);
+
+        let mut instructions = vec![ata::create_associated_token_account(
+            &mint_authority.pubkey(),
+            owner,
+            &self.stablecoin,
+            &spl_token::ID,
+        )];
+        if amount > 0 {
+            instructions.push(
+                token::mint_to(
+                    &spl_token::ID,
+                    &self.stablecoin,
+                    &token_account,
+                    &mint_authority.pubkey(),
+                    &[],
+                    amount,
+                )
+                .unwrap(),
+            );
+        }
+        self.send(&instructions, &[&mint_authority]).await.unwrap();
+
+        token_account
+    }
+
+    pub fn token_account(&self, user: &Pubkey) -> Pubkey {
+        get_associated_token_address(user, &self.stablecoin)
+    }
+
+    pub async fn deposit(
+        &mut self,
+        user: &Keypair,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let token_account = self.token_account(&user.pubkey());
+        self.send(
+            &[instructions::deposit(
+                user.pubkey(),
+                self.stablecoin,
+                token_account,
+                spl_token::ID,
+                amount,
+            )],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn withdraw(
+        &mut self,
+        user: &Keypair,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let token_account = self.token_account(&user.pubkey());
+        self.send(
+            &[instructions::withdraw(
+                user.pubkey(),
+                self.stablecoin,
+                token_account,
+                spl_token::ID,
+                amount,
+            )],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn current_round(&mut self) -> u64 {
+        pda::current_round(&self.platform_config().await.global_round_info)
+    }
+
+    pub async fn start_round(&mut self) -> std::result::Result<(), BanksClientError> {
+        let round = self.current_round().await;
+        let payer = self.payer();
+        self.send(
+            &[instructions::start_round(
+                payer.pubkey(),
+                round,
+                self.price_account,
+            )],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn place_bet(
+        &mut self,
+        user: &Keypair,
+        amount: u64,
+        is_long: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let platform_config = self.platform_config().await;
+        let round = pda::current_round(&platform_config.global_round_info);
+        self.send(
+            &[instructions::place_bet(
+                user.pubkey(),
+                user.pubkey(),
+                round,
+                platform_config.has_house_pool,
+                amount,
+                is_long,
+            )],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn end_round(&mut self) -> std::result::Result<(), BanksClientError> {
+        let platform_config = self.platform_config().await;
+        let round = pda::current_round(&platform_config.global_round_info);
+        let payer = self.payer();
+        self.send(
+            &[instructions::end_round(
+                payer.pubkey(),
+                round,
+                self.price_account,
+                platform_config.has_house_pool,
+            )],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn claim_user_winnings(
+        &mut self,
+        user: &Keypair,
+        round: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        self.send(
+            &[instructions::claim_user_winnings(
+                user.pubkey(),
+                user.pubkey(),
+                round,
+                None,
+            )],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn initialize_house_pool(
+        &mut self,
+        max_exposure: u16,
+        platform_fee_share: u16,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owner = self.owner.insecure_clone();
+        self.send(
+            &[instructions::initialize_house_pool(
+                owner.pubkey(),
+                self.stablecoin,
+                spl_token::ID,
+                max_exposure,
+                platform_fee_share,
+            )],
+            &[&owner],
+        )
+        .await
+    }
+
+    pub fn lp_token_account(&self, liquidity_provider: &Pubkey) -> Pubkey {
+        get_associated_token_address(liquidity_provider, &pda::lp_mint())
+    }
+
+    /// Deposits `amount` of the liquidity provider's stablecoins into the house pool, creating
+    /// their LP token account if they don't have one yet.
+    pub async fn deposit_liquidity(
+        &mut self,
+        liquidity_provider: &Keypair,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let payer = self.payer();
+        self.send(
+            &[
+                ata::create_associated_token_account_idempotent(
+                    &payer.pubkey(),
+                    &liquidity_provider.pubkey(),
+                    &pda::lp_mint(),
+                    &spl_token::ID,
+                ),
+                instructions::deposit_liquidity(
+                    liquidity_provider.pubkey(),
+                    self.stablecoin,
+                    self.token_account(&liquidity_provider.pubkey()),
+                    self.lp_token_account(&liquidity_provider.pubkey()),
+                    spl_token::ID,
+                    amount,
+                ),
+            ],
+            &[liquidity_provider],
+        )
+        .await
+    }
+
+    pub async fn withdraw_liquidity(
+        &mut self,
+        liquidity_provider: &Keypair,
+        shares: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        self.send(
+            &[instructions::withdraw_liquidity(
+                liquidity_provider.pubkey(),
+                self.stablecoin,
+                self.token_account(&liquidity_provider.pubkey()),
+                self.lp_token_account(&liquidity_provider.pubkey()),
+                spl_token::ID,
+                shares,
+            )],
+            &[liquidity_provider],
+        )
+        .await
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        self.try_account(address).await.unwrap()
+    }
+
+    /// Returns `None` if there is no account at `address`.
+    pub async fn try_account<T: AccountDeserialize>(&mut self, address: Pubkey) -> Option<T> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()?;
+
+        Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+    }
+
+    pub async fn platform_config(&mut self) -> bearish_dot_fun::PlatformConfig {
+        self.account(pda::platform_config()).await
+    }
+
+    pub async fn round(&mut self, round: u64) -> bearish_dot_fun::Round {
+        self.account(pda::round(round)).await
+    }
+
+    pub async fn house_pool(&mut self) -> bearish_dot_fun::HousePool {
+        self.account(pda::house_pool()).await
+    }
+
+    pub async fn user_info(&mut self, user: &Pubkey) -> bearish_dot_fun::UserInfo {
+        self.account(pda::user_info(user)).await
+    }
+
+    pub async fn balance(&mut self, address: &Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(*address)
+            .await
+            .unwrap()
+    }
+
+    pub async fn token_balance(&mut self, token_account: Pubkey) -> u64 {
+        let account = self
+            .context
+            .banks_client
+            .get_account(token_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+}
+
+/// Returns the custom error code the program failed with, if any.
+pub fn error_code(error: BanksClientError) -> Option<u32> {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
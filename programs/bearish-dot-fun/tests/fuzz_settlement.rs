@@ -0,0 +1,542 @@
+//! Property-based fuzzing of settlement and claim accounting. Each case replays a random sequence
+//! of deposits, bets, price moves, settlements, claims and config changes against a fresh bank,
+//! and checks the platform's invariants after every step. Set `PROPTEST_CASES` to run more cases
+//! than the default.
+
+mod common;
+
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{
+    constants::general::BPS, Allocation, Bet, JackPotAllocation, Round, UserInfo,
+};
+use bearish_dot_fun_client::{instructions, math, pda};
+use common::{TestContext, DURATION};
+use proptest::prelude::*;
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+
+const USERS: usize = 4;
+const STARTING_PRICE: i64 = 1_000_000;
+/// Enough to never run out, while keeping the token supply below `u64::MAX`.
+const WALLET_AMOUNT: u64 = u64::MAX / 8;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Deposit {
+        user: usize,
+        amount: u64,
+    },
+    Withdraw {
+        user: usize,
+        amount: u64,
+    },
+    SetAffiliate {
+        user: usize,
+        affiliate: usize,
+    },
+    PlaceBet {
+        user: usize,
+        amount: u64,
+        is_long: bool,
+    },
+    /// Ends the current round at the last price moved by `price_move`, and starts the next one.
+    EndRound {
+        price_move: i64,
+    },
+    /// Places each user's bet in `bets`, if any, and ends the round. Rounds where most users bet
+    /// are what gets winners, affiliates and streaks into the pools.
+    PlayRound {
+        bets: [Option<(u64, bool)>; USERS],
+        price_move: i64,
+    },
+    /// Claims the user's winnings in the `round`-th most recently ended round.
+    Claim {
+        user: usize,
+        round: usize,
+    },
+    /// Claims the affiliate winnings of the user's bet in the `round`-th most recently ended
+    /// round.
+    ClaimAffiliate {
+        user: usize,
+        round: usize,
+    },
+    /// Claims every bet's winnings and affiliate winnings, oldest round first, so that streaks
+    /// get paid.
+    ClaimAll,
+    SetAllocation {
+        shares: [u16; 4],
+    },
+    SetJackpotAllocation {
+        shares: [u16; 6],
+    },
+    SetMinBetAmount {
+        min_bet_amount: u64,
+    },
+    WithdrawPlatformFees,
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![4 => 0..10_000u64, 1 => 0..=u64::MAX / 64]
+}
+
+/// Allocations are drawn by cutting `BPS` at three points, so that they always add up.
+fn allocation_shares() -> impl Strategy<Value = [u16; 4]> {
+    proptest::array::uniform3(0..=BPS).prop_map(|mut cuts| {
+        cuts.sort();
+        [cuts[0], cuts[1] - cuts[0], cuts[2] - cuts[1], BPS - cuts[2]]
+    })
+}
+
+fn jackpot_allocation_shares() -> impl Strategy<Value = [u16; 6]> {
+    proptest::array::uniform6(0..=BPS).prop_map(|mut shares| {
+        shares.sort();
+        shares
+    })
+}
+
+/// Prices rise more often than not, so that users betting long run up streaks.
+fn price_move() -> impl Strategy<Value = i64> {
+    prop_oneof![3 => 1..=2i64, 2 => -2..=0i64]
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    let user = || 0..USERS;
+    let round = || 0..4usize;
+
+    prop_oneof![
+        3 => (user(), amount()).prop_map(|(user, amount)| Operation::Deposit { user, amount }),
+        1 => (user(), amount()).prop_map(|(user, amount)| Operation::Withdraw { user, amount }),
+        1 => (user(), user())
+            .prop_map(|(user, affiliate)| Operation::SetAffiliate { user, affiliate }),
+        6 => (user(), amount(), any::<bool>()).prop_map(|(user, amount, is_long)| {
+            Operation::PlaceBet {
+                user,
+                amount,
+                is_long,
+            }
+        }),
+        2 => price_move().prop_map(|price_move| Operation::EndRound { price_move }),
+        6 => (
+            proptest::array::uniform4(prop::option::weighted(
+                0.9,
+                (amount(), prop::bool::weighted(0.75)),
+            )),
+            price_move(),
+        )
+            .prop_map(|(bets, price_move)| Operation::PlayRound { bets, price_move }),
+        4 => (user(), round()).prop_map(|(user, round)| Operation::Claim { user, round }),
+        2 => (user(), round()).prop_map(|(user, round)| Operation::ClaimAffiliate { user, round }),
+        2 => Just(Operation::ClaimAll),
+        1 => allocation_shares().prop_map(|shares| Operation::SetAllocation { shares }),
+        1 => jackpot_allocation_shares()
+            .prop_map(|shares| Operation::SetJackpotAllocation { shares }),
+        1 => (0..1_000u64)
+            .prop_map(|min_bet_amount| Operation::SetMinBetAmount { min_bet_amount }),
+        1 => Just(Operation::WithdrawPlatformFees),
+    ]
+}
+
+struct Fuzzer {
+    test: TestContext,
+    users: Vec<Keypair>,
+    owner_token_account: Pubkey,
+    price: i64,
+    /// Round numbers of the rounds ended so far, most recent last.
+    ended_rounds: Vec<u64>,
+    /// Every user and round number a bet has been placed in.
+    bets: Vec<(usize, u64)>,
+    /// Winnings and affiliate winnings claimed so far, by round number.
+    claimed: HashMap<u64, (u64, u64)>,
+}
+
+impl Fuzzer {
+    /// Starts a round with every user funded, half of it deposited, and referred by the next user.
+    async fn new() -> Self {
+        let mut test = TestContext::initialized(STARTING_PRICE).await;
+        let mut users = Vec::new();
+        for _ in 0..USERS {
+            let user = test.create_user(WALLET_AMOUNT).await;
+            test.deposit(&user, WALLET_AMOUNT / 2).await.unwrap();
+            users.push(user);
+        }
+        for (user, affiliate) in users.iter().zip(users.iter().cycle().skip(1)) {
+            test.send(
+                &[instructions::set_affiliate(
+                    user.pubkey(),
+                    affiliate.pubkey(),
+                )],
+                &[user],
+            )
+            .await
+            .unwrap();
+        }
+        let owner = test.owner.pubkey();
+        let owner_token_account = test.create_token_account(&owner, 0).await;
+        test.start_round().await.unwrap();
+
+        Self {
+            test,
+            users,
+            owner_token_account,
+            price: STARTING_PRICE,
+            ended_rounds: Vec::new(),
+            bets: Vec::new(),
+            claimed: HashMap::new(),
+        }
+    }
+
+    fn user(&self, user: usize) -> Keypair {
+        self.users[user].insecure_clone()
+    }
+
+    fn ended_round(&self, round: usize) -> Option<u64> {
+        let len = self.ended_rounds.len();
+        (len > 0).then(|| self.ended_rounds[len - 1 - round % len])
+    }
+
+    async fn send_as_owner(&mut self, instruction: Instruction) -> Result<(), BanksClientError> {
+        let owner = self.test.owner.insecure_clone();
+        self.test.send(&[instruction], &[&owner]).await
+    }
+
+    async fn run(&mut self, operation: &Operation) -> Result<(), BanksClientError> {
+        let owner = self.test.owner.pubkey();
+
+        match *operation {
+            Operation::Deposit { user, amount } => {
+                let user = self.user(user);
+                let token_account = self.test.token_account(&user.pubkey());
+                if amount > self.test.token_balance(token_account).await {
+                    return Ok(());
+                }
+
+                self.test.deposit(&user, amount).await
+            }
+            Operation::Withdraw { user, amount } => {
+                let user = self.user(user);
+                self.test.withdraw(&user, amount).await
+            }
+            Operation::SetAffiliate { user, affiliate } => {
+                let user = self.user(user);
+                let affiliate = self.users[affiliate].pubkey();
+                self.test
+                    .send(
+                        &[instructions::set_affiliate(user.pubkey(), affiliate)],
+                        &[&user],
+                    )
+                    .await
+            }
+            Operation::PlaceBet {
+                user,
+                amount,
+                is_long,
+            } => self.place_bet(user, amount, is_long).await,
+            Operation::EndRound { price_move } => {
+                self.end_round(price_move).await;
+
+                Ok(())
+            }
+            Operation::PlayRound { bets, price_move } => {
+                for (user, &bet) in bets.iter().enumerate() {
+                    let Some((amount, is_long)) = bet else {
+                        continue;
+                    };
+                    let result = self.place_bet(user, amount, is_long).await;
+                    assert_clean_failure(operation, result);
+                }
+                self.end_round(price_move).await;
+
+                Ok(())
+            }
+            Operation::Claim { user, round } => match self.ended_round(round) {
+                Some(round) => self.claim(user, round).await,
+                None => Ok(()),
+            },
+            Operation::ClaimAffiliate { user, round } => match self.ended_round(round) {
+                Some(round) => self.claim_affiliate(user, round).await,
+                None => Ok(()),
+            },
+            Operation::ClaimAll => {
+                for (user, round) in self.bets.clone() {
+                    if !self.ended_rounds.contains(&round) {
+                        continue;
+                    }
+                    let result = self.claim(user, round).await;
+                    assert_clean_failure(operation, result);
+                    let result = self.claim_affiliate(user, round).await;
+                    assert_clean_failure(operation, result);
+                }
+
+                Ok(())
+            }
+            Operation::SetAllocation { shares } => {
+                let allocation = Allocation {
+                    winners_share: shares[0],
+                    affiliate_share: shares[1],
+                    jackpot_share: shares[2],
+                    platform_share: shares[3],
+                };
+                self.send_as_owner(instructions::set_allocation(owner, allocation))
+                    .await
+            }
+            Operation::SetJackpotAllocation { shares } => {
+                let jackpot_allocation = JackPotAllocation {
+                    streak_5: shares[0],
+                    streak_6: shares[1],
+                    streak_7: shares[2],
+                    streak_8: shares[3],
+                    streak_9: shares[4],
+                    streak_10: shares[5],
+                };
+                self.send_as_owner(instructions::set_jackpot_allocation(
+                    owner,
+                    jackpot_allocation,
+                ))
+                .await
+            }
+            Operation::SetMinBetAmount { min_bet_amount } => {
+                self.send_as_owner(instructions::set_min_bet_amount(owner, min_bet_amount))
+                    .await
+            }
+            Operation::WithdrawPlatformFees => {
+                let instruction = instructions::withdraw_platform_fees(
+                    owner,
+                    self.test.stablecoin,
+                    self.owner_token_account,
+                    spl_token::ID,
+                );
+                self.send_as_owner(instruction).await
+            }
+        }
+    }
+
+    async fn place_bet(
+        &mut self,
+        user: usize,
+        amount: u64,
+        is_long: bool,
+    ) -> Result<(), BanksClientError> {
+        let round = self.test.current_round().await;
+        let keypair = self.user(user);
+        self.test.place_bet(&keypair, amount, is_long).await?;
+        if !self.bets.contains(&(user, round)) {
+            self.bets.push((user, round));
+        }
+
+        Ok(())
+    }
+
+    /// Ends the current round at the last price moved by `price_move`, and starts the next one.
+    async fn end_round(&mut self, price_move: i64) {
+        let round = self.test.current_round().await;
+        self.price += price_move;
+
+        self.test.warp(DURATION as i64).await;
+        self.test.set_price(self.price).await;
+        self.test
+            .end_round()
+            .await
+            .expect("rounds end once their duration has elapsed");
+        self.ended_rounds.push(round);
+        self.test.start_round().await.unwrap();
+    }
+
+    /// Claims the user's winnings in `round`, checking that the round's winners haven't been paid
+    /// more than its winners pool, on top of their stakes and streak jackpots.
+    async fn claim(&mut self, user: usize, round: u64) -> Result<(), BanksClientError> {
+        let keypair = self.user(user);
+        let user = keypair.pubkey();
+
+        let Some(bet) = self
+            .test
+            .try_account::<Bet>(pda::user_bet(&user, round))
+            .await
+        else {
+            return self.test.claim_user_winnings(&keypair, round).await;
+        };
+        let amount_before = self.test.user_info(&user).await.amount;
+        let jackpot_before = self.jackpot_pool_amount().await;
+
+        self.test.claim_user_winnings(&keypair, round).await?;
+
+        let jackpot_amount = jackpot_before
+            .checked_sub(self.jackpot_pool_amount().await)
+            .expect("claims never add to the jackpot");
+        let winnings =
+            self.test.user_info(&user).await.amount - amount_before - bet.amount - jackpot_amount;
+        let ended_round = self.test.round(round).await;
+        let (winners_pool, _) = ended_round.distributable_pools(&ended_round.allocation);
+
+        let claimed = self.claimed.entry(round).or_default();
+        claimed.0 += winnings;
+        assert!(
+            claimed.0 <= winners_pool,
+            "claimed {} from a winners pool of {winners_pool} in round {round}",
+            claimed.0
+        );
+
+        Ok(())
+    }
+
+    /// Claims the affiliate winnings of the user's bet in `round`, checking that the round's
+    /// affiliates haven't been paid more than its affiliate pool.
+    async fn claim_affiliate(&mut self, user: usize, round: u64) -> Result<(), BanksClientError> {
+        let user = self.users[user].pubkey();
+        let Some(bet) = self
+            .test
+            .try_account::<Bet>(pda::user_bet(&user, round))
+            .await
+        else {
+            return Ok(());
+        };
+        let Some(affiliate) = self.users.iter().find(|u| u.pubkey() == bet.affiliate) else {
+            return Ok(());
+        };
+        let affiliate = affiliate.insecure_clone();
+        let affiliate_token_account = self.test.token_account(&affiliate.pubkey());
+        let balance_before = self.test.token_balance(affiliate_token_account).await;
+
+        self.test
+            .send(
+                &[instructions::claim_affiliate_winnings(
+                    affiliate.pubkey(),
+                    user,
+                    round,
+                    self.test.stablecoin,
+                    affiliate_token_account,
+                    spl_token::ID,
+                )],
+                &[&affiliate],
+            )
+            .await?;
+
+        let winnings = self.test.token_balance(affiliate_token_account).await - balance_before;
+        let ended_round = self.test.round(round).await;
+        let (_, affiliate_pool) = ended_round.distributable_pools(&ended_round.allocation);
+
+        let claimed = self.claimed.entry(round).or_default();
+        claimed.1 += winnings;
+        assert!(
+            claimed.1 <= affiliate_pool,
+            "claimed {} from an affiliate pool of {affiliate_pool} in round {round}",
+            claimed.1
+        );
+
+        Ok(())
+    }
+
+    async fn jackpot_pool_amount(&mut self) -> u64 {
+        self.test
+            .platform_config()
+            .await
+            .global_round_info
+            .jackpot_pool_amount
+    }
+
+    /// Checks that the vault holds at least everything the platform owes: user balances, the
+    /// jackpot, platform fees, the open round's bets and every unclaimed winning.
+    async fn check_solvency(&mut self) {
+        let vault_balance = self.test.token_balance(pda::platform_vault()).await;
+        let global_round_info = self.test.platform_config().await.global_round_info;
+        let current_round = self
+            .test
+            .round(pda::current_round(&global_round_info))
+            .await;
+
+        let mut liabilities = global_round_info.jackpot_pool_amount as u128
+            + global_round_info.accumulated_platform_fees as u128
+            + current_round.total_bet_amount_long as u128
+            + current_round.total_bet_amount_short as u128;
+
+        let mut user_infos = Vec::new();
+        for user in &self.users {
+            let user_info = self
+                .test
+                .try_account::<UserInfo>(pda::user_info(&user.pubkey()))
+                .await;
+            liabilities += user_info.as_ref().map_or(0, |user_info| user_info.amount) as u128;
+            user_infos.push(user_info);
+        }
+
+        for &(user, round_number) in &self.bets {
+            if !self.ended_rounds.contains(&round_number) {
+                continue;
+            }
+            let user_info = user_infos[user].as_ref().unwrap();
+            let round: Round = self.test.round(round_number).await;
+            let bet: Bet = self
+                .test
+                .account(pda::user_bet(&self.users[user].pubkey(), round_number))
+                .await;
+
+            if let Some(payout) =
+                math::user_payout(&round, round_number, &bet, user_info, &global_round_info)
+            {
+                liabilities += (payout.amount + payout.winnings) as u128;
+            }
+            if let Some(affiliate_payout) = math::affiliate_payout(&round, &bet) {
+                liabilities += affiliate_payout as u128;
+            }
+        }
+
+        assert!(
+            vault_balance as u128 >= liabilities,
+            "vault holds {vault_balance} but owes {liabilities}"
+        );
+    }
+}
+
+/// Operations may fail, but only with one of the program's or anchor's errors. Anything else,
+/// like a panic on overflow or a transfer the vault can't cover, is a bug.
+fn assert_clean_failure(operation: &Operation, result: Result<(), BanksClientError>) {
+    if let Err(error) = result {
+        let error = error.unwrap();
+        assert!(
+            matches!(
+                error,
+                TransactionError::InstructionError(_, InstructionError::Custom(code))
+                    if code >= anchor_lang::error::ErrorCode::InstructionMissing as u32
+            ),
+            "{operation:?} failed with {error:?}"
+        );
+    }
+}
+
+/// Honours `PROPTEST_CASES`, and otherwise runs few enough cases to keep `cargo test` quick.
+fn config() -> ProptestConfig {
+    if std::env::var("PROPTEST_CASES").is_ok() {
+        ProptestConfig::default()
+    } else {
+        ProptestConfig::with_cases(24)
+    }
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn settlement_and_claims_keep_the_platform_solvent(
+        operations in prop::collection::vec(operation(), 1..64)
+    ) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let mut fuzzer = Fuzzer::new().await;
+            for operation in &operations {
+                let result = fuzzer.run(operation).await;
+                assert_clean_failure(operation, result);
+                fuzzer.check_solvency().await;
+            }
+        });
+    }
+}
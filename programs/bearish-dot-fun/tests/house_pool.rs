@@ -0,0 +1,143 @@
+mod common;
+
+use bearish_dot_fun::error::ErrorCodes;
+use bearish_dot_fun_client::instructions;
+use common::{error_code, TestContext, DURATION};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+const STARTING_PRICE: i64 = 100_000;
+const LIQUIDITY: u64 = 1_000;
+const MAX_EXPOSURE: u16 = 5_000;
+const PLATFORM_FEE_SHARE: u16 = 5_000;
+
+/// Initializes the platform and a house pool holding `LIQUIDITY` from a single LP.
+async fn setup() -> (TestContext, Keypair) {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    test.initialize_house_pool(MAX_EXPOSURE, PLATFORM_FEE_SHARE)
+        .await
+        .unwrap();
+
+    let liquidity_provider = test.create_user(LIQUIDITY).await;
+    test.deposit_liquidity(&liquidity_provider, LIQUIDITY)
+        .await
+        .unwrap();
+
+    (test, liquidity_provider)
+}
+
+#[tokio::test]
+async fn withdrawals_above_the_pool_liquidity_fail_cleanly() {
+    let (mut test, liquidity_provider) = setup().await;
+
+    let error = test
+        .withdraw_liquidity(&liquidity_provider, LIQUIDITY + 1)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::InsufficientHouseLiquidity.into())
+    );
+
+    test.withdraw_liquidity(&liquidity_provider, LIQUIDITY)
+        .await
+        .unwrap();
+    assert_eq!(test.house_pool().await.total_liquidity, 0);
+}
+
+#[tokio::test]
+async fn the_house_takes_its_side_as_bets_are_placed() {
+    let (mut test, _) = setup().await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    test.start_round().await.unwrap();
+    let round_number = test.current_round().await;
+
+    test.place_bet(&alice, 100, true).await.unwrap();
+    let round = test.round(round_number).await;
+    assert_eq!(round.house_bet_amount, 100);
+    assert!(!round.house_is_long);
+    assert_eq!(round.total_bet_amount_short, 100);
+    assert_eq!(test.house_pool().await.total_liquidity, LIQUIDITY - 100);
+
+    // Bob's bet evens the round out partly, so the house scales its side down to the rest.
+    test.place_bet(&bob, 60, false).await.unwrap();
+    let round = test.round(round_number).await;
+    assert_eq!(round.house_bet_amount, 40);
+    assert!(!round.house_is_long);
+    assert_eq!(round.short_positions, 2);
+    assert_eq!(round.total_bet_amount_long, 100);
+    assert_eq!(round.total_bet_amount_short, 100);
+    assert_eq!(test.house_pool().await.total_liquidity, LIQUIDITY - 40);
+
+    // Ending the round settles the position as it stood, without moving it.
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE + 1).await;
+    test.end_round().await.unwrap();
+    let round = test.round(round_number).await;
+    assert_eq!(round.house_bet_amount, 40);
+    assert_eq!(round.total_bet_amount_short, 100);
+}
+
+#[tokio::test]
+async fn the_house_pool_cannot_be_left_out_once_enabled() {
+    let (mut test, _) = setup().await;
+    let alice = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+
+    let error = test
+        .send(
+            &[instructions::place_bet(
+                alice.pubkey(),
+                alice.pubkey(),
+                round,
+                false,
+                100,
+                true,
+            )],
+            &[&alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::HousePoolRequired.into())
+    );
+    let error = test
+        .send(&[instructions::quote_bet(round, false, 100, true)], &[])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::HousePoolRequired.into())
+    );
+    test.place_bet(&alice, 100, true).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE - 1).await;
+    let payer = test.payer();
+    let error = test
+        .send(
+            &[instructions::end_round(
+                payer.pubkey(),
+                round,
+                test.price_account,
+                false,
+            )],
+            &[],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::HousePoolRequired.into())
+    );
+
+    test.end_round().await.unwrap();
+    assert_eq!(test.round(round).await.house_bet_amount, 100);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use bearish_dot_fun::{error::ErrorCodes, RoundOutcome};
+use common::{
+    error_code, mock_price_account, sample_global_round_info, TestContext, DURATION,
+    STALENESS_THRESHOLD,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+const FEED_ID: u64 = 0;
+const CONF: u64 = 1;
+
+/// Initializes the platform against the owner's mock oracle feed, published at `price`.
+async fn setup(price: i64, expo: i32) -> TestContext {
+    let mut test = TestContext::new().await;
+    test.price_account = mock_price_account(&test.owner.pubkey(), FEED_ID);
+    set_price(&mut test, price, expo).await;
+    test.initialize(sample_global_round_info(test.price_account))
+        .await
+        .unwrap();
+
+    test
+}
+
+async fn set_price(test: &mut TestContext, price: i64, expo: i32) {
+    let now = test.clock().await.unix_timestamp;
+    test.set_mock_price(FEED_ID, price, CONF, expo, now)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn rounds_settle_on_mock_prices() {
+    let mut test = setup(2_500_000, -5).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    let expected_outcomes = [
+        (2_500_001, RoundOutcome::LongsWon),
+        (2_499_999, RoundOutcome::ShortsWon),
+        (2_500_000, RoundOutcome::Tie),
+    ];
+    for (ending_price, outcome) in expected_outcomes {
+        set_price(&mut test, 2_500_000, -5).await;
+        test.start_round().await.unwrap();
+        let round = test.current_round().await;
+        test.place_bet(&alice, 100, true).await.unwrap();
+        test.place_bet(&bob, 100, false).await.unwrap();
+
+        test.warp(DURATION as i64).await;
+        set_price(&mut test, ending_price, -5).await;
+        test.end_round().await.unwrap();
+
+        let ended_round = test.round(round).await;
+        assert_eq!(ended_round.starting_price, 2_500_000);
+        assert_eq!(ended_round.ending_price, ending_price as u64);
+        assert!(ended_round.outcome() == outcome);
+    }
+}
+
+#[tokio::test]
+async fn rounds_nobody_won_go_to_the_jackpot_and_platform() {
+    let mut test = setup(2_500_000, -5).await;
+    let alice = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    test.place_bet(&alice, 1_000, true).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    set_price(&mut test, 2_400_000, -5).await;
+    test.end_round().await.unwrap();
+    assert!(test.round(round).await.outcome() == RoundOutcome::OneSided);
+
+    let global_round_info = test.platform_config().await.global_round_info;
+    assert_eq!(global_round_info.jackpot_pool_amount, 900);
+    assert_eq!(global_round_info.accumulated_platform_fees, 100);
+
+    let error = test.claim_user_winnings(&alice, round).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::IneligibleForClaim.into())
+    );
+}
+
+#[tokio::test]
+async fn prices_older_than_the_staleness_threshold_are_rejected() {
+    let mut test = setup(2_500_000, -5).await;
+    test.start_round().await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    let now = test.clock().await.unix_timestamp;
+    let stale_publish_time = now - STALENESS_THRESHOLD as i64 - 1;
+    test.set_mock_price(FEED_ID, 2_500_000, CONF, -5, stale_publish_time)
+        .await
+        .unwrap();
+    assert!(test.end_round().await.is_err());
+
+    test.set_mock_price(FEED_ID, 2_500_000, CONF, -5, stale_publish_time + 1)
+        .await
+        .unwrap();
+    test.end_round().await.unwrap();
+}
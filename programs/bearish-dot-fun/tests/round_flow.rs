@@ -0,0 +1,475 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, Discriminator, Space};
+use anchor_spl::token::spl_token;
+use bearish_dot_fun::{
+    constants, error::ErrorCodes, Allocation, Bet, UserInfo, WinningsPreference,
+};
+use bearish_dot_fun_client::{instructions, pda};
+use common::{error_code, TestContext, DURATION};
+use solana_program_test::tokio;
+use solana_sdk::{account::Account, rent::Rent, signature::Signer};
+
+/// $100,000 at an exponent of -8.
+const STARTING_PRICE: i64 = 10_000_000_000_000;
+
+#[tokio::test]
+async fn longs_win_and_claim() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    let carol = test.create_user(1_000).await;
+    for user in [&alice, &bob, &carol] {
+        test.deposit(user, 1_000).await.unwrap();
+    }
+    assert_eq!(test.token_balance(pda::platform_vault()).await, 3_000);
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    test.place_bet(&alice, 300, true).await.unwrap();
+    test.place_bet(&bob, 100, true).await.unwrap();
+    test.place_bet(&carol, 1_000, false).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE + 1).await;
+    test.end_round().await.unwrap();
+
+    let ended_round = test.round(round).await;
+    assert_eq!(ended_round.ending_price, STARTING_PRICE as u64 + 1);
+    let global_round_info = test.platform_config().await.global_round_info;
+    assert_eq!(global_round_info.round, round);
+    assert_eq!(global_round_info.jackpot_pool_amount, 400);
+    assert_eq!(global_round_info.accumulated_platform_fees, 100);
+
+    // The winners split 45% of the losing side, pro rata.
+    test.claim_user_winnings(&alice, round).await.unwrap();
+    test.claim_user_winnings(&bob, round).await.unwrap();
+    assert_eq!(test.user_info(&alice.pubkey()).await.amount, 1_000 + 337);
+    assert_eq!(test.user_info(&bob.pubkey()).await.amount, 1_000 + 112);
+
+    let error = test.claim_user_winnings(&alice, round).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::AlreadyClaimedWinnings.into())
+    );
+    let error = test.claim_user_winnings(&carol, round).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::IneligibleForClaim.into())
+    );
+
+    test.withdraw(&alice, 1_337).await.unwrap();
+    let alice_token_account = test.token_account(&alice.pubkey());
+    assert_eq!(test.token_balance(alice_token_account).await, 1_337);
+}
+
+#[tokio::test]
+async fn shorts_win_when_the_price_drops() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    test.place_bet(&alice, 1_000, true).await.unwrap();
+    test.place_bet(&bob, 1_000, false).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE - 1).await;
+    test.end_round().await.unwrap();
+
+    let error = test.claim_user_winnings(&alice, round).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::IneligibleForClaim.into())
+    );
+    test.claim_user_winnings(&bob, round).await.unwrap();
+    assert_eq!(test.user_info(&bob.pubkey()).await.amount, 1_000 + 450);
+}
+
+#[tokio::test]
+async fn ties_go_to_the_jackpot_and_platform() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    test.place_bet(&alice, 1_000, true).await.unwrap();
+    test.place_bet(&bob, 1_000, false).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE).await;
+    test.end_round().await.unwrap();
+
+    let global_round_info = test.platform_config().await.global_round_info;
+    assert_eq!(global_round_info.jackpot_pool_amount, 1_800);
+    assert_eq!(global_round_info.accumulated_platform_fees, 200);
+
+    for user in [&alice, &bob] {
+        let error = test.claim_user_winnings(user, round).await.unwrap_err();
+        assert_eq!(
+            error_code(error),
+            Some(ErrorCodes::IneligibleForClaim.into())
+        );
+    }
+}
+
+#[tokio::test]
+async fn rounds_cannot_end_early() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    test.start_round().await.unwrap();
+
+    test.warp(DURATION as i64 - 1).await;
+    test.set_price(STARTING_PRICE).await;
+    let error = test.end_round().await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::RoundHasNotEndedYet.into())
+    );
+
+    test.warp(1).await;
+    test.set_price(STARTING_PRICE).await;
+    test.end_round().await.unwrap();
+}
+
+#[tokio::test]
+async fn rounds_cannot_end_on_a_stale_price() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    test.start_round().await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    assert!(test.end_round().await.is_err());
+
+    test.set_price(STARTING_PRICE).await;
+    test.end_round().await.unwrap();
+}
+
+#[tokio::test]
+async fn claims_pay_out_at_the_allocation_the_round_settled_at() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    test.place_bet(&alice, 1_000, true).await.unwrap();
+    test.place_bet(&bob, 1_000, false).await.unwrap();
+
+    test.warp(DURATION as i64).await;
+    test.set_price(STARTING_PRICE + 1).await;
+    test.end_round().await.unwrap();
+
+    let owner = test.owner.insecure_clone();
+    let allocation = Allocation {
+        winners_share: 9_000,
+        affiliate_share: 0,
+        jackpot_share: 0,
+        platform_share: 1_000,
+    };
+    test.send(
+        &[instructions::set_allocation(owner.pubkey(), allocation)],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+
+    // The round set aside 45% of the losing side for its winners, whatever the allocation now.
+    test.claim_user_winnings(&alice, round).await.unwrap();
+    assert_eq!(test.user_info(&alice.pubkey()).await.amount, 1_000 + 450);
+}
+
+#[tokio::test]
+async fn the_round_imbalance_holds_for_the_first_bet_and_after_cancellations() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let bob = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.deposit(&bob, 1_000).await.unwrap();
+
+    // Either side may be at most twice the other, or 300 while the other has no bets.
+    let owner = test.owner.insecure_clone();
+    test.send(
+        &[
+            instructions::set_max_round_imbalance(owner.pubkey(), 20_000, 300),
+            instructions::set_cancellation_window(owner.pubkey(), DURATION),
+        ],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    let error = test.place_bet(&alice, 301, true).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::RoundImbalanceExceeded.into())
+    );
+    test.place_bet(&alice, 300, true).await.unwrap();
+    test.place_bet(&bob, 150, false).await.unwrap();
+
+    // Cancelling part of the short side would leave the long side more than twice as large.
+    let error = test
+        .send(
+            &[instructions::cancel_bet(bob.pubkey(), round, false, 1)],
+            &[&bob],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::RoundImbalanceExceeded.into())
+    );
+    test.send(
+        &[instructions::cancel_bet(alice.pubkey(), round, false, 100)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(test.round(round).await.total_bet_amount_long, 200);
+}
+
+#[tokio::test]
+async fn a_strike_authority_starts_rounds_held_for_a_strike_override() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let keeper = test.create_user(0).await;
+    let owner = test.owner.insecure_clone();
+    let round = test.current_round().await;
+    let strike_price = STARTING_PRICE as u64 * 2;
+
+    // Nobody but the owner can hold off the round until they appoint the keeper.
+    let error = test
+        .send(
+            &[instructions::set_strike_override_pending(
+                keeper.pubkey(),
+                true,
+            )],
+            &[&keeper],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::NotStrikeAuthority.into())
+    );
+    test.send(
+        &[instructions::set_strike_authority(
+            owner.pubkey(),
+            keeper.pubkey(),
+        )],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+    test.send(
+        &[instructions::set_strike_override_pending(
+            keeper.pubkey(),
+            true,
+        )],
+        &[&keeper],
+    )
+    .await
+    .unwrap();
+
+    let error = test.start_round().await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::StrikeOverridePending.into())
+    );
+
+    test.send(
+        &[instructions::start_round_with_strike(
+            keeper.pubkey(),
+            round,
+            test.price_account,
+            strike_price,
+        )],
+        &[&keeper],
+    )
+    .await
+    .unwrap();
+    assert_eq!(test.round(round).await.strike_price, strike_price);
+    assert!(!test.platform_config().await.is_strike_override_pending);
+}
+
+#[tokio::test]
+async fn keepers_are_paid_back_the_rent_of_repeat_bets_from_the_users_escrow() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let keeper = test.create_user(0).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+    test.send(
+        &[instructions::set_winnings_preference(
+            alice.pubkey(),
+            WinningsPreference::RepeatBet {
+                amount: 100,
+                is_long: true,
+            },
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    test.start_round().await.unwrap();
+    let round = test.current_round().await;
+    let error = test
+        .send(
+            &[instructions::execute_repeat_bet(
+                keeper.pubkey(),
+                alice.pubkey(),
+                round,
+                false,
+            )],
+            &[&keeper],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::RepeatBetRentNotEscrowed.into())
+    );
+
+    // Enough for two repeat bets.
+    let bet_rent = test
+        .context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(constants::general::ANCHOR_DISCRIMINATOR_SIZE + Bet::INIT_SPACE);
+    test.send(
+        &[instructions::escrow_repeat_bet_rent(
+            alice.pubkey(),
+            2 * bet_rent,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    let keeper_balance = test.balance(&keeper.pubkey()).await;
+    test.send(
+        &[instructions::execute_repeat_bet(
+            keeper.pubkey(),
+            alice.pubkey(),
+            round,
+            false,
+        )],
+        &[&keeper],
+    )
+    .await
+    .unwrap();
+    assert_eq!(test.balance(&keeper.pubkey()).await, keeper_balance);
+    assert_eq!(test.user_info(&alice.pubkey()).await.amount, 900);
+    assert_eq!(test.round(round).await.total_bet_amount_long, 100);
+
+    // The rest of the escrow goes back to Alice once she stops repeating bets.
+    let alice_balance = test.balance(&alice.pubkey()).await;
+    test.send(
+        &[instructions::set_winnings_preference(
+            alice.pubkey(),
+            WinningsPreference::KeepInBalance,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        test.balance(&alice.pubkey()).await,
+        alice_balance + bet_rent
+    );
+}
+
+#[tokio::test]
+async fn blocked_users_cannot_pass_another_users_access_accounts() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    let mallory = test.create_user(1_000).await;
+    let owner = test.owner.insecure_clone();
+    test.send(
+        &[instructions::block_user(owner.pubkey(), mallory.pubkey())],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+
+    let error = test.deposit(&mallory, 1_000).await.unwrap_err();
+    assert_eq!(error_code(error), Some(ErrorCodes::UserBlocked.into()));
+
+    let mut deposit = instructions::deposit(
+        mallory.pubkey(),
+        test.stablecoin,
+        test.token_account(&mallory.pubkey()),
+        spl_token::ID,
+        1_000,
+    );
+    for account in &mut deposit.accounts {
+        if account.pubkey == pda::blocked_user(&mallory.pubkey()) {
+            account.pubkey = pda::blocked_user(&alice.pubkey());
+        }
+    }
+    let error = test.send(&[deposit], &[&mallory]).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(ErrorCodes::InvalidAccessAccount.into())
+    );
+}
+
+#[tokio::test]
+async fn user_infos_from_before_the_appended_fields_are_migrated() {
+    let mut test = TestContext::initialized(STARTING_PRICE).await;
+    let alice = test.create_user(1_000).await;
+    test.deposit(&alice, 1_000).await.unwrap();
+
+    // Rewrite alice's account with the layout it had when the platform was first deployed.
+    let (user_info, bump) = Pubkey::find_program_address(
+        &[constants::seeds::USER, alice.pubkey().as_ref()],
+        &bearish_dot_fun::ID,
+    );
+    let mut data = UserInfo::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(Pubkey::default().as_ref());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(bump);
+    test.context.set_account(
+        &user_info,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: bearish_dot_fun::ID,
+            ..Account::default()
+        }
+        .into(),
+    );
+
+    let error = test.withdraw(&alice, 1_000).await.unwrap_err();
+    assert_eq!(
+        error_code(error),
+        Some(anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    );
+
+    let payer = test.payer().pubkey();
+    test.send(
+        &[instructions::migrate_user_info(payer, alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let migrated = test.user_info(&alice.pubkey()).await;
+    assert_eq!(migrated.user, alice.pubkey());
+    assert_eq!(migrated.amount, 1_000);
+    assert!(migrated.winnings_preference == WinningsPreference::KeepInBalance);
+
+    test.withdraw(&alice, 1_000).await.unwrap();
+    let alice_token_account = test.token_account(&alice.pubkey());
+    assert_eq!(test.token_balance(alice_token_account).await, 1_000);
+}
@@ -0,0 +1,119 @@
+//! Pyth price accounts whose price can be set at will, so that rounds can be settled at any price
+//! on a local validator. Never deploy this anywhere else.
+
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+use pyth_sdk_solana::state::{
+    AccountType, PriceStatus, PriceType, SolanaPriceAccount, MAGIC, VERSION_2,
+};
+
+declare_id!("FaTWwMnMYCBcQN9WhCr6Q6ChesaJF5vXT5YUdukiVTJS");
+
+#[constant]
+pub const PRICE_ACCOUNT_SEED: &[u8] = b"price_account";
+
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    pub fn set_price(
+        ctx: Context<SetPrice>,
+        feed_id: u64,
+        price: i64,
+        conf: u64,
+        expo: i32,
+        publish_time: i64,
+    ) -> Result<()> {
+        SetPrice::set_price(ctx, feed_id, price, conf, expo, publish_time)
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(feed_id: u64)]
+pub struct SetPrice<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The price account in Pyth's layout, created on the first price set. It is derived
+    /// from the authority, so only they can move its price.
+    #[account(
+        mut,
+        seeds = [
+            PRICE_ACCOUNT_SEED,
+            authority.key().as_ref(),
+            feed_id.to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub price_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetPrice<'_> {
+    /// Publishes `price` as the current aggregate price of the feed. The previous price is set to
+    /// the same values, so the price reads the same whatever the reader's slot.
+    pub fn set_price(
+        ctx: Context<SetPrice>,
+        feed_id: u64,
+        price: i64,
+        conf: u64,
+        expo: i32,
+        publish_time: i64,
+    ) -> Result<()> {
+        let price_account = &ctx.accounts.price_account;
+
+        if price_account.data_is_empty() {
+            let space = std::mem::size_of::<SolanaPriceAccount>();
+            let authority = ctx.accounts.authority.key();
+            let feed_id = feed_id.to_be_bytes();
+            let price_account_bump = &[ctx.bumps.price_account];
+            let price_account_signer = &[&[
+                PRICE_ACCOUNT_SEED,
+                authority.as_ref(),
+                feed_id.as_ref(),
+                price_account_bump,
+            ][..]];
+
+            create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    CreateAccount {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: price_account.to_account_info(),
+                    },
+                    price_account_signer,
+                ),
+                Rent::get()?.minimum_balance(space),
+                space as u64,
+                &crate::ID,
+            )?;
+        }
+
+        let slot = Clock::get()?.slot;
+        let mut data = price_account.try_borrow_mut_data()?;
+        let price_account = bytemuck::from_bytes_mut::<SolanaPriceAccount>(&mut data);
+
+        price_account.magic = MAGIC;
+        price_account.ver = VERSION_2;
+        price_account.atype = AccountType::Price as u32;
+        price_account.size = std::mem::size_of::<SolanaPriceAccount>() as u32;
+        price_account.ptype = PriceType::Price;
+        price_account.expo = expo;
+        price_account.last_slot = slot;
+        price_account.valid_slot = slot;
+        price_account.timestamp = publish_time;
+        price_account.prev_slot = slot;
+        price_account.prev_price = price;
+        price_account.prev_conf = conf;
+        price_account.prev_timestamp = publish_time;
+        price_account.agg.price = price;
+        price_account.agg.conf = conf;
+        price_account.agg.status = PriceStatus::Trading;
+        price_account.agg.pub_slot = slot;
+
+        Ok(())
+    }
+}
@@ -7,6 +7,9 @@ echo -e "\nRunning tests..."
 anchor test tests/initialization.test.ts --skip-build
 sleep 1s
 
+anchor test tests/tokenExtensions.test.ts --skip-build
+sleep 1s
+
 anchor test tests/transferOwnership.test.ts --skip-build
 sleep 1s
 
@@ -19,6 +22,9 @@ sleep 1s
 anchor test tests/setAffiliate.test.ts --skip-build
 sleep 1s
 
+anchor test tests/gating.test.ts --skip-build
+sleep 1s
+
 anchor test tests/deposit.test.ts --skip-build
 sleep 1s
 
@@ -31,6 +37,15 @@ sleep 1s
 anchor test tests/placeBet.test.ts --skip-build
 sleep 1s
 
+anchor test tests/placeBetFromWallet.test.ts --skip-build
+sleep 1s
+
+anchor test tests/exposureLimits.test.ts --skip-build
+sleep 1s
+
+anchor test tests/cancelBet.test.ts --skip-build
+sleep 1s
+
 anchor test tests/endRoundPart1.test.ts --skip-build
 sleep 1s
 
@@ -56,4 +71,37 @@ anchor test tests/claimUserWinningsPart4.test.ts --skip-build
 sleep 1s
 
 anchor test tests/claimAffiliateWinnings.test.ts --skip-build
-sleep 1s
\ No newline at end of file
+sleep 1s
+
+anchor test tests/winningsPreference.test.ts --skip-build
+sleep 1s
+
+anchor test tests/session.test.ts --skip-build
+sleep 1s
+
+anchor test tests/order.test.ts --skip-build
+sleep 1s
+
+anchor test tests/mintMarket.test.ts --skip-build
+sleep 1s
+
+anchor test tests/nativeSol.test.ts --skip-build
+sleep 1s
+
+anchor test tests/housePool.test.ts --skip-build
+sleep 1s
+
+anchor test tests/fixedOdds.test.ts --skip-build
+sleep 1s
+
+anchor test tests/bucketMarket.test.ts --skip-build
+sleep 1s
+
+anchor test tests/strikeRound.test.ts --skip-build
+sleep 1s
+
+anchor test tests/quote.test.ts --skip-build
+sleep 1s
+
+anchor test tests/mockOracle.test.ts --skip-build
+sleep 1s
@@ -0,0 +1,165 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    // < -1%, -1%..0, 0..+1%, > +1%
+    const bucketBoundaries = [-100, 0, 100];
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+    });
+
+    it("Doesn't allow bucket boundaries out of order", async () => {
+        try {
+            await programMethods.setBucketBoundaries(owner, [0, -100], bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidBucketBoundaries
+            );
+        }
+    });
+
+    it("Allows the owner to set the bucket boundaries", async () => {
+        await programMethods.setBucketBoundaries(owner, bucketBoundaries, bearishDotFun);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.deepStrictEqual(platformConfigAccount.bucketBoundaries, bucketBoundaries);
+    });
+
+    it("Snapshots the bucket boundaries at the start of a round", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.deepStrictEqual(roundAccount.bucketBoundaries, bucketBoundaries);
+    });
+
+    it("Doesn't allow bets on a bucket that doesn't exist", async () => {
+        try {
+            await programMethods.placeBucketBet(
+                user1,
+                new anchor.BN(amount),
+                bucketBoundaries.length + 1,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidBucket
+            );
+        }
+    });
+
+    it("Allows users to bet on buckets", async () => {
+        await programMethods.placeBucketBet(user1, new anchor.BN(amount / 2), 3, bearishDotFun);
+        await programMethods.placeBucketBet(user1, new anchor.BN(amount / 2), 3, bearishDotFun);
+        await programMethods.placeBucketBet(user2, new anchor.BN(amount), 0, bearishDotFun);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.bucketTotals[3].toNumber(), amount);
+        assert.strictEqual(roundAccount.bucketPositions[3].toNumber(), 1);
+        assert.strictEqual(roundAccount.bucketTotals[0].toNumber(), amount);
+        assert.strictEqual(roundAccount.bucketPositions[0].toNumber(), 1);
+    });
+
+    it("Doesn't allow betting on multiple buckets of a round", async () => {
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+
+        try {
+            await programMethods.placeBucketBet(user1, new anchor.BN(amount), 2, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.cannotBetOnMultipleBuckets
+            );
+        }
+    });
+
+    it("Picks the winning bucket from the price change when the round ends", async () => {
+        // The price moves from SOL/USD to BTC/USD, which is well above +1%.
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.winningBucket, 3);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.accumulatedPlatformFees.toNumber(),
+            (amount * sampleGlobalRoundInfo.allocation.platformShare) / bps
+        );
+    });
+
+    it("Pays the losing buckets' pools out to the winning bucket", async () => {
+        await programMethods.claimBucketWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+
+        const allocation = sampleGlobalRoundInfo.allocation;
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            amount * 2 + (amount * (allocation.winnersShare + allocation.affiliateShare)) / bps
+        );
+    });
+
+    it("Doesn't allow losing buckets to claim", async () => {
+        try {
+            await programMethods.claimBucketWinnings(user2, currentRoundIndex - 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.ineligibleForClaim
+            );
+        }
+    });
+});
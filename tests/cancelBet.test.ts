@@ -0,0 +1,143 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    const cancellationWindow = new anchor.BN(1000);
+    const cancellationFee = 100;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+        await programMethods.setCancellationWindow(owner, cancellationWindow, bearishDotFun);
+        await programMethods.setCancellationFee(owner, cancellationFee, bearishDotFun);
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+
+        await programMethods.setAffiliate(user2, owner.publicKey, bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+
+        await programMethods.startRound(user1, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+        await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+    });
+
+    it("Doesn't allow cancelling with amount 0", async () => {
+        try {
+            await programMethods.cancelBet(user1, new anchor.BN(0), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.cancelAmountZero
+            );
+        }
+    });
+
+    it("Doesn't allow cancelling more than the bet amount", async () => {
+        try {
+            await programMethods.cancelBet(user1, new anchor.BN(amount + 1), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.cancelAmountExceedsBetAmount
+            );
+        }
+    });
+
+    it("Allows reducing a bet within the cancellation window", async () => {
+        const cancelAmount = amount / 2;
+        const fee = (cancelAmount * cancellationFee) / bps;
+
+        await programMethods.cancelBet(user1, new anchor.BN(cancelAmount), bearishDotFun);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount - cancelAmount);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), cancelAmount - fee);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.longPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.totalBetAmountLong.toNumber(), amount - cancelAmount);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(platformConfigAccount.globalRoundInfo.jackpotPoolAmount.toNumber(), fee);
+    });
+
+    it("Allows cancelling a bet entirely within the cancellation window", async () => {
+        const fee = (amount * cancellationFee) / bps;
+
+        await programMethods.cancelBet(user2, new anchor.BN(amount), bearishDotFun);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetchNullable(
+            pda.getUserBet(user2.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.isNull(userBetAccount);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), amount - fee);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.shortPositions.toNumber(), 0);
+        assert.strictEqual(roundAccount.affiliatesForShortPositions.toNumber(), 0);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), 0);
+    });
+
+    it("Doesn't allow cancelling after the cancellation window has elapsed", async () => {
+        await programMethods.setCancellationWindow(owner, new anchor.BN(1), bearishDotFun);
+        await sleep(2 * millisecondsPerSecond);
+
+        try {
+            await programMethods.cancelBet(user1, new anchor.BN(amount / 2), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.cancellationWindowElapsed
+            );
+        }
+    });
+});
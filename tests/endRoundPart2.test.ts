@@ -4,9 +4,15 @@ import { assert } from "chai";
 import { BearishDotFun } from "../target/types/bearish_dot_fun";
 
 import { User } from "./utils/types";
-import { pda, programMethods, runRound } from "./utils/utils";
+import { getEvents, pda, programMethods, runRound, sleep } from "./utils/utils";
 import { setup } from "./utils/setup";
-import { sampleGlobalRoundInfo, decimals, bps } from "./utils/constants";
+import {
+    sampleGlobalRoundInfo,
+    decimals,
+    bps,
+    millisecondsPerSecond,
+    priceAccounts,
+} from "./utils/constants";
 
 describe("bearish-dot-fun", () => {
     let owner: anchor.web3.Keypair,
@@ -177,4 +183,60 @@ describe("bearish-dot-fun", () => {
             0
         );
     });
+
+    it("Emits the round's settlement when a round ends", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+        await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        const txSignature = await programMethods.endRound(owner, bearishDotFun);
+
+        const roundEnded = (await getEvents(txSignature, bearishDotFun)).find(
+            (event) => event.name === "roundEnded"
+        ).data;
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber();
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(roundIndex, bearishDotFun)
+        );
+        const allocation = sampleGlobalRoundInfo.allocation;
+
+        assert.strictEqual(roundEnded.round.toNumber(), roundIndex);
+        assert.strictEqual(
+            roundEnded.startingPrice.toNumber(),
+            roundAccount.startingPrice.toNumber()
+        );
+        assert.strictEqual(roundEnded.strikePrice.toNumber(), roundAccount.strikePrice.toNumber());
+        assert.strictEqual(roundEnded.endingPrice.toNumber(), roundAccount.endingPrice.toNumber());
+        assert.deepEqual(roundEnded.outcome, { longsWon: {} });
+        assert.strictEqual(roundEnded.totalBetAmountLong.toNumber(), amount);
+        assert.strictEqual(roundEnded.totalBetAmountShort.toNumber(), amount);
+        assert.strictEqual(roundEnded.longPositions.toNumber(), 1);
+        assert.strictEqual(roundEnded.shortPositions.toNumber(), 1);
+        assert.strictEqual(
+            roundEnded.jackpotAmount.toNumber(),
+            (amount * allocation.jackpotShare) / bps
+        );
+        assert.strictEqual(
+            roundEnded.platformFee.toNumber(),
+            (amount * allocation.platformShare) / bps
+        );
+        assert.strictEqual(
+            roundEnded.winnersPool.toNumber(),
+            (amount * allocation.winnersShare) / bps
+        );
+        assert.strictEqual(
+            roundEnded.affiliatePool.toNumber(),
+            (amount * allocation.affiliateShare) / bps
+        );
+        assert.strictEqual(
+            roundEnded.jackpotPoolAmount.toNumber(),
+            platformConfigAccount.globalRoundInfo.jackpotPoolAmount.toNumber()
+        );
+    });
 });
@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods } from "./utils/utils";
+import { setup } from "./utils/setup";
+import { bps, decimals, errors, sampleGlobalRoundInfo } from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    const depositAmount = amount * 3;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+        await programMethods.setMaxBetAmount(owner, new anchor.BN(amount), bearishDotFun);
+        await programMethods.setMaxUserBetAmountPerRound(
+            owner,
+            new anchor.BN((amount * 3) / 2),
+            bearishDotFun
+        );
+        await programMethods.setMaxRoundImbalance(
+            owner,
+            new anchor.BN(2 * bps),
+            new anchor.BN(amount / 2),
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(depositAmount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(depositAmount), bearishDotFun);
+
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+    });
+
+    it("Doesn't allow placing a bet above the max bet amount", async () => {
+        try {
+            await programMethods.placeBet(user1, new anchor.BN(amount + 1), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.betAmountAboveMaxBetAmount
+            );
+        }
+    });
+
+    it("Doesn't allow a side to exceed the max one-sided amount while the other is empty", async () => {
+        try {
+            await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.roundImbalanceExceeded
+            );
+        }
+    });
+
+    it("Allows placing bets within the limits", async () => {
+        await programMethods.placeBet(user2, new anchor.BN(amount / 2), false, bearishDotFun);
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.totalBetAmountLong.toNumber(), amount);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), amount / 2);
+    });
+
+    it("Doesn't allow exceeding the max round imbalance", async () => {
+        try {
+            await programMethods.placeBet(user1, new anchor.BN(amount / 2), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.roundImbalanceExceeded
+            );
+        }
+    });
+
+    it("Doesn't allow exceeding the max bet amount per user per round", async () => {
+        await programMethods.placeBet(user2, new anchor.BN(amount / 2), false, bearishDotFun);
+        await programMethods.placeBet(user1, new anchor.BN(amount / 2), true, bearishDotFun);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), (amount * 3) / 2);
+
+        try {
+            await programMethods.placeBet(user1, new anchor.BN(1), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.userRoundExposureExceeded
+            );
+        }
+    });
+});
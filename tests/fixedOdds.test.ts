@@ -0,0 +1,202 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    const baseMultiplier = 19000;
+    const skew = 1000;
+    // The first bet of a round always lands on the side with the larger liability.
+    const multiplier = (baseMultiplier * (bps - skew)) / bps;
+    const payout = (amount * multiplier) / bps;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+        await programMethods.initializeHousePool(owner, 0, 0, bearishDotFun);
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+        await programMethods.depositLiquidity(user2, new anchor.BN(amount), bearishDotFun);
+
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+    });
+
+    it("Doesn't allow fixed-odds bets while disabled", async () => {
+        try {
+            await programMethods.placeFixedOddsBet(
+                user1,
+                new anchor.BN(amount),
+                true,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.fixedOddsDisabled
+            );
+        }
+    });
+
+    it("Doesn't allow a config that can pay out less than the stake", async () => {
+        try {
+            await programMethods.setFixedOddsConfig(owner, new anchor.BN(bps), skew, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidFixedOddsConfig
+            );
+        }
+    });
+
+    it("Allows the owner to set the fixed-odds config", async () => {
+        await programMethods.setFixedOddsConfig(
+            owner,
+            new anchor.BN(baseMultiplier),
+            skew,
+            bearishDotFun
+        );
+
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(housePoolAccount.fixedOddsBaseMultiplier.toNumber(), baseMultiplier);
+        assert.strictEqual(housePoolAccount.fixedOddsSkew, skew);
+    });
+
+    it("Locks the multiplier into the bet and reserves the payout", async () => {
+        await programMethods.placeFixedOddsBet(user1, new anchor.BN(amount), true, bearishDotFun);
+
+        const fixedOddsBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getFixedOddsBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(fixedOddsBetAccount.amount.toNumber(), amount);
+        assert.strictEqual(fixedOddsBetAccount.multiplier.toNumber(), multiplier);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.fixedOddsPayoutLong.toNumber(), payout);
+
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(housePoolAccount.totalLiquidity.toNumber(), amount * 2);
+        assert.strictEqual(housePoolAccount.reservedLiability.toNumber(), payout);
+    });
+
+    it("Doesn't allow bets the house pool can't cover", async () => {
+        try {
+            await programMethods.placeFixedOddsBet(
+                user2,
+                new anchor.BN(amount),
+                true,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.insufficientHouseLiquidity
+            );
+        }
+    });
+
+    it("Doesn't allow LPs to withdraw the reserved liability", async () => {
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+
+        try {
+            await programMethods.withdrawLiquidity(
+                user2,
+                housePoolAccount.totalShares,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.insufficientHouseLiquidity
+            );
+        }
+    });
+
+    it("Requires the house pool to end a round with fixed-odds bets", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+
+        try {
+            await programMethods.endRound(owner, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.housePoolRequired
+            );
+        }
+    });
+
+    it("Settles winning fixed-odds bets against the house pool", async () => {
+        await programMethods.endRound(owner, bearishDotFun, true);
+
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(housePoolAccount.totalLiquidity.toNumber(), amount * 2 - payout);
+        assert.strictEqual(housePoolAccount.reservedLiability.toNumber(), 0);
+    });
+
+    it("Pays out the stake times the locked-in multiplier", async () => {
+        await programMethods.claimFixedOddsWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), payout);
+    });
+
+    it("Doesn't allow claiming fixed-odds winnings twice", async () => {
+        try {
+            await programMethods.claimFixedOddsWinnings(
+                user1,
+                currentRoundIndex - 1,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.alreadyClaimedWinnings
+            );
+        }
+    });
+});
@@ -0,0 +1,157 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, transferSOL } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    const gatekeeper = anchor.web3.Keypair.generate();
+    const amount = 100 * 10 ** decimals;
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await transferSOL(provider, owner, gatekeeper.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+    });
+
+    it("Allows the owner to block a user", async () => {
+        await programMethods.blockUser(owner, user2.publicKey, bearishDotFun);
+
+        const blockedUserAccount = await bearishDotFun.account.blockedUser.fetch(
+            pda.getBlockedUser(user2.publicKey, bearishDotFun)
+        );
+        assert.deepStrictEqual(blockedUserAccount.user, user2.publicKey);
+    });
+
+    it("Doesn't allow a blocked user to deposit or set an affiliate", async () => {
+        try {
+            await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.userBlocked
+            );
+        }
+
+        try {
+            await programMethods.setAffiliate(user2, owner.publicKey, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.userBlocked
+            );
+        }
+    });
+
+    it("Allows a blocked user to withdraw", async () => {
+        await programMethods.withdraw(user2, new anchor.BN(amount), bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), 0);
+    });
+
+    it("Allows the owner to unblock a user", async () => {
+        await programMethods.unblockUser(owner, user2.publicKey, bearishDotFun);
+
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), amount);
+    });
+
+    it("Doesn't allow enabling gating without a gatekeeper", async () => {
+        try {
+            await programMethods.setGating(
+                owner,
+                true,
+                anchor.web3.PublicKey.default,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.gatekeeperDefaultPubkey
+            );
+        }
+    });
+
+    it("Requires an attestation to deposit once gating is enabled", async () => {
+        await programMethods.setGating(owner, true, gatekeeper.publicKey, bearishDotFun);
+
+        try {
+            await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidAttestation
+            );
+        }
+    });
+
+    it("Allows an attested user to deposit", async () => {
+        const expiresAt = new anchor.BN(Math.floor(Date.now() / millisecondsPerSecond) + 1000);
+
+        await programMethods.issueAttestation(
+            gatekeeper,
+            user1.publicKey,
+            expiresAt,
+            bearishDotFun
+        );
+
+        const attestationAccount = await bearishDotFun.account.attestation.fetch(
+            pda.getAttestation(user1.publicKey, bearishDotFun)
+        );
+        assert.deepStrictEqual(attestationAccount.user, user1.publicKey);
+        assert.deepStrictEqual(attestationAccount.gatekeeper, gatekeeper.publicKey);
+        assert.strictEqual(attestationAccount.expiresAt.toNumber(), expiresAt.toNumber());
+        assert.isFalse(attestationAccount.isRevoked);
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), amount);
+    });
+
+    it("Doesn't allow depositing with a revoked attestation", async () => {
+        await programMethods.revokeAttestation(gatekeeper, user1.publicKey, bearishDotFun);
+
+        try {
+            await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.attestationRevoked
+            );
+        }
+    });
+});
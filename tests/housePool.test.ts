@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    const liquidityAmount = amount * 2;
+    const maxExposure = bps / 2;
+    const platformFeeShare = bps / 2;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+    });
+
+    it("Doesn't allow a max exposure above 100%", async () => {
+        try {
+            await programMethods.initializeHousePool(
+                owner,
+                bps + 1,
+                platformFeeShare,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidMaxExposure
+            );
+        }
+    });
+
+    it("Allows the owner to initialize the house pool", async () => {
+        await programMethods.initializeHousePool(
+            owner,
+            maxExposure,
+            platformFeeShare,
+            bearishDotFun
+        );
+
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.deepStrictEqual(housePoolAccount.lpMint, pda.getLpMint(bearishDotFun));
+        assert.strictEqual(housePoolAccount.maxExposure, maxExposure);
+        assert.strictEqual(housePoolAccount.platformFeeShare, platformFeeShare);
+        assert.strictEqual(housePoolAccount.totalLiquidity.toNumber(), 0);
+    });
+
+    it("Mints LP shares for deposited liquidity", async () => {
+        await programMethods.depositLiquidity(user2, new anchor.BN(liquidityAmount), bearishDotFun);
+
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(housePoolAccount.totalLiquidity.toNumber(), liquidityAmount);
+        assert.strictEqual(housePoolAccount.totalShares.toNumber(), liquidityAmount);
+
+        const lpTokenAccount = await spl.getAccount(
+            bearishDotFun.provider.connection,
+            await spl.getAssociatedTokenAddress(pda.getLpMint(bearishDotFun), user2.publicKey)
+        );
+        assert.strictEqual(Number(lpTokenAccount.amount), liquidityAmount);
+    });
+
+    it("Takes the opposite side of a one sided round", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+
+        // The house takes its side as the bet is placed, before the outcome is known.
+        const roundAccountBeforeEnd = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccountBeforeEnd.houseBetAmount.toNumber(), amount);
+        assert.isFalse(roundAccountBeforeEnd.houseIsLong);
+
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun, true);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.houseBetAmount.toNumber(), amount);
+        assert.isFalse(roundAccount.houseIsLong);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), amount);
+
+        const platformFee = (amount * sampleGlobalRoundInfo.allocation.platformShare) / bps;
+        const lpFee = (platformFee * platformFeeShare) / bps;
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(
+            housePoolAccount.totalLiquidity.toNumber(),
+            liquidityAmount - amount + lpFee
+        );
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.accumulatedPlatformFees.toNumber(),
+            platformFee - lpFee
+        );
+    });
+
+    it("Pays winners out of the house's side of the round", async () => {
+        await programMethods.claimUserWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            amount + (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps
+        );
+    });
+
+    it("Redeems LP shares for their share of the pool", async () => {
+        const housePoolAccount = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+
+        await programMethods.withdrawLiquidity(user2, housePoolAccount.totalShares, bearishDotFun);
+
+        const housePoolAccountAfter = await bearishDotFun.account.housePool.fetch(
+            pda.getHousePool(bearishDotFun)
+        );
+        assert.strictEqual(housePoolAccountAfter.totalLiquidity.toNumber(), 0);
+        assert.strictEqual(housePoolAccountAfter.totalShares.toNumber(), 0);
+    });
+});
@@ -0,0 +1,261 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { createSplTokenMint, getStablecoin, pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let mint: anchor.web3.PublicKey;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        mint = await createSplTokenMint(provider.connection, owner, decimals);
+        await getStablecoin(provider, mint, owner, user1, amount);
+        await getStablecoin(provider, mint, owner, user2, amount);
+    });
+
+    it("Doesn't allow whitelisting the platform stablecoin", async () => {
+        try {
+            await programMethods.whitelistMint(owner, stablecoin, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.mintIsPlatformStablecoin
+            );
+        }
+    });
+
+    it("Allows the owner to whitelist a mint", async () => {
+        await programMethods.whitelistMint(owner, mint, bearishDotFun);
+
+        const mintMarketAccount = await bearishDotFun.account.mintMarket.fetch(
+            pda.getMintMarket(mint, bearishDotFun)
+        );
+        assert.deepStrictEqual(mintMarketAccount.mint, mint);
+        assert.isTrue(mintMarketAccount.isEnabled);
+        assert.strictEqual(mintMarketAccount.jackpotPoolAmount.toNumber(), 0);
+        assert.strictEqual(mintMarketAccount.accumulatedPlatformFees.toNumber(), 0);
+    });
+
+    it("Tracks deposits separately for each mint", async () => {
+        await programMethods.depositMint(user1, mint, new anchor.BN(amount), bearishDotFun);
+        await programMethods.depositMint(user2, mint, new anchor.BN(amount), bearishDotFun);
+
+        const mintUserInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, mint, bearishDotFun)
+        );
+        assert.strictEqual(mintUserInfoAccount.amount.toNumber(), amount);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetchNullable(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.isNull(userInfoAccount);
+
+        const mintVaultBalance = await provider.connection.getTokenAccountBalance(
+            pda.getMintVault(mint, bearishDotFun)
+        );
+        assert.strictEqual(Number(mintVaultBalance.value.amount), amount * 2);
+    });
+
+    it("Checks bets in a mint against the mint's own bet limits", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.setMintMarketBetLimits(
+            owner,
+            mint,
+            new anchor.BN(0),
+            new anchor.BN(amount / 2),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            bearishDotFun
+        );
+
+        try {
+            await programMethods.placeMintBet(
+                user1,
+                mint,
+                new anchor.BN(amount),
+                true,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.betAmountAboveMaxBetAmount
+            );
+        }
+
+        await programMethods.setMintMarketBetLimits(
+            owner,
+            mint,
+            new anchor.BN(0),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            bearishDotFun
+        );
+    });
+
+    it("Allows placing bets in a mint on the current round", async () => {
+        await programMethods.placeMintBet(user1, mint, new anchor.BN(amount), true, bearishDotFun);
+        await programMethods.placeMintBet(user2, mint, new anchor.BN(amount), false, bearishDotFun);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        const mintRoundAccount = await bearishDotFun.account.round.fetch(
+            pda.getMintRound(currentRoundIndex, mint, bearishDotFun)
+        );
+        assert.strictEqual(
+            mintRoundAccount.startingPrice.toNumber(),
+            roundAccount.startingPrice.toNumber()
+        );
+        assert.strictEqual(mintRoundAccount.totalBetAmountLong.toNumber(), amount);
+        assert.strictEqual(mintRoundAccount.totalBetAmountShort.toNumber(), amount);
+        assert.strictEqual(roundAccount.totalBetAmountLong.toNumber(), 0);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), 0);
+    });
+
+    it("Settles a mint's pools once the round has ended", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        await programMethods.settleMintRound(mint, currentRoundIndex - 1, bearishDotFun);
+
+        const allocation = sampleGlobalRoundInfo.allocation;
+        const mintMarketAccount = await bearishDotFun.account.mintMarket.fetch(
+            pda.getMintMarket(mint, bearishDotFun)
+        );
+        assert.strictEqual(
+            mintMarketAccount.jackpotPoolAmount.toNumber(),
+            (amount * allocation.jackpotShare) / bps
+        );
+        assert.strictEqual(
+            mintMarketAccount.accumulatedPlatformFees.toNumber(),
+            (amount * allocation.platformShare) / bps
+        );
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(platformConfigAccount.globalRoundInfo.jackpotPoolAmount.toNumber(), 0);
+    });
+
+    it("Doesn't allow settling a mint's pools twice", async () => {
+        try {
+            await programMethods.settleMintRound(mint, currentRoundIndex - 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.roundAlreadyEnded
+            );
+        }
+    });
+
+    it("Credits winnings to the user's balance in the mint", async () => {
+        // The winnings preference is denominated in the stablecoin and doesn't apply to mints, and
+        // a delegate with a session can claim on the user's behalf.
+        const user1AssociatedTokenAccountAddress = spl.getAssociatedTokenAddressSync(
+            stablecoin,
+            user1.publicKey
+        );
+        await programMethods.setWinningsPreference(
+            user1,
+            { withdrawToWallet: { tokenAccount: user1AssociatedTokenAccountAddress } },
+            bearishDotFun
+        );
+        await programMethods.createSession(
+            user1,
+            user2.publicKey,
+            new anchor.BN(Math.floor(Date.now() / millisecondsPerSecond) + 1000),
+            new anchor.BN(0),
+            { placeBet: false, claimWinnings: true },
+            bearishDotFun
+        );
+
+        await programMethods.claimMintUserWinningsAsDelegate(
+            user2,
+            user1.publicKey,
+            mint,
+            currentRoundIndex - 1,
+            bearishDotFun
+        );
+
+        const mintUserInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, mint, bearishDotFun)
+        );
+        assert.strictEqual(
+            mintUserInfoAccount.amount.toNumber(),
+            amount + (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps
+        );
+    });
+
+    it("Allows the owner to withdraw the mint's platform fees", async () => {
+        await programMethods.withdrawMintPlatformFees(owner, mint, bearishDotFun);
+
+        const mintMarketAccount = await bearishDotFun.account.mintMarket.fetch(
+            pda.getMintMarket(mint, bearishDotFun)
+        );
+        assert.strictEqual(mintMarketAccount.accumulatedPlatformFees.toNumber(), 0);
+    });
+
+    it("Doesn't allow depositing into a disabled mint market", async () => {
+        await programMethods.setMintMarketEnabled(owner, mint, false, bearishDotFun);
+
+        try {
+            await programMethods.depositMint(user2, mint, new anchor.BN(amount), bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.mintMarketDisabled
+            );
+        }
+    });
+
+    it("Allows withdrawing from a disabled mint market", async () => {
+        await programMethods.withdrawMint(user1, mint, new anchor.BN(amount), bearishDotFun);
+
+        const mintUserInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, mint, bearishDotFun)
+        );
+        assert.strictEqual(
+            mintUserInfoAccount.amount.toNumber(),
+            (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps
+        );
+    });
+});
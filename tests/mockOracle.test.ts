@@ -0,0 +1,173 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+import { MockOracle } from "../target/types/mock_oracle";
+
+import { mockOracle, pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    sampleGlobalRoundInfo,
+    decimals,
+    bps,
+    errors,
+    millisecondsPerSecond,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>,
+        mockOracleProgram: anchor.Program<MockOracle>;
+    const amount = 100 * 10 ** decimals;
+    const depositAmount = amount * 5;
+    const feedId = 0;
+    const expo = -5;
+    const startingPrice = new anchor.BN(2_500_000);
+    const nonPlatformShare = bps - sampleGlobalRoundInfo.allocation.platformShare;
+
+    function now() {
+        return new anchor.BN(Math.floor(Date.now() / millisecondsPerSecond));
+    }
+
+    async function setPrice(price: anchor.BN, publishTime: anchor.BN = now()) {
+        await mockOracle.setPrice(
+            owner,
+            feedId,
+            price,
+            new anchor.BN(1),
+            expo,
+            publishTime,
+            mockOracleProgram
+        );
+    }
+
+    async function playRound(endingPrice: anchor.BN, betOnShortSide: boolean = true) {
+        await setPrice(startingPrice);
+        await programMethods.startRound(owner, bearishDotFun);
+        const currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+        if (betOnShortSide)
+            await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await setPrice(endingPrice);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        return currentRoundIndex;
+    }
+
+    async function getJackpotPoolAmount() {
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+
+        return platformConfigAccount.globalRoundInfo.jackpotPoolAmount.toNumber();
+    }
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+        mockOracleProgram = anchor.workspace.MockOracle as anchor.Program<MockOracle>;
+
+        await setPrice(startingPrice);
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            {
+                ...sampleGlobalRoundInfo,
+                priceAccount: mockOracle.getPriceAccount(
+                    owner.publicKey,
+                    feedId,
+                    mockOracleProgram
+                ),
+            },
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(depositAmount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(depositAmount), bearishDotFun);
+    });
+
+    it("Settles rounds at the mock oracle's price", async () => {
+        const endingPrice = startingPrice.addn(1);
+        const currentRoundIndex = await playRound(endingPrice);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.startingPrice.toNumber(), startingPrice.toNumber());
+        assert.strictEqual(roundAccount.endingPrice.toNumber(), endingPrice.toNumber());
+
+        await programMethods.claimUserWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+    });
+
+    it("Lets shorts win when the price drops", async () => {
+        const currentRoundIndex = await playRound(startingPrice.subn(1));
+
+        try {
+            await programMethods.claimUserWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.ineligibleForClaim
+            );
+        }
+        await programMethods.claimUserWinnings(user2, currentRoundIndex - 1, bearishDotFun);
+    });
+
+    it("Sends both sides to the jackpot and platform on a tie", async () => {
+        const jackpotPoolAmountBefore = await getJackpotPoolAmount();
+        await playRound(startingPrice);
+
+        const expectedJackpotAmount = (2 * amount * nonPlatformShare) / bps;
+        assert.strictEqual(
+            (await getJackpotPoolAmount()) - jackpotPoolAmountBefore,
+            expectedJackpotAmount
+        );
+    });
+
+    it("Sends the losing side to the jackpot and platform if nobody won", async () => {
+        const jackpotPoolAmountBefore = await getJackpotPoolAmount();
+        await playRound(startingPrice.subn(1), false);
+
+        const expectedJackpotAmount = (amount * nonPlatformShare) / bps;
+        assert.strictEqual(
+            (await getJackpotPoolAmount()) - jackpotPoolAmountBefore,
+            expectedJackpotAmount
+        );
+    });
+
+    it("Doesn't allow ending a round on a stale price", async () => {
+        const stalenessThreshold = new anchor.BN(60);
+        await programMethods.setStalenessThreshold(owner, stalenessThreshold, bearishDotFun);
+        await setPrice(startingPrice);
+        await programMethods.startRound(owner, bearishDotFun);
+
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await setPrice(startingPrice, now().sub(stalenessThreshold).subn(60));
+        let hasFailed = false;
+        try {
+            await programMethods.endRound(owner, bearishDotFun);
+        } catch {
+            hasFailed = true;
+        }
+        assert.isTrue(hasFailed);
+
+        await setPrice(startingPrice);
+        await programMethods.endRound(owner, bearishDotFun);
+        await programMethods.setStalenessThreshold(
+            owner,
+            sampleGlobalRoundInfo.stalenessThreshold,
+            bearishDotFun
+        );
+    });
+});
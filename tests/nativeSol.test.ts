@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = anchor.web3.LAMPORTS_PER_SOL;
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+        await programMethods.setAffiliate(user1, owner.publicKey, bearishDotFun);
+    });
+
+    it("Allows the owner to open a native SOL market", async () => {
+        await programMethods.whitelistNativeSol(owner, bearishDotFun);
+
+        const mintMarketAccount = await bearishDotFun.account.mintMarket.fetch(
+            pda.getMintMarket(spl.NATIVE_MINT, bearishDotFun)
+        );
+        assert.deepStrictEqual(mintMarketAccount.mint, spl.NATIVE_MINT);
+        assert.isTrue(mintMarketAccount.isNative);
+        assert.isTrue(mintMarketAccount.isEnabled);
+    });
+
+    it("Allows depositing native SOL", async () => {
+        const nativeVault = pda.getMintVault(spl.NATIVE_MINT, bearishDotFun);
+        const nativeVaultBalanceBefore = await provider.connection.getBalance(nativeVault);
+
+        await programMethods.depositNativeSol(user1, new anchor.BN(amount), bearishDotFun);
+        await programMethods.depositNativeSol(user2, new anchor.BN(amount), bearishDotFun);
+
+        const nativeVaultBalanceAfter = await provider.connection.getBalance(nativeVault);
+        assert.strictEqual(nativeVaultBalanceAfter - nativeVaultBalanceBefore, amount * 2);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, spl.NATIVE_MINT, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), amount);
+    });
+
+    it("Settles and pays out rounds in lamports", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeMintBet(
+            user1,
+            spl.NATIVE_MINT,
+            new anchor.BN(amount),
+            true,
+            bearishDotFun
+        );
+        await programMethods.placeMintBet(
+            user2,
+            spl.NATIVE_MINT,
+            new anchor.BN(amount),
+            false,
+            bearishDotFun
+        );
+
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+        await programMethods.settleMintRound(spl.NATIVE_MINT, currentRoundIndex - 1, bearishDotFun);
+
+        await programMethods.claimMintUserWinnings(
+            user1,
+            spl.NATIVE_MINT,
+            currentRoundIndex - 1,
+            bearishDotFun
+        );
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, spl.NATIVE_MINT, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            amount + (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps
+        );
+    });
+
+    it("Pays affiliate winnings in lamports", async () => {
+        const ownerBalanceBefore = await provider.connection.getBalance(owner.publicKey);
+
+        await programMethods.claimNativeAffiliateWinnings(
+            owner,
+            user1.publicKey,
+            currentRoundIndex - 1,
+            bearishDotFun
+        );
+
+        const ownerBalanceAfter = await provider.connection.getBalance(owner.publicKey);
+        assert.isAbove(ownerBalanceAfter, ownerBalanceBefore);
+    });
+
+    it("Allows withdrawing native SOL", async () => {
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, spl.NATIVE_MINT, bearishDotFun)
+        );
+        const userBalanceBefore = await provider.connection.getBalance(user1.publicKey);
+
+        await programMethods.withdrawNativeSol(user1, userInfoAccount.amount, bearishDotFun);
+
+        const userBalanceAfter = await provider.connection.getBalance(user1.publicKey);
+        assert.isAbove(userBalanceAfter, userBalanceBefore);
+
+        const userInfoAccountAfter = await bearishDotFun.account.userInfo.fetch(
+            pda.getMintUserInfo(user1.publicKey, spl.NATIVE_MINT, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccountAfter.amount.toNumber(), 0);
+    });
+
+    it("Allows the owner to withdraw platform fees in lamports", async () => {
+        await programMethods.withdrawNativePlatformFees(owner, bearishDotFun);
+
+        const mintMarketAccount = await bearishDotFun.account.mintMarket.fetch(
+            pda.getMintMarket(spl.NATIVE_MINT, bearishDotFun)
+        );
+        assert.strictEqual(mintMarketAccount.accumulatedPlatformFees.toNumber(), 0);
+    });
+});
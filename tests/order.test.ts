@@ -0,0 +1,191 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, runRound } from "./utils/utils";
+import { setup } from "./utils/setup";
+import { bps, decimals, errors, sampleGlobalRoundInfo } from "./utils/constants";
+import { User } from "./utils/types";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    const amount = 100 * 10 ** decimals;
+    const executorFee = 10 ** decimals;
+    const depositAmount = amount * 3;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(depositAmount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(depositAmount), bearishDotFun);
+    });
+
+    it("Allows creating an order and escrows the bet amount and executor fee", async () => {
+        await programMethods.createOrder(
+            user1,
+            0,
+            new anchor.BN(amount),
+            true,
+            { always: {} },
+            new anchor.BN(executorFee),
+            bearishDotFun
+        );
+
+        const orderAccount = await bearishDotFun.account.order.fetch(
+            pda.getOrder(user1.publicKey, 0, bearishDotFun)
+        );
+        assert.deepStrictEqual(orderAccount.user, user1.publicKey);
+        assert.strictEqual(orderAccount.amount.toNumber(), amount);
+        assert.isTrue(orderAccount.isLong);
+        assert.deepEqual(orderAccount.condition, { always: {} });
+        assert.strictEqual(orderAccount.executorFee.toNumber(), executorFee);
+        // No round has started yet, so the order bets in the one after the first.
+        assert.strictEqual(orderAccount.targetRound.toNumber(), 2);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            depositAmount - amount - executorFee
+        );
+    });
+
+    it("Doesn't allow creating an order with an invalid condition", async () => {
+        try {
+            await programMethods.createOrder(
+                user1,
+                1,
+                new anchor.BN(amount),
+                true,
+                { priceRoseBy: { bps: bps + 1 } },
+                new anchor.BN(executorFee),
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidOrderCondition
+            );
+        }
+    });
+
+    it("Allows a keeper to execute an order once its condition is met", async () => {
+        await programMethods.createOrder(
+            user1,
+            1,
+            new anchor.BN(amount),
+            false,
+            { priceRoseBy: { bps } },
+            new anchor.BN(executorFee),
+            bearishDotFun
+        );
+
+        const userData: User[] = [
+            {
+                keypair: user2,
+                amount: new anchor.BN(amount),
+                isLong: true,
+                claimWinnings: false,
+            },
+        ];
+        await runRound(owner, userData, true, bearishDotFun);
+
+        await programMethods.startRound(owner, bearishDotFun);
+        const currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.executeOrder(owner, user1.publicKey, 0, bearishDotFun);
+
+        const orderAccount = await bearishDotFun.account.order.fetchNullable(
+            pda.getOrder(user1.publicKey, 0, bearishDotFun)
+        );
+        assert.isNull(orderAccount);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount);
+        assert.isTrue(userBetAccount.isLong);
+
+        const executorInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(owner.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(executorInfoAccount.amount.toNumber(), executorFee);
+    });
+
+    it("Doesn't allow executing an order whose condition is not met", async () => {
+        try {
+            await programMethods.executeOrder(owner, user1.publicKey, 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.orderConditionNotMet
+            );
+        }
+    });
+
+    it("Doesn't allow executing an order before its target round", async () => {
+        await programMethods.createOrder(
+            user2,
+            0,
+            new anchor.BN(amount),
+            true,
+            { always: {} },
+            new anchor.BN(executorFee),
+            bearishDotFun
+        );
+
+        try {
+            await programMethods.executeOrder(owner, user2.publicKey, 0, bearishDotFun);
+            assert.fail("Executing an order before its target round should fail");
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.notOrderTargetRound
+            );
+        }
+
+        await programMethods.cancelOrder(user2, 0, bearishDotFun);
+    });
+
+    it("Allows cancelling an order and refunds the escrow", async () => {
+        const userBalanceBefore = (
+            await bearishDotFun.account.userInfo.fetch(
+                pda.getUserInfo(user1.publicKey, bearishDotFun)
+            )
+        ).amount.toNumber();
+
+        await programMethods.cancelOrder(user1, 1, bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber() - userBalanceBefore,
+            amount + executorFee
+        );
+
+        const orderAccount = await bearishDotFun.account.order.fetchNullable(
+            pda.getOrder(user1.publicKey, 1, bearishDotFun)
+        );
+        assert.isNull(orderAccount);
+    });
+});
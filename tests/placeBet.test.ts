@@ -114,10 +114,66 @@ describe("bearish-dot-fun", () => {
         assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), amount);
     });
 
-    it("Doesn't allow placing bet for the same round again", async () => {
+    it("Allows topping up an existing position in the same round", async () => {
+        const topUpAmount = amount / 2;
+        await programMethods.deposit(user1, new anchor.BN(topUpAmount), bearishDotFun);
+
+        await programMethods.placeBet(user1, new anchor.BN(topUpAmount), true, bearishDotFun);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount + topUpAmount);
+        assert.isTrue(userBetAccount.isLong);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), 0);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.longPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.totalBetAmountLong.toNumber(), amount + topUpAmount);
+    });
+
+    it("Doesn't double count affiliates when topping up a position", async () => {
+        const topUpAmount = amount / 2;
+        await programMethods.deposit(user2, new anchor.BN(topUpAmount), bearishDotFun);
+
+        await programMethods.placeBet(user2, new anchor.BN(topUpAmount), false, bearishDotFun);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.shortPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.affiliatesForShortPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), amount + topUpAmount);
+    });
+
+    it("Doesn't allow topping up a position with amount 0", async () => {
         try {
-            await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
-        } catch {}
+            await programMethods.placeBet(user2, new anchor.BN(0), false, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.betAmountZero
+            );
+        }
+    });
+
+    it("Doesn't allow placing bet on the opposite side in the same round", async () => {
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+
+        try {
+            await programMethods.placeBet(user2, new anchor.BN(amount), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.cannotBetOnBothSides
+            );
+        }
     });
 
     it("Doesn't allow placing bet for a completed round", async () => {
@@ -128,7 +184,9 @@ describe("bearish-dot-fun", () => {
             await bearishDotFun.methods
                 .placeBet(new anchor.BN(amount), false)
                 .accounts({
+                    authority: user1.publicKey,
                     user: user1.publicKey,
+                    access: pda.getUserAccess(user1.publicKey, bearishDotFun),
                     round: pda.getRound(currentRoundIndex, bearishDotFun),
                     userBet: pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun),
                 })
@@ -0,0 +1,110 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods } from "./utils/utils";
+import { setup } from "./utils/setup";
+import { bumpRangeInclusive, decimals, errors, sampleGlobalRoundInfo } from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+    });
+
+    it("Allows a first-time user to bet directly from their wallet", async () => {
+        const user1AssociatedTokenAccountAddress = await spl.getAssociatedTokenAddress(
+            stablecoin,
+            user1.publicKey
+        );
+        const user1BalanceBefore = (
+            await spl.getAccount(provider.connection, user1AssociatedTokenAccountAddress)
+        ).amount;
+
+        await programMethods.placeBetFromWallet(user1, new anchor.BN(amount), true, bearishDotFun);
+
+        const user1BalanceAfter = (
+            await spl.getAccount(provider.connection, user1AssociatedTokenAccountAddress)
+        ).amount;
+        assert.strictEqual(Number(user1BalanceBefore) - Number(user1BalanceAfter), amount);
+
+        const platformVaultBalanceAfter = (
+            await spl.getAccount(provider.connection, pda.getPlatformVault(bearishDotFun))
+        ).amount;
+        assert.strictEqual(Number(platformVaultBalanceAfter), amount);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), 0);
+        assert.isTrue(
+            userInfoAccount.bump >= bumpRangeInclusive[0] &&
+                userInfoAccount.bump <= bumpRangeInclusive[1]
+        );
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount);
+        assert.isTrue(userBetAccount.isLong);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.longPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.totalBetAmountLong.toNumber(), amount);
+    });
+
+    it("Leaves an existing deposited balance untouched", async () => {
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+
+        await programMethods.placeBetFromWallet(user2, new anchor.BN(amount), false, bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), amount);
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.shortPositions.toNumber(), 1);
+        assert.strictEqual(roundAccount.totalBetAmountShort.toNumber(), amount);
+    });
+
+    it("Doesn't allow betting from wallet with amount 0", async () => {
+        try {
+            await programMethods.placeBetFromWallet(user1, new anchor.BN(0), true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.depositAmountZero
+            );
+        }
+    });
+});
@@ -0,0 +1,115 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    errors,
+    sampleGlobalRoundInfo,
+    decimals,
+    bps,
+    millisecondsPerSecond,
+    priceAccounts,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    const amount = 100 * 10 ** decimals;
+    const depositAmount = amount * 3;
+    const allocation = sampleGlobalRoundInfo.allocation;
+    let roundIndex: number;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(depositAmount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(depositAmount), bearishDotFun);
+
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        roundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+    });
+
+    it("Quotes a bet on the current round", async () => {
+        const shortQuote = await programMethods.quoteBet(
+            new anchor.BN(amount),
+            false,
+            bearishDotFun
+        );
+        const expectedWinnings = (amount * allocation.winnersShare) / bps;
+        assert.strictEqual(shortQuote.winnings.toNumber(), expectedWinnings);
+        assert.strictEqual(shortQuote.payout.toNumber(), amount + expectedWinnings);
+
+        const longQuote = await programMethods.quoteBet(new anchor.BN(amount), true, bearishDotFun);
+        assert.strictEqual(longQuote.winnings.toNumber(), 0);
+        assert.strictEqual(longQuote.payout.toNumber(), amount);
+    });
+
+    it("Quotes a claim as exactly what claiming credits", async () => {
+        await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        const quote = await programMethods.quoteClaim(
+            user1.publicKey,
+            roundIndex - 1,
+            bearishDotFun
+        );
+        assert.strictEqual(quote.amount.toNumber(), amount);
+        assert.strictEqual(quote.winnings.toNumber(), (amount * allocation.winnersShare) / bps);
+        assert.strictEqual(quote.jackpotAmount.toNumber(), 0);
+
+        const userInfoBefore = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        await programMethods.claimUserWinnings(user1, roundIndex - 1, bearishDotFun);
+        const userInfoAfter = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAfter.amount.toNumber() - userInfoBefore.amount.toNumber(),
+            quote.payout.toNumber()
+        );
+    });
+
+    it("Doesn't quote a claim for a losing bet", async () => {
+        try {
+            await programMethods.quoteClaim(user2.publicKey, roundIndex - 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.ineligibleForClaim
+            );
+        }
+    });
+
+    it("Quotes the next streak jackpot", async () => {
+        const quote = await programMethods.quoteJackpot(user1.publicKey, bearishDotFun);
+
+        assert.strictEqual(quote.round.toNumber(), roundIndex + 1);
+        assert.strictEqual(quote.streak.toNumber(), 2);
+        assert.strictEqual(quote.jackpotAmount.toNumber(), 0);
+    });
+});
@@ -0,0 +1,164 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep, transferSOL } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+import { SessionPermissions } from "./utils/types";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const delegate = anchor.web3.Keypair.generate();
+    const amount = 100 * 10 ** decimals;
+    const permissions: SessionPermissions = { placeBet: true, claimWinnings: true };
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await transferSOL(provider, owner, delegate.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+
+        await programMethods.deposit(user1, new anchor.BN(amount * 2), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+    });
+
+    it("Doesn't allow delegating to the user themselves", async () => {
+        const expiresAt = new anchor.BN(Math.floor(Date.now() / millisecondsPerSecond) + 1000);
+
+        try {
+            await programMethods.createSession(
+                user1,
+                user1.publicKey,
+                expiresAt,
+                new anchor.BN(amount),
+                permissions,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidDelegate
+            );
+        }
+    });
+
+    it("Allows a user to create a session for a delegate", async () => {
+        const expiresAt = new anchor.BN(Math.floor(Date.now() / millisecondsPerSecond) + 1000);
+
+        await programMethods.createSession(
+            user1,
+            delegate.publicKey,
+            expiresAt,
+            new anchor.BN(amount),
+            permissions,
+            bearishDotFun
+        );
+
+        const sessionAccount = await bearishDotFun.account.session.fetch(
+            pda.getSession(user1.publicKey, delegate.publicKey, bearishDotFun)
+        );
+        assert.deepStrictEqual(sessionAccount.user, user1.publicKey);
+        assert.deepStrictEqual(sessionAccount.delegate, delegate.publicKey);
+        assert.strictEqual(sessionAccount.expiresAt.toNumber(), expiresAt.toNumber());
+        assert.strictEqual(sessionAccount.maxSpend.toNumber(), amount);
+        assert.strictEqual(sessionAccount.spent.toNumber(), 0);
+        assert.deepEqual(sessionAccount.permissions, permissions);
+    });
+
+    it("Allows a delegate to place a bet on behalf of the user", async () => {
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        await programMethods.placeBetAsDelegate(
+            delegate,
+            user1.publicKey,
+            new anchor.BN(amount),
+            true,
+            bearishDotFun
+        );
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount);
+        assert.isTrue(userBetAccount.isLong);
+
+        const sessionAccount = await bearishDotFun.account.session.fetch(
+            pda.getSession(user1.publicKey, delegate.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(sessionAccount.spent.toNumber(), amount);
+    });
+
+    it("Doesn't allow a delegate to exceed the session spend cap", async () => {
+        try {
+            await programMethods.placeBetAsDelegate(
+                delegate,
+                user1.publicKey,
+                new anchor.BN(amount),
+                true,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.sessionSpendCapExceeded
+            );
+        }
+    });
+
+    it("Allows a delegate to claim winnings on behalf of the user", async () => {
+        await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        await programMethods.claimUserWinningsAsDelegate(
+            delegate,
+            user1.publicKey,
+            currentRoundIndex - 1,
+            bearishDotFun
+        );
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user1.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.isTrue(userBetAccount.hasClaimedWinnings);
+    });
+
+    it("Allows a user to revoke a session", async () => {
+        await programMethods.revokeSession(user1, delegate.publicKey, bearishDotFun);
+
+        const sessionAccount = await bearishDotFun.account.session.fetchNullable(
+            pda.getSession(user1.publicKey, delegate.publicKey, bearishDotFun)
+        );
+        assert.isNull(sessionAccount);
+    });
+});
@@ -5,7 +5,7 @@ import { BearishDotFun } from "../target/types/bearish_dot_fun";
 
 import { pda, programMethods } from "./utils/utils";
 import { setup } from "./utils/setup";
-import { decimals, errors, priceAccounts, sampleGlobalRoundInfo } from "./utils/constants";
+import { bps, decimals, errors, priceAccounts, sampleGlobalRoundInfo } from "./utils/constants";
 import { Allocation, JackPotAllocation } from "./utils/types";
 
 describe("bearish-dot-fun", () => {
@@ -200,6 +200,130 @@ describe("bearish-dot-fun", () => {
         );
     });
 
+    it("Allows setting cancellation window", async () => {
+        const cancellationWindow = new anchor.BN(10);
+
+        await programMethods.setCancellationWindow(owner, cancellationWindow, bearishDotFun);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.cancellationWindow.toNumber(),
+            cancellationWindow.toNumber()
+        );
+    });
+
+    it("Allows setting cancellation fee", async () => {
+        const cancellationFee = 100;
+
+        await programMethods.setCancellationFee(owner, cancellationFee, bearishDotFun);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(platformConfigAccount.globalRoundInfo.cancellationFee, cancellationFee);
+    });
+
+    it("Doesn't allow setting cancellation fee above 100%", async () => {
+        try {
+            await programMethods.setCancellationFee(owner, bps + 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.exceedsMaxFee
+            );
+        }
+    });
+
+    it("Allows setting max bet amount", async () => {
+        const maxBetAmount = new anchor.BN(1000 * 10 ** decimals);
+
+        await programMethods.setMaxBetAmount(owner, maxBetAmount, bearishDotFun);
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.maxBetAmount.toNumber(),
+            maxBetAmount.toNumber()
+        );
+    });
+
+    it("Allows setting max bet amount per user per round", async () => {
+        const maxUserBetAmountPerRound = new anchor.BN(1000 * 10 ** decimals);
+
+        await programMethods.setMaxUserBetAmountPerRound(
+            owner,
+            maxUserBetAmountPerRound,
+            bearishDotFun
+        );
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.maxUserBetAmountPerRound.toNumber(),
+            maxUserBetAmountPerRound.toNumber()
+        );
+    });
+
+    it("Allows setting max round imbalance", async () => {
+        const maxRoundImbalance = new anchor.BN(3 * bps);
+        const maxOneSidedAmount = new anchor.BN(1000);
+
+        await programMethods.setMaxRoundImbalance(
+            owner,
+            maxRoundImbalance,
+            maxOneSidedAmount,
+            bearishDotFun
+        );
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.maxRoundImbalance.toNumber(),
+            maxRoundImbalance.toNumber()
+        );
+        assert.strictEqual(
+            platformConfigAccount.globalRoundInfo.maxOneSidedAmount.toNumber(),
+            maxOneSidedAmount.toNumber()
+        );
+    });
+
+    it("Doesn't allow setting max round imbalance below 100%", async () => {
+        try {
+            await programMethods.setMaxRoundImbalance(
+                owner,
+                new anchor.BN(bps - 1),
+                new anchor.BN(1000),
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidMaxRoundImbalance
+            );
+        }
+    });
+
+    it("Doesn't allow setting max round imbalance without a max one-sided amount", async () => {
+        try {
+            await programMethods.setMaxRoundImbalance(
+                owner,
+                new anchor.BN(3 * bps),
+                new anchor.BN(0),
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidMaxOneSidedAmount
+            );
+        }
+    });
+
     it("Doesn't allow non-owner to change platform config", async () => {
         const duration = new anchor.BN(1000);
 
@@ -53,6 +53,10 @@ describe("bearish-dot-fun", () => {
         );
         assert.isAbove(roundAccount.startTime.toNumber(), 0);
         assert.isAbove(roundAccount.startingPrice.toNumber(), 0);
+        assert.strictEqual(
+            roundAccount.strikePrice.toNumber(),
+            roundAccount.startingPrice.toNumber()
+        );
         assert.strictEqual(roundAccount.endingPrice.toNumber(), 0);
         assert.strictEqual(roundAccount.longPositions.toNumber(), 0);
         assert.strictEqual(roundAccount.shortPositions.toNumber(), 0);
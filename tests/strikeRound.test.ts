@@ -0,0 +1,173 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, sleep } from "./utils/utils";
+import { setup } from "./utils/setup";
+import {
+    bps,
+    decimals,
+    errors,
+    millisecondsPerSecond,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+} from "./utils/constants";
+
+describe("bearish-dot-fun", () => {
+    let owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let currentRoundIndex: number;
+    const amount = 100 * 10 ** decimals;
+    const strikeOffset = 200;
+
+    before(async () => {
+        ({ owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+        await programMethods.setPriceAccount(owner, priceAccounts.solUsd, bearishDotFun);
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(amount), bearishDotFun);
+    });
+
+    it("Doesn't allow a strike offset of -100% or below", async () => {
+        try {
+            await programMethods.setStrikeOffset(owner, -bps, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidStrikeOffset
+            );
+        }
+    });
+
+    it("Doesn't allow a strike offset above 100%", async () => {
+        try {
+            await programMethods.setStrikeOffset(owner, bps + 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidStrikeOffset
+            );
+        }
+    });
+
+    it("Offsets the strike price from the starting price", async () => {
+        await programMethods.setStrikeOffset(owner, strikeOffset, bearishDotFun);
+        await programMethods.startRound(owner, bearishDotFun);
+        currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(
+            roundAccount.strikePrice.toString(),
+            roundAccount.startingPrice
+                .mul(new anchor.BN(bps + strikeOffset))
+                .div(new anchor.BN(bps))
+                .toString()
+        );
+
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+    });
+
+    it("Allows the owner to start a round with an explicit strike price", async () => {
+        // A strike far above any real price, so the round closes below it.
+        const strikePrice = new anchor.BN(2).pow(new anchor.BN(62));
+        await programMethods.startRoundWithStrike(owner, strikePrice, bearishDotFun);
+        currentRoundIndex += 1;
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.strikePrice.toString(), strikePrice.toString());
+    });
+
+    it("Settles the round against the strike price instead of the starting price", async () => {
+        await programMethods.placeBet(user1, new anchor.BN(amount), true, bearishDotFun);
+        await programMethods.placeBet(user2, new anchor.BN(amount), false, bearishDotFun);
+
+        // The price rises from SOL/USD to BTC/USD, but stays below the strike.
+        await programMethods.setPriceAccount(owner, priceAccounts.btcUsd, bearishDotFun);
+        await sleep(sampleGlobalRoundInfo.duration.toNumber() * millisecondsPerSecond);
+        await programMethods.endRound(owner, bearishDotFun);
+
+        await programMethods.claimUserWinnings(user2, currentRoundIndex - 1, bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            amount + (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps
+        );
+    });
+
+    it("Doesn't allow bets on the wrong side of the strike to claim", async () => {
+        try {
+            await programMethods.claimUserWinnings(user1, currentRoundIndex - 1, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.ineligibleForClaim
+            );
+        }
+    });
+
+    it("Doesn't allow anyone but the owner or strike authority to hold off a round", async () => {
+        try {
+            await programMethods.setStrikeOverridePending(user1, true, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.notStrikeAuthority
+            );
+        }
+    });
+
+    it("Doesn't allow a round to be started while a strike override is pending", async () => {
+        await programMethods.setStrikeAuthority(owner, user1.publicKey, bearishDotFun);
+        await programMethods.setStrikeOverridePending(user1, true, bearishDotFun);
+
+        try {
+            await programMethods.startRound(user2, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.strikeOverridePending
+            );
+        }
+    });
+
+    it("Allows the strike authority to start the held off round with a strike price", async () => {
+        const strikePrice = new anchor.BN(2).pow(new anchor.BN(62));
+        await programMethods.startRoundWithStrike(user1, strikePrice, bearishDotFun);
+        currentRoundIndex += 1;
+
+        const roundAccount = await bearishDotFun.account.round.fetch(
+            pda.getRound(currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(roundAccount.strikePrice.toString(), strikePrice.toString());
+
+        const platformConfigAccount = await bearishDotFun.account.platformConfig.fetch(
+            pda.getPlatformConfig(bearishDotFun)
+        );
+        assert.isFalse(platformConfigAccount.isStrikeOverridePending);
+    });
+});
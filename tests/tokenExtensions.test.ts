@@ -0,0 +1,147 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods } from "./utils/utils";
+import { setup } from "./utils/setup";
+import { bps, decimals, errors, sampleGlobalRoundInfo } from "./utils/constants";
+
+async function createToken2022Mint(
+    provider: anchor.AnchorProvider,
+    owner: anchor.web3.Keypair,
+    extensionType: spl.ExtensionType,
+    initializeExtensionInstruction: (
+        mint: anchor.web3.PublicKey
+    ) => anchor.web3.TransactionInstruction
+) {
+    const mint = anchor.web3.Keypair.generate();
+    const mintLength = spl.getMintLen([extensionType]);
+
+    const transaction = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+            fromPubkey: owner.publicKey,
+            newAccountPubkey: mint.publicKey,
+            space: mintLength,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLength),
+            programId: spl.TOKEN_2022_PROGRAM_ID,
+        }),
+        initializeExtensionInstruction(mint.publicKey),
+        spl.createInitializeMintInstruction(
+            mint.publicKey,
+            decimals,
+            owner.publicKey,
+            null,
+            spl.TOKEN_2022_PROGRAM_ID
+        )
+    );
+    await anchor.web3.sendAndConfirmTransaction(provider.connection, transaction, [owner, mint]);
+
+    return mint.publicKey;
+}
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    const amount = 100 * 10 ** decimals;
+    const transferFeeBps = 100;
+
+    before(async () => {
+        ({ provider, owner, user1, bearishDotFun } = await setup());
+    });
+
+    it("Doesn't allow initializing with a permanent delegate mint", async () => {
+        const mint = await createToken2022Mint(
+            provider,
+            owner,
+            spl.ExtensionType.PermanentDelegate,
+            (mint) =>
+                spl.createInitializePermanentDelegateInstruction(
+                    mint,
+                    owner.publicKey,
+                    spl.TOKEN_2022_PROGRAM_ID
+                )
+        );
+
+        try {
+            await programMethods.initialize(
+                owner,
+                mint,
+                spl.TOKEN_2022_PROGRAM_ID,
+                sampleGlobalRoundInfo,
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.unsupportedMintExtension
+            );
+        }
+    });
+
+    it("Credits only the amount received by the vault for transfer fee mints", async () => {
+        const mint = await createToken2022Mint(
+            provider,
+            owner,
+            spl.ExtensionType.TransferFeeConfig,
+            (mint) =>
+                spl.createInitializeTransferFeeConfigInstruction(
+                    mint,
+                    owner.publicKey,
+                    owner.publicKey,
+                    transferFeeBps,
+                    BigInt(amount),
+                    spl.TOKEN_2022_PROGRAM_ID
+                )
+        );
+
+        await programMethods.initialize(
+            owner,
+            mint,
+            spl.TOKEN_2022_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        const userTokenAccount = await spl.getOrCreateAssociatedTokenAccount(
+            provider.connection,
+            user1,
+            mint,
+            user1.publicKey,
+            false,
+            undefined,
+            undefined,
+            spl.TOKEN_2022_PROGRAM_ID
+        );
+        await spl.mintTo(
+            provider.connection,
+            user1,
+            mint,
+            userTokenAccount.address,
+            owner,
+            amount,
+            [],
+            undefined,
+            spl.TOKEN_2022_PROGRAM_ID
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(amount), bearishDotFun);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        const platformVaultBalance = await provider.connection.getTokenAccountBalance(
+            pda.getPlatformVault(bearishDotFun)
+        );
+        assert.strictEqual(
+            userInfoAccount.amount.toNumber(),
+            amount - (amount * transferFeeBps) / bps
+        );
+        assert.strictEqual(
+            Number(platformVaultBalance.value.amount),
+            userInfoAccount.amount.toNumber()
+        );
+    });
+});
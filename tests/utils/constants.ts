@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 
-import { GlobalRoundInfo } from "./types";
+import { AllowedMintExtensions, GlobalRoundInfo } from "./types";
 
 const decimals = 9;
 const bps = 10000;
@@ -18,6 +18,16 @@ const seeds = {
     user: "user",
     round: "round",
     userBet: "user_bet",
+    session: "session",
+    order: "order",
+    attestation: "attestation",
+    blockedUser: "blocked_user",
+    mintMarket: "mint_market",
+    housePool: "house_pool",
+    lpMint: "lp_mint",
+    fixedOddsBet: "fixed_odds_bet",
+    bucketBet: "bucket_bet",
+    mockPriceAccount: "price_account",
 };
 
 const errors = {
@@ -39,6 +49,52 @@ const errors = {
     alreadyCollectedPlatformFees: "Already collected platform fees.",
     claimAmountZero: "Claim amount cannot be 0.",
     platformFeeAmountZero: "Platform fee amount to collect is 0.",
+    betAmountZero: "Bet amount cannot be 0.",
+    cannotBetOnBothSides: "Cannot bet on both sides of a round.",
+    cancellationWindowElapsed: "Cancellation window has elapsed.",
+    cancelAmountZero: "Cancel amount cannot be 0.",
+    cancelAmountExceedsBetAmount: "Cancel amount exceeds bet amount.",
+    invalidPayoutTokenAccount: "Invalid payout token account.",
+    missingPayoutAccounts: "Missing accounts required to pay out winnings.",
+    noRepeatBet: "User has no repeat bet standing order.",
+    repeatBetRentNotEscrowed: "Not enough lamports escrowed for the repeat bet's rent.",
+    insufficientBalance: "Insufficient balance.",
+    invalidDelegate: "Invalid delegate address.",
+    sessionExpired: "Session has expired.",
+    sessionPermissionDenied: "Session does not permit this instruction.",
+    sessionSpendCapExceeded: "Session spend cap exceeded.",
+    sessionRequired: "A valid session is required for delegated calls.",
+    invalidOrderCondition: "Invalid order condition.",
+    orderConditionNotMet: "Order condition not met.",
+    notOrderTargetRound: "Orders can only be executed in their target round.",
+    betAmountAboveMaxBetAmount: "Bet amount cannot be more than the maximum bet amount.",
+    userRoundExposureExceeded: "Exceeds max bet amount per user per round.",
+    roundImbalanceExceeded: "Exceeds max round imbalance.",
+    invalidMaxRoundImbalance: "Max round imbalance must be 0 or at least 100%.",
+    invalidMaxOneSidedAmount: "Max one-sided amount must be set along with the max round imbalance.",
+    userBlocked: "User is blocked.",
+    invalidAttestation: "Invalid attestation.",
+    attestationRevoked: "Attestation has been revoked.",
+    attestationExpired: "Attestation has expired.",
+    gatekeeperDefaultPubkey: "Gatekeeper cannot be default pubkey.",
+    mintMarketDisabled: "Mint market is disabled.",
+    mintIsPlatformStablecoin: "Mint is already the platform stablecoin.",
+    unsupportedMintExtension: "Mint has an unsupported extension.",
+    mintMarketNotNative: "Mint market does not hold native SOL.",
+    invalidMaxExposure: "Max exposure cannot exceed 100%.",
+    housePoolDepleted: "House pool has no liquidity left.",
+    liquidityAmountZero: "Liquidity amount cannot be 0.",
+    sharesAmountZero: "Share amount cannot be 0.",
+    fixedOddsDisabled: "Fixed-odds betting is disabled.",
+    invalidFixedOddsConfig: "Invalid fixed-odds configuration.",
+    insufficientHouseLiquidity: "House liquidity cannot cover this.",
+    housePoolRequired: "House pool is required.",
+    invalidBucketBoundaries: "Bucket boundaries must be increasing and at most 7.",
+    invalidBucket: "Invalid bucket.",
+    cannotBetOnMultipleBuckets: "Cannot bet on multiple buckets of a round.",
+    invalidStrikeOffset: "Strike offset must be above -100% and at most 100%.",
+    notStrikeAuthority: "Signer is not the owner or strike authority.",
+    strikeOverridePending: "A strike price override is pending.",
 };
 
 const sampleGlobalRoundInfo: GlobalRoundInfo = {
@@ -63,6 +119,18 @@ const sampleGlobalRoundInfo: GlobalRoundInfo = {
     stalenessThreshold: new anchor.BN(1e9), // Setting to an extremely high value for testing purposes
     jackpotPoolAmount: new anchor.BN(0),
     accumulatedPlatformFees: new anchor.BN(0),
+    cancellationWindow: new anchor.BN(0),
+    cancellationFee: 0,
+    maxBetAmount: new anchor.BN(0),
+    maxUserBetAmountPerRound: new anchor.BN(0),
+    maxRoundImbalance: new anchor.BN(0),
+    maxOneSidedAmount: new anchor.BN(0),
+};
+
+const noMintExtensionsAllowed: AllowedMintExtensions = {
+    permanentDelegate: false,
+    nonTransferable: false,
+    transferHook: false,
 };
 
 export {
@@ -74,4 +142,5 @@ export {
     seeds,
     errors,
     sampleGlobalRoundInfo,
+    noMintExtensionsAllowed,
 };
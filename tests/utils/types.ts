@@ -26,8 +26,35 @@ interface GlobalRoundInfo {
     stalenessThreshold: anchor.BN;
     jackpotPoolAmount: anchor.BN;
     accumulatedPlatformFees: anchor.BN;
+    cancellationWindow: anchor.BN;
+    cancellationFee: number;
+    maxBetAmount: anchor.BN;
+    maxUserBetAmountPerRound: anchor.BN;
+    maxRoundImbalance: anchor.BN;
+    maxOneSidedAmount: anchor.BN;
 }
 
+interface AllowedMintExtensions {
+    permanentDelegate: boolean;
+    nonTransferable: boolean;
+    transferHook: boolean;
+}
+
+type WinningsPreference =
+    | { keepInBalance: {} }
+    | { withdrawToWallet: { tokenAccount: anchor.web3.PublicKey } }
+    | { repeatBet: { amount: anchor.BN; isLong: boolean } };
+
+interface SessionPermissions {
+    placeBet: boolean;
+    claimWinnings: boolean;
+}
+
+type OrderCondition =
+    | { always: {} }
+    | { priceDroppedBy: { bps: number } }
+    | { priceRoseBy: { bps: number } };
+
 interface User {
     keypair: anchor.web3.Keypair;
     amount: anchor.BN;
@@ -35,4 +62,13 @@ interface User {
     claimWinnings: boolean;
 }
 
-export { Allocation, JackPotAllocation, GlobalRoundInfo, User };
+export {
+    Allocation,
+    JackPotAllocation,
+    GlobalRoundInfo,
+    AllowedMintExtensions,
+    WinningsPreference,
+    SessionPermissions,
+    OrderCondition,
+    User,
+};
@@ -2,9 +2,25 @@ import * as anchor from "@coral-xyz/anchor";
 import * as spl from "@solana/spl-token";
 import { Connection, sendAndConfirmTransaction, SystemProgram, Transaction } from "@solana/web3.js";
 import { BearishDotFun } from "../../target/types/bearish_dot_fun";
+import { MockOracle } from "../../target/types/mock_oracle";
 
-import { Allocation, GlobalRoundInfo, JackPotAllocation, User } from "./types";
-import { millisecondsPerSecond, priceAccounts, sampleGlobalRoundInfo, seeds } from "./constants";
+import {
+    Allocation,
+    AllowedMintExtensions,
+    GlobalRoundInfo,
+    JackPotAllocation,
+    OrderCondition,
+    SessionPermissions,
+    User,
+    WinningsPreference,
+} from "./types";
+import {
+    millisecondsPerSecond,
+    noMintExtensionsAllowed,
+    priceAccounts,
+    sampleGlobalRoundInfo,
+    seeds,
+} from "./constants";
 
 function sleep(ms: number): Promise<void> {
     return new Promise((resolve) => setTimeout(resolve, ms));
@@ -57,6 +73,18 @@ async function getStablecoin(
     );
 }
 
+async function getEvents(txSignature: string, program: anchor.Program<BearishDotFun>) {
+    const connection = program.provider.connection;
+    await connection.confirmTransaction(txSignature, "confirmed");
+    const transaction = await connection.getTransaction(txSignature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+    });
+
+    const eventParser = new anchor.EventParser(program.programId, program.coder);
+    return Array.from(eventParser.parseLogs(transaction.meta.logMessages));
+}
+
 async function runRound(
     owner: anchor.web3.Keypair,
     users: User[],
@@ -131,6 +159,140 @@ const pda = {
             program.programId
         )[0];
     },
+    getFixedOddsBet(
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.fixedOddsBet),
+                user.toBuffer(),
+                new anchor.BN(roundIndex).toArrayLike(Buffer, "be", 8),
+            ],
+            program.programId
+        )[0];
+    },
+    getBucketBet(
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.bucketBet),
+                user.toBuffer(),
+                new anchor.BN(roundIndex).toArrayLike(Buffer, "be", 8),
+            ],
+            program.programId
+        )[0];
+    },
+    getSession(
+        user: anchor.web3.PublicKey,
+        delegate: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.session), user.toBuffer(), delegate.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getAttestation(user: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.attestation), user.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getBlockedUser(user: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.blockedUser), user.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getUserAccess(user: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return {
+            attestation: pda.getAttestation(user, program),
+            blockedUser: pda.getBlockedUser(user, program),
+        };
+    },
+    getOrder(
+        user: anchor.web3.PublicKey,
+        orderId: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.order),
+                user.toBuffer(),
+                new anchor.BN(orderId).toArrayLike(Buffer, "be", 8),
+            ],
+            program.programId
+        )[0];
+    },
+    getHousePool(program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.housePool)],
+            program.programId
+        )[0];
+    },
+    getLpMint(program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.lpMint)],
+            program.programId
+        )[0];
+    },
+    getMintMarket(mint: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.mintMarket), mint.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getMintVault(mint: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.platformVault), mint.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getMintUserInfo(
+        user: anchor.web3.PublicKey,
+        mint: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(seeds.user), user.toBuffer(), mint.toBuffer()],
+            program.programId
+        )[0];
+    },
+    getMintRound(
+        roundIndex: number,
+        mint: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.round),
+                new anchor.BN(roundIndex).toArrayLike(Buffer, "be", 8),
+                mint.toBuffer(),
+            ],
+            program.programId
+        )[0];
+    },
+    getMintUserBet(
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        mint: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.userBet),
+                user.toBuffer(),
+                new anchor.BN(roundIndex).toArrayLike(Buffer, "be", 8),
+                mint.toBuffer(),
+            ],
+            program.programId
+        )[0];
+    },
 };
 
 const programMethods = {
@@ -139,10 +301,11 @@ const programMethods = {
         stablecoin: anchor.web3.PublicKey,
         tokenProgramId: anchor.web3.PublicKey,
         globalRoundInfo: GlobalRoundInfo,
-        program: anchor.Program<BearishDotFun>
+        program: anchor.Program<BearishDotFun>,
+        allowedMintExtensions: AllowedMintExtensions = noMintExtensionsAllowed
     ) {
         const txSignature = await program.methods
-            .initialize(globalRoundInfo)
+            .initialize(globalRoundInfo, allowedMintExtensions)
             .accounts({
                 owner: owner.publicKey,
                 stablecoin: stablecoin,
@@ -259,188 +422,147 @@ const programMethods = {
 
         return txSignature;
     },
-    async withdrawPlatformFees(owner: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
-        const provider = program.provider;
-        const stablecoin = (
-            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
-        ).stablecoin;
-        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
-
+    async setCancellationWindow(
+        owner: anchor.web3.Keypair,
+        cancellationWindow: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
         const txSignature = await program.methods
-            .withdrawPlatformFees()
+            .setCancellationWindow(cancellationWindow)
             .accounts({
                 owner: owner.publicKey,
-                stablecoin,
-                ownerTokenAccount: (
-                    await spl.getOrCreateAssociatedTokenAccount(
-                        provider.connection,
-                        owner,
-                        stablecoin,
-                        owner.publicKey
-                    )
-                ).address,
-                tokenProgram: tokenProgramId,
             })
             .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async setAffiliate(
-        user: anchor.web3.Keypair,
-        affiliate: anchor.web3.PublicKey,
+    async setCancellationFee(
+        owner: anchor.web3.Keypair,
+        cancellationFee: number,
         program: anchor.Program<BearishDotFun>
     ) {
         const txSignature = await program.methods
-            .setAffiliate(affiliate)
+            .setCancellationFee(cancellationFee)
             .accounts({
-                user: user.publicKey,
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async deposit(
-        user: anchor.web3.Keypair,
-        amount: anchor.BN,
+    async setMaxBetAmount(
+        owner: anchor.web3.Keypair,
+        maxBetAmount: anchor.BN,
         program: anchor.Program<BearishDotFun>
     ) {
-        const provider = program.provider;
-        const stablecoin = (
-            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
-        ).stablecoin;
-        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
-
         const txSignature = await program.methods
-            .deposit(amount)
+            .setMaxBetAmount(maxBetAmount)
             .accounts({
-                user: user.publicKey,
-                stablecoin,
-                userTokenAccount: await spl.getAssociatedTokenAddress(
-                    stablecoin,
-                    user.publicKey,
-                    false,
-                    tokenProgramId
-                ),
-                tokenProgram: tokenProgramId,
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async withdraw(
-        user: anchor.web3.Keypair,
-        amount: anchor.BN,
+    async setMaxUserBetAmountPerRound(
+        owner: anchor.web3.Keypair,
+        maxUserBetAmountPerRound: anchor.BN,
         program: anchor.Program<BearishDotFun>
     ) {
-        const provider = program.provider;
-        const stablecoin = (
-            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
-        ).stablecoin;
-        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
-
         const txSignature = await program.methods
-            .withdraw(amount)
+            .setMaxUserBetAmountPerRound(maxUserBetAmountPerRound)
             .accounts({
-                user: user.publicKey,
-                stablecoin,
-                userTokenAccount: await spl.getAssociatedTokenAddress(
-                    stablecoin,
-                    user.publicKey,
-                    false,
-                    tokenProgramId
-                ),
-                tokenProgram: tokenProgramId,
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async startRound(user: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
-        const platformConfigAccount = await program.account.platformConfig.fetch(
-            pda.getPlatformConfig(program)
-        );
-        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
-        const priceAccount = platformConfigAccount.globalRoundInfo.priceAccount;
-
+    async setMaxRoundImbalance(
+        owner: anchor.web3.Keypair,
+        maxRoundImbalance: anchor.BN,
+        maxOneSidedAmount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
         const txSignature = await program.methods
-            .startRound()
+            .setMaxRoundImbalance(maxRoundImbalance, maxOneSidedAmount)
             .accounts({
-                user: user.publicKey,
-                round: pda.getRound(roundIndex, program),
-                priceAccount: priceAccount,
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async endRound(user: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
-        const platformConfigAccount = await program.account.platformConfig.fetch(
-            pda.getPlatformConfig(program)
-        );
-        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
-        const priceAccount = platformConfigAccount.globalRoundInfo.priceAccount;
-
+    async setBucketBoundaries(
+        owner: anchor.web3.Keypair,
+        bucketBoundaries: number[],
+        program: anchor.Program<BearishDotFun>
+    ) {
         const txSignature = await program.methods
-            .endRound()
+            .setBucketBoundaries(bucketBoundaries)
             .accounts({
-                user: user.publicKey,
-                round: pda.getRound(roundIndex, program),
-                priceAccount: priceAccount,
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async placeBet(
-        user: anchor.web3.Keypair,
-        amount: anchor.BN,
-        isLong: boolean,
+    async setStrikeOffset(
+        owner: anchor.web3.Keypair,
+        strikeOffset: number,
         program: anchor.Program<BearishDotFun>
     ) {
-        const roundIndex =
-            (
-                await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
-            ).globalRoundInfo.round.toNumber() + 1;
+        const txSignature = await program.methods
+            .setStrikeOffset(strikeOffset)
+            .accounts({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
 
+        return txSignature;
+    },
+    async setStrikeAuthority(
+        owner: anchor.web3.Keypair,
+        strikeAuthority: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
         const txSignature = await program.methods
-            .placeBet(amount, isLong)
+            .setStrikeAuthority(strikeAuthority)
             .accounts({
-                user: user.publicKey,
-                round: pda.getRound(roundIndex, program),
-                userBet: pda.getUserBet(user.publicKey, roundIndex, program),
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async claimUserWinnings(
-        user: anchor.web3.Keypair,
-        roundIndex: number,
+    async setGating(
+        owner: anchor.web3.Keypair,
+        isGated: boolean,
+        gatekeeper: anchor.web3.PublicKey,
         program: anchor.Program<BearishDotFun>
     ) {
         const txSignature = await program.methods
-            .claimUserWinnings(new anchor.BN(roundIndex))
+            .setGating(isGated, gatekeeper)
             .accounts({
-                user: user.publicKey,
-                round: pda.getRound(roundIndex + 1, program),
-                userBet: pda.getUserBet(user.publicKey, roundIndex + 1, program),
+                owner: owner.publicKey,
             })
-            .signers([user])
+            .signers([owner])
             .rpc();
 
         return txSignature;
     },
-    async claimAffiliateWinnings(
-        affiliate: anchor.web3.Keypair,
-        user: anchor.web3.PublicKey,
-        roundIndex: number,
+    async initializeHousePool(
+        owner: anchor.web3.Keypair,
+        maxExposure: number,
+        platformFeeShare: number,
         program: anchor.Program<BearishDotFun>
     ) {
         const provider = program.provider;
@@ -450,10 +572,1000 @@ const programMethods = {
         const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
 
         const txSignature = await program.methods
-            .claimAffiliateWinnings(new anchor.BN(roundIndex))
-            .accounts({
-                user: user,
-                affiliate: affiliate.publicKey,
+            .initializeHousePool(maxExposure, platformFeeShare)
+            .accountsPartial({
+                owner: owner.publicKey,
+                stablecoin,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async setHousePoolConfig(
+        owner: anchor.web3.Keypair,
+        maxExposure: number,
+        platformFeeShare: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setHousePoolConfig(maxExposure, platformFeeShare)
+            .accountsPartial({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async setFixedOddsConfig(
+        owner: anchor.web3.Keypair,
+        baseMultiplier: anchor.BN,
+        skew: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setFixedOddsConfig(baseMultiplier, skew)
+            .accountsPartial({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async blockUser(
+        owner: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .blockUser(user)
+            .accounts({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async unblockUser(
+        owner: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .unblockUser()
+            .accounts({
+                owner: owner.publicKey,
+                blockedUser: pda.getBlockedUser(user, program),
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async issueAttestation(
+        gatekeeper: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        expiresAt: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .issueAttestation(user, expiresAt)
+            .accounts({
+                gatekeeper: gatekeeper.publicKey,
+            })
+            .signers([gatekeeper])
+            .rpc();
+
+        return txSignature;
+    },
+    async revokeAttestation(
+        gatekeeper: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .revokeAttestation()
+            .accounts({
+                gatekeeper: gatekeeper.publicKey,
+                attestation: pda.getAttestation(user, program),
+            })
+            .signers([gatekeeper])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdrawPlatformFees(owner: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+
+        const txSignature = await program.methods
+            .withdrawPlatformFees()
+            .accounts({
+                owner: owner.publicKey,
+                stablecoin,
+                ownerTokenAccount: (
+                    await spl.getOrCreateAssociatedTokenAccount(
+                        provider.connection,
+                        owner,
+                        stablecoin,
+                        owner.publicKey
+                    )
+                ).address,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async whitelistMint(
+        owner: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>,
+        allowedMintExtensions: AllowedMintExtensions = noMintExtensionsAllowed
+    ) {
+        const provider = program.provider;
+        const tokenProgramId = (await provider.connection.getAccountInfo(mint)).owner;
+
+        const txSignature = await program.methods
+            .whitelistMint(allowedMintExtensions)
+            .accountsPartial({
+                owner: owner.publicKey,
+                mint,
+                mintMarket: pda.getMintMarket(mint, program),
+                mintVault: pda.getMintVault(mint, program),
+                tokenProgram: tokenProgramId,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async setMintMarketEnabled(
+        owner: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        isEnabled: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setMintMarketEnabled(isEnabled)
+            .accountsPartial({
+                owner: owner.publicKey,
+                mintMarket: pda.getMintMarket(mint, program),
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async setMintMarketBetLimits(
+        owner: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        minBetAmount: anchor.BN,
+        maxBetAmount: anchor.BN,
+        maxUserBetAmountPerRound: anchor.BN,
+        maxOneSidedAmount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setMintMarketBetLimits(
+                minBetAmount,
+                maxBetAmount,
+                maxUserBetAmountPerRound,
+                maxOneSidedAmount
+            )
+            .accountsPartial({
+                owner: owner.publicKey,
+                mintMarket: pda.getMintMarket(mint, program),
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdrawMintPlatformFees(
+        owner: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const tokenProgramId = (await provider.connection.getAccountInfo(mint)).owner;
+
+        const txSignature = await program.methods
+            .withdrawMintPlatformFees()
+            .accountsPartial({
+                owner: owner.publicKey,
+                mint,
+                mintMarket: pda.getMintMarket(mint, program),
+                mintVault: pda.getMintVault(mint, program),
+                ownerTokenAccount: (
+                    await spl.getOrCreateAssociatedTokenAccount(
+                        provider.connection,
+                        owner,
+                        mint,
+                        owner.publicKey
+                    )
+                ).address,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async whitelistNativeSol(owner: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
+        const txSignature = await program.methods
+            .whitelistNativeSol()
+            .accountsPartial({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdrawNativePlatformFees(
+        owner: anchor.web3.Keypair,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .withdrawNativePlatformFees()
+            .accountsPartial({
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc();
+
+        return txSignature;
+    },
+    async depositLiquidity(
+        liquidityProvider: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return await programMethods.manageLiquidity(liquidityProvider, amount, true, program);
+    },
+    async withdrawLiquidity(
+        liquidityProvider: anchor.web3.Keypair,
+        shares: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return await programMethods.manageLiquidity(liquidityProvider, shares, false, program);
+    },
+    async manageLiquidity(
+        liquidityProvider: anchor.web3.Keypair,
+        amount: anchor.BN,
+        isDeposit: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+        const lpMint = pda.getLpMint(program);
+
+        const method = isDeposit
+            ? program.methods.depositLiquidity(amount)
+            : program.methods.withdrawLiquidity(amount);
+        const txSignature = await method
+            .accountsPartial({
+                liquidityProvider: liquidityProvider.publicKey,
+                lpMint,
+                stablecoin,
+                liquidityProviderTokenAccount: await spl.getAssociatedTokenAddress(
+                    stablecoin,
+                    liquidityProvider.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                liquidityProviderLpTokenAccount: (
+                    await spl.getOrCreateAssociatedTokenAccount(
+                        provider.connection,
+                        liquidityProvider,
+                        lpMint,
+                        liquidityProvider.publicKey,
+                        false,
+                        undefined,
+                        undefined,
+                        tokenProgramId
+                    )
+                ).address,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([liquidityProvider])
+            .rpc();
+
+        return txSignature;
+    },
+    async setAffiliate(
+        user: anchor.web3.Keypair,
+        affiliate: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setAffiliate(affiliate)
+            .accounts({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async setWinningsPreference(
+        user: anchor.web3.Keypair,
+        winningsPreference: WinningsPreference,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setWinningsPreference(winningsPreference)
+            .accounts({
+                user: user.publicKey,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async deposit(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+
+        const txSignature = await program.methods
+            .deposit(amount)
+            .accounts({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                stablecoin,
+                userTokenAccount: await spl.getAssociatedTokenAddress(
+                    stablecoin,
+                    user.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdraw(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+
+        const txSignature = await program.methods
+            .withdraw(amount)
+            .accounts({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                stablecoin,
+                userTokenAccount: await spl.getAssociatedTokenAddress(
+                    stablecoin,
+                    user.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async depositMint(
+        user: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const tokenProgramId = (await provider.connection.getAccountInfo(mint)).owner;
+
+        const txSignature = await program.methods
+            .depositMint(amount)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                mint,
+                mintMarket: pda.getMintMarket(mint, program),
+                mintVault: pda.getMintVault(mint, program),
+                userTokenAccount: await spl.getAssociatedTokenAddress(
+                    mint,
+                    user.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                userInfo: pda.getMintUserInfo(user.publicKey, mint, program),
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdrawMint(
+        user: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const tokenProgramId = (await provider.connection.getAccountInfo(mint)).owner;
+
+        const txSignature = await program.methods
+            .withdrawMint(amount)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                mint,
+                mintMarket: pda.getMintMarket(mint, program),
+                mintVault: pda.getMintVault(mint, program),
+                userTokenAccount: await spl.getAssociatedTokenAddress(
+                    mint,
+                    user.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                userInfo: pda.getMintUserInfo(user.publicKey, mint, program),
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async depositNativeSol(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .depositNativeSol(amount)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async withdrawNativeSol(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .withdrawNativeSol(amount)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async createSession(
+        user: anchor.web3.Keypair,
+        delegate: anchor.web3.PublicKey,
+        expiresAt: anchor.BN,
+        maxSpend: anchor.BN,
+        permissions: SessionPermissions,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .createSession(delegate, expiresAt, maxSpend, permissions)
+            .accounts({
+                user: user.publicKey,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async revokeSession(
+        user: anchor.web3.Keypair,
+        delegate: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .revokeSession()
+            .accounts({
+                user: user.publicKey,
+                session: pda.getSession(user.publicKey, delegate, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async startRound(user: anchor.web3.Keypair, program: anchor.Program<BearishDotFun>) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+        const priceAccount = platformConfigAccount.globalRoundInfo.priceAccount;
+
+        const txSignature = await program.methods
+            .startRound()
+            .accounts({
+                user: user.publicKey,
+                round: pda.getRound(roundIndex, program),
+                priceAccount: priceAccount,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async startRoundWithStrike(
+        authority: anchor.web3.Keypair,
+        strikePrice: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+        const priceAccount = platformConfigAccount.globalRoundInfo.priceAccount;
+
+        const txSignature = await program.methods
+            .startRoundWithStrike(strikePrice)
+            .accountsPartial({
+                authority: authority.publicKey,
+                round: pda.getRound(roundIndex, program),
+                priceAccount: priceAccount,
+            })
+            .signers([authority])
+            .rpc();
+
+        return txSignature;
+    },
+    async setStrikeOverridePending(
+        authority: anchor.web3.Keypair,
+        isPending: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .setStrikeOverridePending(isPending)
+            .accounts({
+                authority: authority.publicKey,
+            })
+            .signers([authority])
+            .rpc();
+
+        return txSignature;
+    },
+    async endRound(
+        user: anchor.web3.Keypair,
+        program: anchor.Program<BearishDotFun>,
+        withHousePool: boolean = false
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+        const priceAccount = platformConfigAccount.globalRoundInfo.priceAccount;
+
+        const txSignature = await program.methods
+            .endRound()
+            .accounts({
+                user: user.publicKey,
+                round: pda.getRound(roundIndex, program),
+                priceAccount: priceAccount,
+                housePool: withHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async placeBet(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        isLong: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .placeBet(amount, isLong)
+            .accounts({
+                authority: user.publicKey,
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user.publicKey, roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async placeBucketBet(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        bucket: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const roundIndex =
+            (
+                await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .placeBucketBet(amount, bucket)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                round: pda.getRound(roundIndex, program),
+                bucketBet: pda.getBucketBet(user.publicKey, roundIndex, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async placeFixedOddsBet(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        isLong: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const roundIndex =
+            (
+                await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .placeFixedOddsBet(amount, isLong)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                round: pda.getRound(roundIndex, program),
+                fixedOddsBet: pda.getFixedOddsBet(user.publicKey, roundIndex, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async placeMintBet(
+        user: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        amount: anchor.BN,
+        isLong: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const roundIndex =
+            (
+                await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+            ).globalRoundInfo.round.toNumber() + 1;
+        const platformUserInfo = pda.getUserInfo(user.publicKey, program);
+        const hasPlatformUserInfo =
+            (await program.account.userInfo.fetchNullable(platformUserInfo)) !== null;
+
+        const txSignature = await program.methods
+            .placeMintBet(amount, isLong)
+            .accountsPartial({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                mintMarket: pda.getMintMarket(mint, program),
+                userInfo: pda.getMintUserInfo(user.publicKey, mint, program),
+                platformUserInfo: hasPlatformUserInfo ? platformUserInfo : null,
+                round: pda.getRound(roundIndex, program),
+                mintRound: pda.getMintRound(roundIndex, mint, program),
+                userBet: pda.getMintUserBet(user.publicKey, roundIndex, mint, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async settleMintRound(
+        mint: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .settleMintRound(new anchor.BN(roundIndex))
+            .accountsPartial({
+                mintMarket: pda.getMintMarket(mint, program),
+                round: pda.getRound(roundIndex + 1, program),
+                mintRound: pda.getMintRound(roundIndex + 1, mint, program),
+            })
+            .rpc();
+
+        return txSignature;
+    },
+    async placeBetAsDelegate(
+        delegate: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        amount: anchor.BN,
+        isLong: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .placeBet(amount, isLong)
+            .accounts({
+                authority: delegate.publicKey,
+                user,
+                access: pda.getUserAccess(user, program),
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user, roundIndex, program),
+                session: pda.getSession(user, delegate.publicKey, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([delegate])
+            .rpc();
+
+        return txSignature;
+    },
+    async placeBetFromWallet(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        isLong: boolean,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const stablecoin = platformConfigAccount.stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .placeBetFromWallet(amount, isLong)
+            .accounts({
+                user: user.publicKey,
+                access: pda.getUserAccess(user.publicKey, program),
+                stablecoin,
+                userTokenAccount: await spl.getAssociatedTokenAddress(
+                    stablecoin,
+                    user.publicKey,
+                    false,
+                    tokenProgramId
+                ),
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user.publicKey, roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async executeRepeatBet(
+        keeper: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .executeRepeatBet()
+            .accounts({
+                keeper: keeper.publicKey,
+                user,
+                access: pda.getUserAccess(user, program),
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user, roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([keeper])
+            .rpc();
+
+        return txSignature;
+    },
+    async createOrder(
+        user: anchor.web3.Keypair,
+        orderId: number,
+        amount: anchor.BN,
+        isLong: boolean,
+        condition: OrderCondition,
+        executorFee: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .createOrder(new anchor.BN(orderId), amount, isLong, condition, executorFee)
+            .accounts({
+                user: user.publicKey,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async cancelOrder(
+        user: anchor.web3.Keypair,
+        orderId: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .cancelOrder()
+            .accounts({
+                user: user.publicKey,
+                order: pda.getOrder(user.publicKey, orderId, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async executeOrder(
+        executor: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        orderId: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .executeOrder()
+            .accounts({
+                executor: executor.publicKey,
+                user,
+                access: pda.getUserAccess(user, program),
+                order: pda.getOrder(user, orderId, program),
+                previousRound: pda.getRound(roundIndex - 1, program),
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user, roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([executor])
+            .rpc();
+
+        return txSignature;
+    },
+    async cancelBet(
+        user: anchor.web3.Keypair,
+        amount: anchor.BN,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        const txSignature = await program.methods
+            .cancelBet(amount)
+            .accounts({
+                user: user.publicKey,
+                round: pda.getRound(roundIndex, program),
+                userBet: pda.getUserBet(user.publicKey, roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimUserWinnings(
+        user: anchor.web3.Keypair,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimUserWinnings(new anchor.BN(roundIndex))
+            .accounts({
+                authority: user.publicKey,
+                user: user.publicKey,
+                round: pda.getRound(roundIndex + 1, program),
+                userBet: pda.getUserBet(user.publicKey, roundIndex + 1, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimUserWinningsAsDelegate(
+        delegate: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimUserWinnings(new anchor.BN(roundIndex))
+            .accounts({
+                authority: delegate.publicKey,
+                user,
+                round: pda.getRound(roundIndex + 1, program),
+                userBet: pda.getUserBet(user, roundIndex + 1, program),
+                session: pda.getSession(user, delegate.publicKey, program),
+            })
+            .signers([delegate])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimUserWinningsToWallet(
+        user: anchor.web3.Keypair,
+        roundIndex: number,
+        payoutTokenAccount: anchor.web3.PublicKey,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+
+        const txSignature = await program.methods
+            .claimUserWinnings(new anchor.BN(roundIndex))
+            .accounts({
+                authority: user.publicKey,
+                user: user.publicKey,
+                round: pda.getRound(roundIndex + 1, program),
+                userBet: pda.getUserBet(user.publicKey, roundIndex + 1, program),
+                stablecoin,
+                platformVault: pda.getPlatformVault(program),
+                payoutTokenAccount,
+                tokenProgram: tokenProgramId,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimAffiliateWinnings(
+        affiliate: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const provider = program.provider;
+        const stablecoin = (
+            await program.account.platformConfig.fetch(pda.getPlatformConfig(program))
+        ).stablecoin;
+        const tokenProgramId = (await provider.connection.getAccountInfo(stablecoin)).owner;
+
+        const txSignature = await program.methods
+            .claimAffiliateWinnings(new anchor.BN(roundIndex))
+            .accounts({
+                user: user,
+                affiliate: affiliate.publicKey,
                 stablecoin,
                 round: pda.getRound(roundIndex + 1, program),
                 userBet: pda.getUserBet(user, roundIndex + 1, program),
@@ -472,6 +1584,181 @@ const programMethods = {
 
         return txSignature;
     },
+    async claimBucketWinnings(
+        user: anchor.web3.Keypair,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimBucketWinnings(new anchor.BN(roundIndex))
+            .accountsPartial({
+                user: user.publicKey,
+                round: pda.getRound(roundIndex + 1, program),
+                bucketBet: pda.getBucketBet(user.publicKey, roundIndex + 1, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimFixedOddsWinnings(
+        user: anchor.web3.Keypair,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimFixedOddsWinnings(new anchor.BN(roundIndex))
+            .accountsPartial({
+                user: user.publicKey,
+                round: pda.getRound(roundIndex + 1, program),
+                fixedOddsBet: pda.getFixedOddsBet(user.publicKey, roundIndex + 1, program),
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimMintUserWinnings(
+        user: anchor.web3.Keypair,
+        mint: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimMintUserWinnings(new anchor.BN(roundIndex))
+            .accountsPartial({
+                authority: user.publicKey,
+                user: user.publicKey,
+                mintMarket: pda.getMintMarket(mint, program),
+                userInfo: pda.getMintUserInfo(user.publicKey, mint, program),
+                mintRound: pda.getMintRound(roundIndex + 1, mint, program),
+                userBet: pda.getMintUserBet(user.publicKey, roundIndex + 1, mint, program),
+                session: null,
+            })
+            .signers([user])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimMintUserWinningsAsDelegate(
+        delegate: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        mint: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimMintUserWinnings(new anchor.BN(roundIndex))
+            .accountsPartial({
+                authority: delegate.publicKey,
+                user,
+                mintMarket: pda.getMintMarket(mint, program),
+                userInfo: pda.getMintUserInfo(user, mint, program),
+                mintRound: pda.getMintRound(roundIndex + 1, mint, program),
+                userBet: pda.getMintUserBet(user, roundIndex + 1, mint, program),
+                session: pda.getSession(user, delegate.publicKey, program),
+            })
+            .signers([delegate])
+            .rpc();
+
+        return txSignature;
+    },
+    async claimNativeAffiliateWinnings(
+        affiliate: anchor.web3.Keypair,
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        const txSignature = await program.methods
+            .claimNativeAffiliateWinnings(new anchor.BN(roundIndex))
+            .accountsPartial({
+                user,
+                affiliate: affiliate.publicKey,
+                userBet: pda.getMintUserBet(user, roundIndex + 1, spl.NATIVE_MINT, program),
+            })
+            .signers([affiliate])
+            .rpc();
+
+        return txSignature;
+    },
+    async quoteBet(amount: anchor.BN, isLong: boolean, program: anchor.Program<BearishDotFun>) {
+        const platformConfigAccount = await program.account.platformConfig.fetch(
+            pda.getPlatformConfig(program)
+        );
+        const roundIndex = platformConfigAccount.globalRoundInfo.round.toNumber() + 1;
+
+        return await program.methods
+            .quoteBet(amount, isLong)
+            .accountsPartial({
+                round: pda.getRound(roundIndex, program),
+                housePool: platformConfigAccount.hasHousePool ? pda.getHousePool(program) : null,
+            })
+            .view();
+    },
+    async quoteClaim(
+        user: anchor.web3.PublicKey,
+        roundIndex: number,
+        program: anchor.Program<BearishDotFun>
+    ) {
+        return await program.methods
+            .quoteClaim(new anchor.BN(roundIndex))
+            .accountsPartial({
+                user,
+                round: pda.getRound(roundIndex + 1, program),
+                userBet: pda.getUserBet(user, roundIndex + 1, program),
+            })
+            .view();
+    },
+    async quoteJackpot(user: anchor.web3.PublicKey, program: anchor.Program<BearishDotFun>) {
+        return await program.methods.quoteJackpot().accountsPartial({ user }).view();
+    },
+};
+
+const mockOracle = {
+    getPriceAccount(
+        authority: anchor.web3.PublicKey,
+        feedId: number,
+        program: anchor.Program<MockOracle>
+    ) {
+        return anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from(seeds.mockPriceAccount),
+                authority.toBuffer(),
+                new anchor.BN(feedId).toArrayLike(Buffer, "be", 8),
+            ],
+            program.programId
+        )[0];
+    },
+    async setPrice(
+        authority: anchor.web3.Keypair,
+        feedId: number,
+        price: anchor.BN,
+        conf: anchor.BN,
+        expo: number,
+        publishTime: anchor.BN,
+        program: anchor.Program<MockOracle>
+    ) {
+        const txSignature = await program.methods
+            .setPrice(new anchor.BN(feedId), price, conf, expo, publishTime)
+            .accountsPartial({
+                authority: authority.publicKey,
+                priceAccount: mockOracle.getPriceAccount(authority.publicKey, feedId, program),
+            })
+            .signers([authority])
+            .rpc();
+
+        return txSignature;
+    },
 };
 
-export { sleep, transferSOL, createSplTokenMint, getStablecoin, runRound, pda, programMethods };
+export {
+    sleep,
+    transferSOL,
+    createSplTokenMint,
+    getStablecoin,
+    getEvents,
+    runRound,
+    pda,
+    programMethods,
+    mockOracle,
+};
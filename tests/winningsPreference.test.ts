@@ -0,0 +1,177 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { assert } from "chai";
+import { BearishDotFun } from "../target/types/bearish_dot_fun";
+
+import { pda, programMethods, runRound, transferSOL } from "./utils/utils";
+import { setup } from "./utils/setup";
+import { bps, decimals, errors, sampleGlobalRoundInfo } from "./utils/constants";
+import { User } from "./utils/types";
+
+describe("bearish-dot-fun", () => {
+    let provider: anchor.AnchorProvider,
+        owner: anchor.web3.Keypair,
+        user1: anchor.web3.Keypair,
+        user2: anchor.web3.Keypair,
+        stablecoin: anchor.web3.PublicKey,
+        bearishDotFun: anchor.Program<BearishDotFun>;
+    let user1AssociatedTokenAccountAddress: anchor.web3.PublicKey;
+    const amount = 100 * 10 ** decimals;
+    const depositAmount = amount * 3;
+
+    before(async () => {
+        ({ provider, owner, user1, user2, stablecoin, bearishDotFun } = await setup());
+
+        await programMethods.initialize(
+            owner,
+            stablecoin,
+            spl.TOKEN_PROGRAM_ID,
+            sampleGlobalRoundInfo,
+            bearishDotFun
+        );
+
+        await programMethods.deposit(user1, new anchor.BN(depositAmount), bearishDotFun);
+        await programMethods.deposit(user2, new anchor.BN(depositAmount), bearishDotFun);
+
+        user1AssociatedTokenAccountAddress = await spl.getAssociatedTokenAddress(
+            stablecoin,
+            user1.publicKey
+        );
+    });
+
+    it("Allows setting the winnings preference", async () => {
+        await programMethods.setWinningsPreference(
+            user1,
+            { withdrawToWallet: { tokenAccount: user1AssociatedTokenAccountAddress } },
+            bearishDotFun
+        );
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.deepEqual(userInfoAccount.winningsPreference, {
+            withdrawToWallet: { tokenAccount: user1AssociatedTokenAccountAddress },
+        });
+    });
+
+    it("Doesn't allow withdrawing winnings to the default pubkey", async () => {
+        try {
+            await programMethods.setWinningsPreference(
+                user2,
+                { withdrawToWallet: { tokenAccount: anchor.web3.PublicKey.default } },
+                bearishDotFun
+            );
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.invalidPayoutTokenAccount
+            );
+        }
+    });
+
+    it("Transfers winnings straight to the designated token account on claim", async () => {
+        const userData: User[] = [
+            {
+                keypair: user1,
+                amount: new anchor.BN(amount),
+                isLong: true,
+                claimWinnings: false,
+            },
+            {
+                keypair: user2,
+                amount: new anchor.BN(amount),
+                isLong: false,
+                claimWinnings: false,
+            },
+        ];
+
+        const currentRoundIndex = await runRound(owner, userData, true, bearishDotFun);
+
+        const user1BalanceBefore = (
+            await spl.getAccount(provider.connection, user1AssociatedTokenAccountAddress)
+        ).amount;
+
+        await programMethods.claimUserWinningsToWallet(
+            user1,
+            currentRoundIndex - 1,
+            user1AssociatedTokenAccountAddress,
+            bearishDotFun
+        );
+
+        const user1BalanceAfter = (
+            await spl.getAccount(provider.connection, user1AssociatedTokenAccountAddress)
+        ).amount;
+        const expectedWinnings = (amount * sampleGlobalRoundInfo.allocation.winnersShare) / bps;
+        assert.strictEqual(
+            Number(user1BalanceAfter) - Number(user1BalanceBefore),
+            amount + expectedWinnings
+        );
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user1.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), depositAmount - amount);
+    });
+
+    it("Allows a keeper to execute a repeat bet standing order", async () => {
+        await programMethods.setWinningsPreference(
+            user2,
+            { repeatBet: { amount: new anchor.BN(amount), isLong: false } },
+            bearishDotFun
+        );
+
+        await programMethods.startRound(owner, bearishDotFun);
+        const currentRoundIndex =
+            (
+                await bearishDotFun.account.platformConfig.fetch(
+                    pda.getPlatformConfig(bearishDotFun)
+                )
+            ).globalRoundInfo.round.toNumber() + 1;
+
+        try {
+            await programMethods.executeRepeatBet(owner, user2.publicKey, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.repeatBetRentNotEscrowed
+            );
+        }
+
+        // The keeper is paid back the bet's rent from the lamports escrowed in the user info.
+        const betRent = await provider.connection.getMinimumBalanceForRentExemption(
+            bearishDotFun.account.bet.size
+        );
+        await transferSOL(
+            provider,
+            user2,
+            pda.getUserInfo(user2.publicKey, bearishDotFun),
+            betRent
+        );
+        const ownerBalanceBefore = await provider.connection.getBalance(owner.publicKey);
+        await programMethods.executeRepeatBet(owner, user2.publicKey, bearishDotFun);
+        const ownerBalanceAfter = await provider.connection.getBalance(owner.publicKey);
+        assert.isAtLeast(ownerBalanceAfter, ownerBalanceBefore - 10_000);
+
+        const userBetAccount = await bearishDotFun.account.bet.fetch(
+            pda.getUserBet(user2.publicKey, currentRoundIndex, bearishDotFun)
+        );
+        assert.strictEqual(userBetAccount.amount.toNumber(), amount);
+        assert.isFalse(userBetAccount.isLong);
+
+        const userInfoAccount = await bearishDotFun.account.userInfo.fetch(
+            pda.getUserInfo(user2.publicKey, bearishDotFun)
+        );
+        assert.strictEqual(userInfoAccount.amount.toNumber(), depositAmount - 2 * amount);
+    });
+
+    it("Doesn't allow executing a repeat bet for a user without a standing order", async () => {
+        try {
+            await programMethods.executeRepeatBet(owner, user1.publicKey, bearishDotFun);
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.noRepeatBet
+            );
+        }
+    });
+});
@@ -96,6 +96,12 @@ describe("bearish-dot-fun", () => {
     it("Does not allow a user to withdraw more than deposited tokens", async () => {
         try {
             await programMethods.withdraw(user1, new anchor.BN(withdrawAmount), bearishDotFun);
-        } catch {}
+            assert.fail("Withdrawing more than the balance should fail");
+        } catch (error) {
+            assert.strictEqual(
+                (error as anchor.AnchorError).error.errorMessage,
+                errors.insufficientBalance
+            );
+        }
     });
 });